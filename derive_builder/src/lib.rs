@@ -110,11 +110,14 @@
 //! ## Owned, aka Consuming
 //!
 //! Precede your struct (or field) with `#[builder(pattern="owned")]` to opt into this pattern.
+//! `#[builder(pattern="move")]` is an alias.
 //!
 //! * Setters take and return `self`.
 //! * PRO: Setter calls and final build method can be chained.
 //! * CON: If you don't chain your calls, you have to create a reference to each return value,
 //!   e.g. `builder = builder.ipsum(42)`.
+//! * PRO: The build method moves the fields out of the builder, so they don't need to
+//!   implement `Clone`. Add `#[builder(derive(Clone))]` if you want a cloneable builder anyway.
 //!
 //! ## Mutable, aka Non-Comsuming (recommended)
 //!
@@ -145,6 +148,10 @@
 //! Switching to consuming signatures (=`self`) is unlikely to give you any performance
 //! gain, but very likely to restrict your API for non-chained use cases.
 //!
+//! If you want to nudge the compiler anyway, `#[builder(inline)]` marks all setters as
+//! `#[inline]`, including aliases, `each` setters and `try_` setters. Use `setter(inline)` to
+//! do this for individual fields only.
+//!
 //! # More Features
 //!
 //! ## Hidden Fields
@@ -155,7 +162,9 @@
 //! - Opt-in — set `#[builder(setter(skip))]` on the whole struct
 //!   and enable individual setters via `#[builder(setter)]`.
 //!
-//! The types of skipped fields must implement `Default`.
+//! The types of skipped fields must implement `Default`, unless the field or the struct
+//! provides a default. With the `struct_default` feature, a skipped field of a struct with
+//! `#[builder(default = "...")]` takes its value from that expression instead.
 //!
 //! ```rust
 //! # #[macro_use]
@@ -175,13 +184,28 @@
 //! - `#[builder(setter(skip="true"))]`
 //! - `#[builder(setter(skip="false"))]`
 //!
+//! Any other string is taken as the value of the skipped field, so
+//! `#[builder(setter(skip = "42"))]` is short for `#[builder(setter(skip), default = "42")]`.
+//!
+//! To inject a value which is usually computed, e.g. from tests, use
+//! `#[builder(setter(skip(private)))]`. The setter is still generated, but only visible within
+//! the crate as `pub(crate)`, and the field falls back to its default if it is not set.
+//!
 //! ## Setter Visibility
 //!
-//! Setters are public by default. You can precede your struct (or field) with `#[builder(public)]`
-//! to make this explicit.
+//! Setters are public by default. You can precede your struct (or field) with
+//! `#[builder(setter(public))]` to make this explicit.
+//!
+//! Otherwise precede your struct (or field) with `#[builder(setter(private))]` to opt into
+//! private setters.
 //!
-//! Otherwise precede your struct (or field) with `#[builder(private)]` to opt into private
-//! setters.
+//! The shorthands `#[builder(public)]` and `#[builder(private)]` are deprecated.
+//!
+//! For restricted visibilities, spell out the visibility as a string, e.g.
+//! `#[builder(setter(vis = "pub(crate)"))]`. An empty string means private. The same works
+//! for the builder fields via `#[builder(field(vis = "pub(super)"))]` and, on the struct level
+//! only, for the builder itself via `#[builder(vis = "pub(crate)")]`, which otherwise inherits
+//! the visibility of your struct. `pub(in path)` is not supported.
 //!
 //! ## Setter Name/Prefix
 //!
@@ -193,7 +217,40 @@
 //!   inserted, since Rust favors snake case here.
 //!
 //! Prefixes can also be defined on the struct level, but renames only work on fields. Renames
-//! take precedence over prefix definitions.
+//! take precedence over prefix definitions, i.e. `#[builder(setter(name="foo"))]` names the
+//! setter `foo` even if the struct declares `#[builder(setter(prefix="with"))]`. A field can
+//! opt out of the struct prefix via `#[builder(setter(prefix=""))]`.
+//!
+//! After renaming a setter you can keep the old name working via
+//! `#[builder(setter(name="new", alias="old"))]`. The alias delegates to the new setter and is
+//! marked as `#[deprecated]`, so callers get a warning. You can declare `alias` multiple times.
+//!
+//! To make a setter reachable under several names for good, e.g. a long and a short form, list
+//! the aliases with `deprecated = false`:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(into, alias("ips", "i", deprecated = false)))]
+//!     ipsum: String,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ips("dolor").build().unwrap();
+//!
+//!     assert_eq!(x, Lorem { ipsum: "dolor".to_string() });
+//! }
+//! ```
+//!
+//! Aliases share the visibility and signature of the primary setter, e.g. `setter(into)` or
+//! `setter(strip_option)`. Without `deprecated = false`, the listed aliases are deprecated as
+//! well.
+//!
+//! Fields with raw identifiers like `r#type` get a setter `r#type`. Derived names drop the
+//! `r#`, e.g. `with_type` for `setter(prefix = "with")`, and so do error messages.
 //!
 //! ## Generic Setters
 //!
@@ -220,6 +277,275 @@
 //! }
 //! ```
 //!
+//! If you prefer `impl Trait` in argument position over a named type parameter, add
+//! `impl_trait`, e.g. `#[builder(setter(into, impl_trait))]`. The generated setter then reads
+//! `fn ipsum(&mut self, value: impl Into<String>)`, which behaves the same but looks cleaner in
+//! the docs and your IDE. Note that callers can't use turbofish syntax on such setters.
+//!
+//! To convert via a specific type instead of the field type, name it on the field, e.g.
+//! `#[builder(setter(into = "PathBuf"))]` on a `Box<Path>` field. The setter is then generic
+//! over `Into<PathBuf>` and converts the result `Into` the field type. This also pins down
+//! the conversion if `Into` of the field type is ambiguous at the call site.
+//!
+//! Generic setters get in the way of type inference, e.g. an integer literal passed to a
+//! setter generic over `Into<u32>` needs a suffix. `#[builder(setter(with_plain))]` keeps the
+//! setter `ipsum(value: T)` plain and adds `ipsum_into(value: impl Into<T>)` next to it. It
+//! implies `into`.
+//!
+//! `#[builder(setter(auto_into))]` on the struct is a more selective alternative: only fields
+//! of string-like types, i.e. `String`, `PathBuf`, `OsString`, `CString` and `Cow`, get generic
+//! setters, while all other fields keep plain setters. An explicit `setter(into)` on a field
+//! still takes precedence.
+//!
+//! To pick the types yourself, list them on the struct instead, e.g.
+//! `#[builder(setter(into(only("String", "PathBuf"))))]`. Only the last path segment of the
+//! field type is compared, so `PathBuf` also matches `std::path::PathBuf`, but not `Box<Path>`.
+//! This can't be combined with `auto_into`.
+//!
+//! To set a `String`, `PathBuf` or `OsString` field from anything that can be borrowed as
+//! `str`, `Path` or `OsStr` respectively, add `#[builder(setter(as_ref))]` to the field. The
+//! setter then reads `fn ipsum<__VALUE: AsRef<str>>(&mut self, value: __VALUE)` and stores an
+//! owned copy via `value.as_ref().to_owned()`. Unlike `setter(into)`, this also accepts e.g.
+//! `&String` or `Box<str>` and always clones. It takes precedence over `setter(into)`.
+//!
+//! ## Optional Fields
+//!
+//! For `Option<T>` fields, `#[builder(setter(strip_option))]` makes the setter accept `T`
+//! and wrap it in `Some`. It can be combined with `into`, in which case the setter is generic
+//! over `Into<T>`.
+//!
+//! Fields of type `Option<Option<T>>` are stripped by one layer as well, i.e. the setter takes
+//! an `Option<T>`. Use `#[builder(setter(strip_option(depth = 2)))]` to strip both layers
+//! instead, so the setter takes a `T`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(strip_option))]
+//!     ipsum: Option<u32>,
+//!     #[builder(setter(strip_option(depth = 2), into))]
+//!     dolor: Option<Option<String>>,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ipsum(42).dolor("dolor").build().unwrap();
+//!
+//!     assert_eq!(x, Lorem {
+//!         ipsum: Some(42),
+//!         dolor: Some(Some("dolor".to_string())),
+//!     });
+//! }
+//! ```
+//!
+//! The `Option` layers are detected syntactically, so they are hidden behind a type alias like
+//! `type MaybeName = Option<String>`. Declare the inner type explicitly in that case, e.g.
+//! `#[builder(setter(strip_option(ty = "String")))]`.
+//!
+//! To pass an `Option` through anyway, e.g. to clear the field again, add `option`:
+//! `#[builder(setter(option))]` keeps the stripped setter `ipsum(T)` and generates an
+//! additional `maybe_ipsum(Option<T>)`, which assigns the `Option` as is. It implies
+//! `strip_option` and is never generic over `Into`.
+//!
+//! For `bool` fields, `#[builder(setter(strip_bool))]` generates a setter without arguments,
+//! which raises the flag, i.e. sets the field to `true`. Unless the field has a default of its
+//! own, it defaults to `false`. Rename the setter to taste, e.g.
+//! `#[builder(setter(strip_bool, name = "enable_ipsum"))]`.
+//!
+//! ## Collection Setters
+//!
+//! For collection fields, `#[builder(setter(each = "arg"))]` adds a setter which appends a
+//! single item, while the normal setter still replaces the whole collection. Use the list form
+//! `#[builder(setter(each(name = "arg", into)))]` to make the item setter generic over `Into`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(each(name = "arg", into)))]
+//!     args: Vec<String>,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().arg("ipsum").arg("dolor").build().unwrap();
+//!
+//!     assert_eq!(x, Lorem {
+//!         args: vec!["ipsum".to_string(), "dolor".to_string()],
+//!     });
+//! }
+//! ```
+//!
+//! A collection field with an `each` setter defaults to an empty collection, so it isn't
+//! required in the build method. An explicit `default = "..."` still takes precedence.
+//!
+//! The item type is taken from the first type parameter, e.g. `T` for `Vec<T>` or
+//! `HashSet<T>`. Any collection implementing `Default` and `Extend` works. The type parameters
+//! of a type alias like `type Args = Vec<String>` can't be inferred, so declare the item type
+//! explicitly via `#[builder(setter(each(name = "arg", ty = "String")))]`.
+//!
+//! For maps like `HashMap<K, V>` or `BTreeMap<K, V>` the item setter takes the key and the value
+//! as separate arguments and `insert`s them, e.g. `.entry("ipsum", 42)` with
+//! `#[builder(setter(each(name = "entry", into)))]`. With an explicit `ty = "(K, V)"` it takes
+//! the pair instead.
+//!
+//! A `String` field is treated as a collection of `str`, so
+//! `#[builder(setter(each = "line"))]` adds a setter taking `&str`, which appends it via
+//! `push_str`. With `each(name = "line", into)` it accepts anything implementing `AsRef<str>`.
+//!
+//! To replace the whole collection at once from any iterator, add
+//! `#[builder(setter(into_iter))]`. The setter then accepts any `IntoIterator` whose items
+//! convert `Into` the item type, and collects them via `FromIterator`, e.g.
+//! `.args(vec!["ipsum", "dolor"])` for a `Vec<String>`. This takes precedence over
+//! `setter(into)`.
+//!
+//! `#[builder(setter(collection))]` adds two companions to the normal setter: `set_args`
+//! replaces the collection from any `IntoIterator`, just like `into_iter`, and `clear_args`
+//! empties the collection if it has been set. A cleared collection is still set, so it doesn't
+//! fall back to its default. Maps take an iterator of key-value pairs. This can't be combined
+//! with `strip_option`.
+//!
+//! With `#[builder(setter(append))]` the normal setter extends the collection via `Extend`
+//! instead of replacing it, so repeated calls accumulate - e.g. to merge layers of
+//! configuration. Like with `each`, the field then defaults to an empty collection. Combined
+//! with `into_iter`, the setter extends the collection from any `IntoIterator`. This can't be
+//! combined with `strip_option` or `strip_bool`, and it doesn't work for `String` fields, as a
+//! `String` can't be iterated itself.
+//!
+//! `each` composes with the other setter options:
+//!
+//! * `setter(into)` only makes the normal setter generic over `Into` the collection. Use
+//!   `each(name = "arg", into)` to make the item setter generic over `Into` the item, too.
+//! * With `setter(strip_option)` on e.g. an `Option<Vec<T>>` field, the item setter pushes into
+//!   the inner collection, initializing any unset `Option` layers with an empty collection.
+//!   Without `strip_option` an `each` setter on an `Option` field is rejected, as is an item
+//!   type that can't be inferred from the type left after stripping.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(into, strip_option, each(name = "arg", into)))]
+//!     args: Option<Vec<String>>,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().arg("ipsum").arg("dolor").build().unwrap();
+//!
+//!     assert_eq!(x, Lorem {
+//!         args: Some(vec!["ipsum".to_string(), "dolor".to_string()]),
+//!     });
+//! }
+//! ```
+//!
+//! ## Call-Once Setters
+//!
+//! To catch values which are accidentally overwritten, `#[builder(setter(once))]` makes the
+//! setter panic if the field has already been set. With `#[builder(setter(once = "error"))]`
+//! the setter returns a `Result<_, String>` instead. Both can be used on a field or the whole
+//! struct.
+//!
+//! The `try_` setter of `try_setter` panics just the same, but can't be combined with
+//! `once = "error"`. Neither can be combined with `setter(each)` or `setter(collection)`, whose
+//! setters are meant to modify a value which has already been set.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(setter(once = "error"))]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! fn main() {
+//!     let mut builder = LoremBuilder::default();
+//!     builder.ipsum(1).unwrap();
+//!
+//!     assert!(builder.ipsum(2).is_err());
+//!     assert_eq!(builder.build().unwrap(), Lorem { ipsum: 1 });
+//! }
+//! ```
+//!
+//! ## Character Sets
+//!
+//! String fields can be restricted to a set of characters with `#[builder(setter(ascii))]`, or
+//! `#[builder(setter(charset = "..."))]` with one of the following values:
+//!
+//! - `"ascii"`: only ASCII characters, the same as `setter(ascii)`.
+//! - `"alphanumeric"`: only ASCII letters and digits.
+//!
+//! The build method returns an error if a value contains any other characters. The check runs
+//! after the validation function, see [Pre-Build Validation](#pre-build-validation). Default
+//! values are not checked.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(setter(ascii))]
+//!     ipsum: String,
+//!     #[builder(setter(charset = "alphanumeric"))]
+//!     dolor: String,
+//! }
+//!
+//! fn main() {
+//!     let err = LoremBuilder::default()
+//!         .ipsum("Grüß Gott".to_string())
+//!         .dolor("sit".to_string())
+//!         .build()
+//!         .unwrap_err();
+//!
+//!     assert_eq!(&err, "`ipsum` must only contain ASCII characters");
+//! }
+//! ```
+//!
+//! ## Const Setters
+//!
+//! With `#[builder(pattern = "owned", setter(const_fn))]` setters are declared as `const fn`, so
+//! builders can be configured in constants. This only works for field types without drop glue,
+//! e.g. `Copy` types. The option is called `const_fn`, because `const` is a keyword.
+//!
+//! `setter(const_fn)` can not be combined with `setter(into)` or other builder patterns. Neither
+//! can it be combined with deprecated options like `public`, whose deprecation notes are emitted
+//! via a regular function call in the setter.
+//!
+//! To make every setter `const fn` which can be one, declare `#[builder(const_setters)]` on the
+//! struct instead. Setters using `into`, `try_into`, `as_ref`, `into_iter`, `append` or
+//! `once = "error"` stay non-const, and so do setters of fields which might have drop glue or
+//! use deprecated options.
+//! Only primitives, references, and `Option`s, tuples and arrays of those are known not to,
+//! e.g. `String` fields keep non-const setters. An explicit `setter(const_fn)` on a field still
+//! takes precedence. Like `setter(const_fn)`, this requires `pattern = "owned"`.
+//!
+//! Const setters need Rust 1.33 or later for mutable locals in `const fn`, and Rust 1.57 or
+//! later in combination with `setter(once)`, which panics if the field has already been set.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(pattern = "owned", setter(const_fn))]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! const DEFAULT_LOREM: LoremBuilder = LoremBuilder { ipsum: None }.ipsum(42);
+//!
+//! fn main() {
+//!     assert_eq!(DEFAULT_LOREM.build().unwrap(), Lorem { ipsum: 42 });
+//! }
+//! ```
+//!
 //! ## Fallible Setters
 //!
 //! Alongside the normal setter methods, you can expose fallible setters which are generic over
@@ -269,6 +595,22 @@
 //! }
 //! ```
 //!
+//! By default, a fallible setter returns the `TryInto::Error` of its argument. Use
+//! `#[builder(try_setter(error = "MyError"))]` to return `MyError` instead, which must
+//! implement `From` for all those conversion errors. This way all fallible setters of a
+//! builder share a single error type.
+//!
+//! Instead of an additional `try_` method, `#[builder(setter(try_into = "MyError"))]` makes the
+//! setter itself fallible. It accepts any `TryInto<T>` and returns
+//! `Result<&mut LoremBuilder, MyError>`, or `Result<LoremBuilder, MyError>` for the owned and
+//! immutable patterns. Again, `MyError` must implement `From` for the conversion errors. This
+//! takes precedence over `setter(into)`, and can't be combined with `setter(const_fn)` or
+//! `setter(once = "error")`. Without an error type, i.e. `#[builder(setter(try_into))]`, the
+//! setter returns the `TryInto::Error` of its argument.
+//!
+//! So the difference between the two: `try_setter` keeps the infallible setter and adds a
+//! `try_` method next to it, while `setter(try_into)` replaces the setter with a fallible one.
+//!
 //! ## Default Values
 //!
 //! You can define default values for each field via annotation by `#[builder(default="...")]`,
@@ -300,10 +642,44 @@
 //! }
 //! ```
 //!
+//! Default expressions must already have the type of the field. To convert them via `Into`
+//! instead - just like a `setter(into)` converts its argument - use
+//! `#[builder(default(into = "..."))]`, e.g. `default(into = "\"hi\"")` for a `String` field.
+//!
+//! `#[builder(fields_default)]` on the struct is a shorthand for `#[builder(default)]` on every
+//! field without an explicit default. Unlike `#[builder(default)]` on the struct, its meaning
+//! doesn't depend on the `struct_default` feature.
+//!
+//! To only make `Option` fields optional, add `#[builder(option_as_optional)]` to the struct.
+//! Unset fields of type `Option<T>` then fall back to `None` instead of failing the build,
+//! unless they have a default of their own. All other fields are still required. Only the last
+//! path segment of the type is compared, so `std::option::Option<T>` works, too, but type
+//! aliases of `Option` don't.
+//!
+//! If the trait default of a field is an invalid sentinel, e.g. an empty `String` which must
+//! be provided, add `#[builder(field(forbid_default))]`. The build method then returns an error
+//! if the field ends up equal to `Default::default()`, whether it was set to that value or
+//! fell back to it. The field type must implement `Default` and `PartialEq`.
+//!
+//! A default expression ending with `?`, e.g. `#[builder(default = "load_port()?")]`, is
+//! evaluated in a closure returning `Result<_, String>`. A failed default then fails the build,
+//! also with `build_fn(collect_errors)`, and `?` converts the error via `From` into a `String`.
+//!
+//! ### Initial Values of Builder Fields
+//!
+//! A default applies in the build method, if the field is still unset. To set the builder
+//! field itself up front, use `#[builder(field(default = "..."))]` instead, e.g.
+//! `field(default = "vec![]")` for a field with an `each` setter. `FooBuilder::default()` and
+//! `FooBuilder::new()` then start with that value, so the field counts as set. This replaces
+//! the derived `Default` impl of the builder with a manual one.
+//!
 //! ### Tips on Defaults
 //!
 //! * The `#[builder(default)]` annotation can be used on the struct level, too. Overrides are
 //!   still possible.
+//! * With the `struct_default` feature, `#[builder(default = "...")]` on the struct builds the
+//!   base value from that expression, so neither the struct nor its field types need to
+//!   implement `Default`.
 //! * Delegate to a private helper method on `FooBuilder` for anything fancy. This way
 //!   you will get _much better error diagnostics_ from the rust compiler and it will be _much
 //!   more readable_ for other human beings. :-)
@@ -349,6 +725,48 @@
 //! You can even reference other fields, but you have to remember that the builder struct
 //! will wrap every type in an Option ([as illustrated earlier](#what-you-get)).
 //!
+//! ### Evaluation Order of Defaults
+//!
+//! Default expressions are evaluated top-to-bottom in declaration order - including those of
+//! fields with skipped setters. The build method runs them only _after_ the validation
+//! function and the struct-level default, if any. If building fails because of an
+//! uninitialized field, the defaults of the following fields are not evaluated at all.
+//!
+//! If a default must be evaluated after the defaults of other fields, e.g. because of side
+//! effects, you can declare this via `#[builder(field(depends_on("a", "b")))]`. The build
+//! method will then evaluate the fields in topological order. Cyclic or unknown dependencies
+//! are rejected at compile time.
+//!
+//! ### Implementing `Default` for the Target
+//!
+//! If every field has a default, `#[builder(derive_target(Default))]` implements `Default` for
+//! the target struct, too - using the same default expressions and evaluation order as the
+//! build method:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(derive_target(Default))]
+//! struct Lorem {
+//!     #[builder(default = "42")]
+//!     ipsum: u32,
+//!     #[builder(default)]
+//!     dolor: String,
+//! }
+//!
+//! # fn main() {
+//! assert_eq!(Lorem::default(), LoremBuilder::default().build().unwrap());
+//! # }
+//! ```
+//!
+//! A field without a default value of its own fails to compile. This includes fallible
+//! defaults ending with `?` and fields relying on the struct-level `#[builder(default)]`, which
+//! would call the very same `Default` impl. Unset `Option` fields with
+//! `#[builder(option_as_optional)]` default to `None`. A default expression must not refer to
+//! the builder via `self`.
+//!
 //! ## Generic Structs
 //!
 //! ```rust
@@ -367,134 +785,978 @@
 //! }
 //! ```
 //!
+//! Bounds in a `where` clause of the struct are forwarded to the builder struct and all of its
+//! impls, so fields may also use associated types like `Option<T::Item>`.
+//!
+//! ## Enums
+//!
+//! Deriving `Builder` for an enum generates one builder per variant with named fields,
+//! e.g. `LoremIpsumBuilder` for `Lorem::Ipsum { .. }`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(setter(into))]
+//! enum Lorem {
+//!     Ipsum { dolor: u32 },
+//!     #[builder(pattern = "owned")]
+//!     Sit { amet: String },
+//! }
+//!
+//! fn main() {
+//!     let x = LoremSitBuilder::default().amet("consectetur").build().unwrap();
+//!     assert_eq!(x, Lorem::Sit { amet: "consectetur".to_string() });
+//! }
+//! ```
+//!
+//! Options on the enum apply to all variants, options on a variant are added to them (but
+//! may not repeat them). Some limitations apply:
+//!
+//! - Unit and tuple variants are skipped, they don't get a builder.
+//! - Each variant must use all type parameters of the enum.
+//! - A struct-level `default`, `diff` and `build_fn(apply_to)` are not supported, use
+//!   `fields_default` instead of `default`.
+//!
 //! ## Build Method Customization
 //!
 //! You can rename or suppress the auto-generated build method, leaving you free to implement
 //! your own version. Suppression is done using `#[builder(build_fn(skip))]` at the struct level,
 //! and renaming is done with `#[builder(build_fn(name = "YOUR_NAME"))]`.
 //!
-//! ## Pre-Build Validation
+//! The build method has the same visibility as the builder. To hand out a public builder, but
+//! finalize it only within your crate, add `#[builder(build_fn(vis = "pub(crate)"))]`. This
+//! applies to the build method taking `&self` of `build_fn(by_ref)`, too.
 //!
-//! If you're using the provided `build` method, you can declare
-//! `#[builder(build_fn(validate="path::to::fn"))]` to specify a validator function which gets
-//! access to the builder before construction. The path does not need to be fully-qualified, and
-//! will consider `use` statements made at module level. It must be accessible from the scope
-//! where the target struct is declared.
+//! ### Collecting All Errors
 //!
-//! The provided function must have the signature `(&FooBuilder) -> Result<_, String>`;
-//! the `Ok` variant is not used by the `build` method.
+//! By default, the build method returns a `String` describing the first problem it runs into.
+//! With `#[builder(build_fn(collect_errors))]` it returns `Result<Lorem, Vec<String>>`
+//! instead, containing an error from the validation function (if any) followed by one error
+//! per uninitialized field. This is handy for user-facing error messages, e.g. in a CLI.
 //!
 //! ```rust
 //! # #[macro_use]
 //! # extern crate derive_builder;
 //! #
-//! #[derive(Builder, Debug, PartialEq)]
-//! #[builder(build_fn(validate="Self::validate"))]
+//! #[derive(Builder, Debug)]
+//! #[builder(build_fn(collect_errors))]
 //! struct Lorem {
-//!     pub ipsum: u8,
-//! }
-//!
-//! impl LoremBuilder {
-//!     /// Check that `Lorem` is putting in the right amount of effort.
-//!     fn validate(&self) -> Result<(), String> {
-//!         if let Some(ref ipsum) = self.ipsum {
-//!             match *ipsum {
-//!                 i if i < 20 => Err("Try harder".to_string()),
-//!                 i if i > 100 => Err("You'll tire yourself out".to_string()),
-//!                 _ => Ok(())
-//!             }
-//!         } else {
-//!             Ok(())
-//!         }
-//!     }
+//!     ipsum: u32,
+//!     dolor: u32,
 //! }
 //!
 //! fn main() {
-//!     // If we're trying too hard...
-//!     let x = LoremBuilder::default().ipsum(120).build().unwrap_err();
+//!     let errors = LoremBuilder::default().build().unwrap_err();
 //!
-//!     // .. the build will fail:
-//!     assert_eq!(&x, "You'll tire yourself out");
+//!     assert_eq!(errors, vec!["`ipsum` must be initialized", "`dolor` must be initialized"]);
 //! }
 //! ```
 //!
-//! Note:
-//! * Default values are applied _after_ validation, and will therefore not be validated!
+//! Note that this changes the error type of the build method.
 //!
-//! ## Additional Trait Derivations
+//! ### Typed Errors
 //!
-//! You can derive additional traits on the builder, including traits defined by other crates:
+//! With `#[builder(build_fn(typed_error))]`, the build method returns a generated
+//! `LoremBuilderError` instead of a `String`, so you can tell the failure modes apart without
+//! parsing messages:
+//!
+//! * `LoremBuilderError::UninitializedField(&'static str)` carries the name of the field.
+//! * `LoremBuilderError::ValidationError(String)` carries the message of a validator, a
+//!   fallible default or a character set check.
+//!
+//! The enum implements `Display` with the same messages as before, as well as
+//! `std::error::Error` and `From<String>` - so validators can keep returning
+//! `Result<(), String>`. It has the same visibility as the builder. This can't be combined
+//! with `build_fn(collect_errors)`, which always returns `Vec<String>`.
 //!
 //! ```rust
 //! # #[macro_use]
 //! # extern crate derive_builder;
 //! #
-//! #[derive(Builder, Clone)]
-//! #[builder(derive(Debug, PartialEq, Eq))]
-//! pub struct Lorem {
-//!     foo: u8,
-//!     bar: String,
+//! #[derive(Builder, Debug)]
+//! #[builder(build_fn(typed_error))]
+//! struct Lorem {
+//!     ipsum: u32,
 //! }
 //!
 //! fn main() {
-//!    assert_eq!(LoremBuilder::default(), LoremBuilder::default());
+//!     let error = LoremBuilder::default().build().unwrap_err();
+//!
+//!     assert_eq!(error, LoremBuilderError::UninitializedField("ipsum"));
 //! }
 //! ```
 //!
-//! Attributes declared for those traits are _not_ forwarded to the fields on the builder.
-//!
-//! ## Documentation Comments and Attributes
-//!
-//! `#[derive(Builder)]` copies doc comments and attributes (`#[...]`) from your fields
-//! to the according builder fields and setter-methods, if it is one of the following:
-//!
-//! * `/// ...`
-//! * `#[doc = ...]`
-//! * `#[cfg(...)]`
-//! * `#[allow(...)]`
+//! ### Building a Different Type
 //!
-//! The whitelisting minimizes interference with other custom attributes like
-//! those used by Serde, Diesel, or others.
+//! If the annotated struct is only a specification of the value you actually need, use
+//! `#[builder(build_fn(into = "LoremConfig"))]`. The build method then assembles the annotated
+//! struct as usual and returns `Into::into` of it, so you need to implement
+//! `From<Lorem> for LoremConfig`.
 //!
 //! ```rust
 //! # #[macro_use]
 //! # extern crate derive_builder;
 //! #
 //! #[derive(Builder)]
+//! #[builder(build_fn(into = "LoremConfig"))]
 //! struct Lorem {
-//!     /// `ipsum` may be any `String` (be creative).
-//!     ipsum: String,
-//!     #[doc = r"`dolor` is the estimated amount of work."]
-//!     dolor: i32,
-//!     // `#[derive(Builder)]` understands conditional compilation via cfg-attributes,
-//!     // i.e. => "no field = no setter".
-//!     #[cfg(target_os = "macos")]
-//!     #[allow(non_snake_case)]
-//!     Im_a_Mac: bool,
+//!     ipsum: u32,
 //! }
-//! # fn main() {}
-//! ```
 //!
-//! # **`#![no_std]`** Support (on Nightly)
+//! #[derive(Debug, PartialEq)]
+//! struct LoremConfig {
+//!     doubled_ipsum: u32,
+//! }
+//!
+//! impl From<Lorem> for LoremConfig {
+//!     fn from(x: Lorem) -> LoremConfig {
+//!         LoremConfig { doubled_ipsum: 2 * x.ipsum }
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ipsum(21).build().unwrap();
+//!
+//!     assert_eq!(x, LoremConfig { doubled_ipsum: 42 });
+//! }
+//! ```
+//!
+//! To post-process the built value with a function instead, use
+//! `#[builder(build_fn(map = "path::to::fn"))]`. The function takes the annotated struct by
+//! value, e.g. to normalize it. If it returns a different type, name that type via `into`,
+//! which then no longer calls `Into::into`. Validation (see below) still runs before the
+//! struct is assembled.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! use std::sync::Arc;
+//!
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(map = "Arc::new", into = "Arc<Lorem>"))]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! fn main() {
+//!     let x: Arc<Lorem> = LoremBuilder::default().ipsum(42).build().unwrap();
+//!
+//!     assert_eq!(*x, Lorem { ipsum: 42 });
+//! }
+//! ```
+//!
+//! Wrapping the value in a smart pointer is common enough to have a shorthand:
+//! `#[builder(build_fn(wrap = "Arc"))]` returns `Arc<Lorem>`, and the same goes for `Rc` and
+//! `Box`. These three are taken from `alloc` under `#![no_std]`. Any other path is used as is,
+//! as long as it has a `new` function, e.g. `wrap = "std::cell::RefCell"`. The wrapper is
+//! applied last, i.e. after `into` and `map`.
+//!
+//! ### Reusable Builders
+//!
+//! With `#[builder(build_fn(reusable))]` the build method takes `&mut self` regardless of the
+//! builder pattern. Instead of cloning, it moves each value out of the builder, resetting the
+//! builder to its initial state, i.e. unset or `field(default = "...")` - so you can cheaply
+//! configure and build the next value with the same builder.
+//!
+//! Note:
+//! * A validation function (see below) still gets `&FooBuilder`. It runs _before_ any value
+//!   is moved.
+//! * Everything else which may fail is evaluated before any value is moved, too, i.e.
+//!   uninitialized fields and default expressions. So a failed build leaves the builder
+//!   untouched, and default expressions still see all values of the builder.
+//!
+//! ### Building by Reference
+//!
+//! The owned pattern consumes the builder on `build`. With `#[builder(build_fn(by_ref))]` you
+//! get an additional `build_ref(&self)` method, which clones each value instead and leaves the
+//! builder intact - regardless of the builder pattern. All fields must therefore implement
+//! `Clone`. If the build method is renamed, the additional method is called `<name>_ref`.
+//! To pick a name of your own, use `#[builder(build_fn(build_ref = "try_peek"))]`, which
+//! implies `by_ref`.
+//! This can't be combined with a preparation function (see below), which needs mutable access
+//! to the builder.
+//!
+//! ### Building from a Base Value
+//!
+//! Defaults are fixed at compile time. To fill unset fields from a value at hand instead, use
+//! `#[builder(build_fn(with_base))]`. It generates an additional
+//! `build_with_defaults(&self, base: Lorem)` method, which moves every field that has not been
+//! set out of `base` - regardless of any `default` on that field. If the build method is
+//! renamed, the additional method is called `<name>_with_defaults`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(with_base))]
+//! struct Lorem {
+//!     ipsum: String,
+//!     dolor: u32,
+//! }
+//!
+//! # fn main() {
+//! let base = Lorem { ipsum: "ipsum".to_string(), dolor: 1 };
+//! let x = LoremBuilder::default().dolor(2).build_with_defaults(base).unwrap();
+//!
+//! assert_eq!(x, Lorem { ipsum: "ipsum".to_string(), dolor: 2 });
+//! # }
+//! ```
+//!
+//! This is not supported for enum variants.
+//!
+//! ### Building Many Values at Once
+//!
+//! To process a batch of builders, e.g. one per config file, declare `#[builder(build_all)]`.
+//! It generates `LoremBuilder::try_build_all(builders: Vec<LoremBuilder>)`, which calls the
+//! build method on each builder. It returns all values if every builder succeeds, and otherwise
+//! the errors of _all_ failed builders, each paired with its index in `builders`, i.e.
+//! `Result<Vec<Lorem>, Vec<(usize, String)>>`. The error type follows the build method, e.g.
+//! `Vec<String>` with `build_fn(collect_errors)`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_all)]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! # fn main() {
+//! let mut valid = LoremBuilder::default();
+//! valid.ipsum(1);
+//! let errors = LoremBuilder::try_build_all(vec![valid, LoremBuilder::default()]).unwrap_err();
+//!
+//! assert_eq!(errors, vec![(1, "`ipsum` must be initialized".to_string())]);
+//! # }
+//! ```
+//!
+//! ### Patching Existing Values
+//!
+//! For PATCH-style partial updates, `#[builder(build_fn(apply_to = "&mut Lorem"))]` generates
+//! `apply(&self, target: &mut Lorem)`. Instead of building a new value, it assigns only the
+//! fields which have been set on the builder to `target` and leaves all others untouched.
+//! Defaults and validation don't apply. The values are cloned, so all field types must
+//! implement `Clone`. The target type may be any struct with the same field names.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(apply_to = "&mut Lorem"))]
+//! struct Lorem {
+//!     ipsum: String,
+//!     dolor: u32,
+//! }
+//!
+//! fn main() {
+//!     let mut x = Lorem { ipsum: "sit".to_string(), dolor: 1 };
+//!     LoremBuilder::default().dolor(42).apply(&mut x);
+//!
+//!     assert_eq!(x, Lorem { ipsum: "sit".to_string(), dolor: 42 });
+//! }
+//! ```
+//!
+//! ## Pre-Build Validation
+//!
+//! If you're using the provided `build` method, you can declare
+//! `#[builder(build_fn(validate="path::to::fn"))]` to specify a validator function which gets
+//! access to the builder before construction. The path does not need to be fully-qualified, and
+//! will consider `use` statements made at module level. It must be accessible from the scope
+//! where the target struct is declared.
+//!
+//! The provided function must have the signature `(&FooBuilder) -> Result<_, String>`;
+//! the `Ok` variant is not used by the `build` method. The signature is checked up front, so
+//! a mismatch is reported as such, e.g. "expected fn pointer, found fn item".
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(build_fn(validate="Self::validate"))]
+//! struct Lorem {
+//!     pub ipsum: u8,
+//! }
+//!
+//! impl LoremBuilder {
+//!     /// Check that `Lorem` is putting in the right amount of effort.
+//!     fn validate(&self) -> Result<(), String> {
+//!         if let Some(ref ipsum) = self.ipsum {
+//!             match *ipsum {
+//!                 i if i < 20 => Err("Try harder".to_string()),
+//!                 i if i > 100 => Err("You'll tire yourself out".to_string()),
+//!                 _ => Ok(())
+//!             }
+//!         } else {
+//!             Ok(())
+//!         }
+//!     }
+//! }
+//!
+//! fn main() {
+//!     // If we're trying too hard...
+//!     let x = LoremBuilder::default().ipsum(120).build().unwrap_err();
+//!
+//!     // .. the build will fail:
+//!     assert_eq!(&x, "You'll tire yourself out");
+//! }
+//! ```
+//!
+//! Note:
+//! * Default values are applied _after_ validation, and will therefore not be validated!
+//!
+//! If you need to fill in derived fields before the struct is constructed, declare
+//! `#[builder(build_fn(prepare="path::to::fn"))]` with the signature `(&mut FooBuilder)`.
+//! The preparation function is called first, i.e. _before_ validation, so the validator sees
+//! its changes. Since it mutates the builder, the `build` method then takes `&mut self`
+//! instead of `&self` (or `mut self` for the owned pattern).
+//!
+//! To validate a single field, declare `#[builder(validate="path::to::fn")]` on the field
+//! with the signature `(&T) -> Result<(), String>`, where `T` is the field type. Field
+//! validators run in the build method after the struct-level validator and only see values
+//! which have been set. With `build_fn(collect_errors)` their errors are collected along with
+//! all others.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! struct Lorem {
+//!     #[builder(validate="check_percentile")]
+//!     pub ipsum: u8,
+//! }
+//!
+//! fn check_percentile(value: &u8) -> Result<(), String> {
+//!     if *value > 100 {
+//!         return Err(format!("{} is not a percentile", value));
+//!     }
+//!     Ok(())
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ipsum(120).build().unwrap_err();
+//!     assert_eq!(&x, "120 is not a percentile");
+//! }
+//! ```
+//!
+//! ## Additional Trait Derivations
+//!
+//! You can derive additional traits on the builder, including traits defined by other crates:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Clone)]
+//! #[builder(derive(Debug, PartialEq, Eq))]
+//! pub struct Lorem {
+//!     foo: u8,
+//!     bar: String,
+//! }
+//!
+//! fn main() {
+//!    assert_eq!(LoremBuilder::default(), LoremBuilder::default());
+//! }
+//! ```
+//!
+//! Attributes declared for those traits are _not_ forwarded to the fields on the builder.
+//!
+//! To derive the same traits as the target struct, add `inherit` to the list, e.g.
+//! `#[builder(derive(inherit))]`. Traits which are listed explicitly are derived as well.
+//! Keep in mind that the compiler hides the `derive` attribute listing `Builder`, and any
+//! `derive` attribute before it, from the macro. So put the traits to inherit into a separate
+//! `derive` attribute _after_ the one with `Builder`:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! #[derive(Debug, Clone, PartialEq)]
+//! #[builder(derive(inherit))]
+//! pub struct Lorem {
+//!     foo: u8,
+//! }
+//!
+//! fn main() {
+//!    assert_eq!(LoremBuilder::default(), LoremBuilder::default());
+//! }
+//! ```
+//!
+//! Which derives make sense depends on the builder pattern:
+//!
+//! * `mutable` and `immutable` builders always derive `Clone`, since their build methods clone
+//!   the fields. Listing `Clone` again is redundant and triggers a deprecation note.
+//! * `owned` builders don't derive `Clone`, so fields need not be `Clone`. If you derive
+//!   `Clone` anyway, every field type must implement it. `Copy` requires `derive(Clone, Copy)`,
+//!   otherwise the macro panics.
+//!
+//! By default, `Default` is derived on the builder as well. If that conflicts with your own derives, or
+//! the derived `T: Default` bounds on type parameters get in the way, add
+//! `#[builder(derive_default = false)]`. The builder then gets a manual `Default` impl, which
+//! initializes every field to `None` without any bounds.
+//!
+//! ## Constructor
+//!
+//! If you prefer `FooBuilder::new()` over `FooBuilder::default()`, add `#[builder(new)]` to
+//! your struct. The generated `new` function has the same visibility as the builder and
+//! simply delegates to `Default`.
+//!
+//! ## Non-Exhaustive Builders
+//!
+//! To keep other crates from constructing or exhaustively destructuring a public builder, e.g.
+//! with public fields, add `#[builder(non_exhaustive)]`. It marks the builder struct
+//! `#[non_exhaustive]`, so adding a field later isn't a breaking change. This requires Rust 1.40
+//! or newer and composes with `derive(...)` and `vis = "..."`.
+//!
+//! ## Builder Modules
+//!
+//! For crates generating many builders, `#[builder(module = "lorem_builder")]` moves all
+//! generated items into a `mod lorem_builder` next to your struct and re-exports the builder
+//! (plus the update struct and view, if any) with the builder's visibility. Private setters and
+//! fields become `pub(super)`, so they stay accessible from the module of your struct.
+//!
+//! The module itself is private. Within the module of your struct you can refer to e.g.
+//! `lorem_builder::LoremBuilder`, everywhere else use the re-export.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(module = "lorem_builder")]
+//! struct Lorem {
+//!     ipsum: u32,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ipsum(42).build().unwrap();
+//!     let y = lorem_builder::LoremBuilder::default().ipsum(42).build().unwrap();
+//!
+//!     assert_eq!(x, y);
+//! }
+//! ```
+//!
+//! ## Key-Value Pairs
+//!
+//! With `#[builder(from_pairs)]` the builder gets a `from_pairs` constructor and implements
+//! `FromIterator<(String, String)>`, e.g. to populate it from a parsed `.env` file or a list
+//! of CLI arguments. Each key is dispatched to the field of the same name and the value is
+//! parsed via `FromStr`. Unknown keys are ignored, unless you opt into
+//! `#[builder(from_pairs(deny_unknown))]`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(from_pairs)]
+//! struct Lorem {
+//!     ipsum: String,
+//!     dolor: u16,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::from_pairs(vec![("ipsum", "sit"), ("dolor", "42")])
+//!         .unwrap()
+//!         .build()
+//!         .unwrap();
+//!
+//!     assert_eq!(x, Lorem { ipsum: "sit".to_string(), dolor: 42 });
+//! }
+//! ```
+//!
+//! Note:
+//! * `from_pairs` returns an error if a value can't be parsed, whereas `collect()` panics.
+//! * Fields with skipped setters can't be set this way.
+//!
+//! ## Command-Line Arguments
+//!
+//! If you compile `derive_builder` with `--features "clap"`, `#[builder(clap)]` lets a single
+//! struct drive both CLI parsing via [`clap`](https://crates.io/crates/clap) 4 and builder
+//! configuration. The builder gets two additional functions:
+//!
+//! * `LoremBuilder::augment_args(cmd: clap::Command) -> clap::Command` adds one optional
+//!   argument per field, e.g. `--dolor-sit` for a field `dolor_sit`.
+//! * `LoremBuilder::from_matches(&clap::ArgMatches) -> Result<LoremBuilder, String>` populates
+//!   the builder from all arguments that were passed, parsing the values via `FromStr`.
+//!
+//! Your crate must depend on `clap` itself, since the generated code refers to `::clap`.
+//! Fields with skipped setters don't get an argument.
+//!
+//! ## JSON Introspection
+//!
+//! If you compile `derive_builder` with `--features "to_json"`, `#[builder(to_json)]` generates
+//! `LoremBuilder::to_json(&self) -> Result<serde_json::Value, serde_json::Error>`. It returns a
+//! JSON object with one entry per field which has been set, e.g. for config introspection
+//! endpoints. The builder itself doesn't need to implement `Serialize`, only the field types do.
+//!
+//! Unset fields and fields with skipped setters are left out. If any value fails to serialize,
+//! e.g. a map with non-string keys, the error of `serde_json` is returned.
+//!
+//! Your crate must depend on `serde` and `serde_json` itself, and declare
+//! `extern crate serde;` and `extern crate serde_json;` at its root, since the generated code
+//! refers to `::serde::Serialize` and `::serde_json`.
+//!
+//! ## Serializing Builders
+//!
+//! If you compile `derive_builder` with `--features "serde"`,
+//! `#[builder(serde)]` attaches `#[serde(skip_serializing_if = "Option::is_none")]` to every
+//! builder field, so unset fields are left out instead of showing up as `null`. Fields with
+//! skipped setters are marked `#[serde(skip)]`.
+//!
+//! The option only emits these field attributes. The builder must still derive the serde
+//! traits, e.g. `#[builder(serde, derive(Serialize))]`, and your crate must depend on `serde`
+//! with its derive macros itself.
+//!
+//! ## Update Structs
+//!
+//! `#[builder(update_struct = "LoremUpdate")]` additionally generates a plain struct with
+//! the same fields, all wrapped in `Option`, and a `LoremBuilder::apply_update` method. Only
+//! fields which are `Some` in the update overwrite the builder, so you can pass partial
+//! updates around without exposing the builder itself.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(update_struct = "LoremUpdate")]
+//! struct Lorem {
+//!     ipsum: String,
+//!     dolor: u16,
+//! }
+//!
+//! fn main() {
+//!     let update = LoremUpdate { dolor: Some(42), ..Default::default() };
+//!
+//!     let x = LoremBuilder::default()
+//!         .ipsum("sit".to_string())
+//!         .dolor(1)
+//!         .apply_update(update)
+//!         .build()
+//!         .unwrap();
+//!
+//!     assert_eq!(x, Lorem { ipsum: "sit".to_string(), dolor: 42 });
+//! }
+//! ```
+//!
+//! ## Partial Structs
+//!
+//! If you maintain a struct of your own with the same fields, all wrapped in `Option`,
+//! `#[builder(partial = "PartialLorem")]` implements `From` conversions between it and the
+//! builder in both directions, moving the fields one by one.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Debug, PartialEq)]
+//! struct PartialLorem {
+//!     ipsum: Option<String>,
+//! }
+//!
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(partial = "PartialLorem")]
+//! struct Lorem {
+//!     ipsum: String,
+//! }
+//!
+//! fn main() {
+//!     let mut builder = LoremBuilder::from(PartialLorem { ipsum: None });
+//!     builder.ipsum("sit".to_string());
+//!
+//!     let partial: PartialLorem = builder.into();
+//!     assert_eq!(partial, PartialLorem { ipsum: Some("sit".to_string()) });
+//! }
+//! ```
+//!
+//! Fields are matched by name, so a missing or mistyped field of the partial struct is a
+//! compile error in the generated `From` impls. Fields without a setter are not part of the
+//! partial struct. Generic partial structs are supported, e.g. `partial = "PartialLorem<T>"`.
+//!
+//! ## Merging Builders
+//!
+//! To assemble a configuration from multiple sources, `#[builder(merge)]` generates
+//! `LoremBuilder::merge(&mut self, other: &LoremBuilder)`. It copies every field which has
+//! been set on `other`, overriding the values of `self`. Fields with skipped setters are left
+//! untouched.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(merge)]
+//! struct Lorem {
+//!     ipsum: String,
+//!     dolor: u16,
+//! }
+//!
+//! fn main() {
+//!     let mut defaults = LoremBuilder::default();
+//!     defaults.ipsum("sit".to_string()).dolor(1);
+//!     let mut overrides = LoremBuilder::default();
+//!     overrides.dolor(42);
+//!
+//!     let x = defaults.merge(&overrides).build().unwrap();
+//!
+//!     assert_eq!(x, Lorem { ipsum: "sit".to_string(), dolor: 42 });
+//! }
+//! ```
+//!
+//! Since values are cloned out of `other`, all field types must implement `Clone`.
+//!
+//! ## Diffing Against a Target
+//!
+//! To debug configuration drift, `#[builder(diff)]` generates
+//! `LoremBuilder::changed_from(&self, base: &Lorem) -> Vec<&'static str>`. It returns the names
+//! of all fields which have been set on the builder to a value different from the one in
+//! `base`, in declaration order. Unset fields and fields with skipped setters are ignored. All
+//! field types must implement `PartialEq`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! #[builder(diff)]
+//! struct Lorem {
+//!     ipsum: String,
+//!     dolor: u16,
+//! }
+//!
+//! fn main() {
+//!     let base = Lorem { ipsum: "sit".to_string(), dolor: 1 };
+//!     let mut builder = LoremBuilder::default();
+//!     builder.ipsum("sit".to_string()).dolor(42);
+//!
+//!     assert_eq!(builder.changed_from(&base), vec!["dolor"]);
+//! }
+//! ```
+//!
+//! ## Taking Fields
+//!
+//! For staged construction, `#[builder(field_take)]` generates a
+//! `take_ipsum(&mut self) -> Option<T>` method per field. It moves the value out of the builder
+//! via `Option::take` and leaves the field unset, so expensive partial state can be handed off
+//! without cloning. The methods have the same visibility as the builder fields, and fields with
+//! skipped setters don't get one.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! #[builder(field_take)]
+//! struct Lorem {
+//!     ipsum: Vec<String>,
+//! }
+//!
+//! fn main() {
+//!     let mut builder = LoremBuilder::default();
+//!     builder.ipsum(vec!["dolor".to_string()]);
+//!
+//!     assert_eq!(builder.take_ipsum(), Some(vec!["dolor".to_string()]));
+//!     assert_eq!(builder.take_ipsum(), None);
+//! }
+//! ```
+//!
+//! ## Builder Views
+//!
+//! `#[builder(view)]` generates a read-only `LoremBuilderView<'view>` with the same fields as
+//! the builder, each borrowed as `Option<&'view T>`, and a `LoremBuilder::view` method. This
+//! lets you pass the configured-so-far state around without cloning the builder.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! #[builder(view)]
+//! struct Lorem {
+//!     ipsum: String,
+//!     dolor: u16,
+//! }
+//!
+//! fn main() {
+//!     let mut builder = LoremBuilder::default();
+//!     builder.ipsum("sit".to_string());
+//!
+//!     let view = builder.view();
+//!     assert_eq!(view.ipsum, Some(&"sit".to_string()));
+//!     assert_eq!(view.dolor, None);
+//! }
+//! ```
+//!
+//! ## Fallible Getters
+//!
+//! To read a single required field before building, `#[builder(getter(try))]` generates a
+//! `get_ipsum(&self) -> Result<&T, String>` method. It fails with the same error as the build
+//! method if the field has not been set, i.e. the typed `UninitializedField` error with
+//! `build_fn(typed_error)`. Defaults are not taken into account. Use it on a field or on the
+//! whole struct; the getters have the same visibility as the setters.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! struct Lorem {
+//!     #[builder(getter(try))]
+//!     ipsum: String,
+//! }
+//!
+//! fn main() {
+//!     let mut builder = LoremBuilder::default();
+//!     assert_eq!(builder.get_ipsum(), Err("`ipsum` must be initialized".to_string()));
+//!
+//!     builder.ipsum("dolor".to_string());
+//!     assert_eq!(builder.get_ipsum(), Ok(&"dolor".to_string()));
+//! }
+//! ```
+//!
+//! ## Setter Traits
+//!
+//! To abstract over multiple builders, declare their setters in a trait and add
+//! `#[builder(impl_trait = "path::to::Trait")]`. The setters are then emitted in an
+//! `impl Trait for LoremBuilder` instead of the inherent impl of the builder, while the build
+//! method and all other methods stay inherent.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! trait NameSetters {
+//!     fn name(&mut self, value: String) -> &mut Self;
+//! }
+//!
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(impl_trait = "NameSetters")]
+//! struct Lorem {
+//!     name: String,
+//! }
+//!
+//! fn fill<T: NameSetters>(builder: &mut T) {
+//!     builder.name("ipsum".to_string());
+//! }
+//!
+//! fn main() {
+//!     let mut builder = LoremBuilder::default();
+//!     fill(&mut builder);
+//!
+//!     assert_eq!(builder.build().unwrap(), Lorem { name: "ipsum".to_string() });
+//! }
+//! ```
+//!
+//! Constraints:
+//! * The trait must declare every generated setter with exactly the generated signature,
+//!   e.g. `fn name<VALUE: Into<String>>(&mut self, value: VALUE) -> &mut Self` for
+//!   `setter(into)` and `fn name(self, value: String) -> Self` for the owned pattern. This
+//!   includes `try_` setters, `each` setters and aliases.
+//! * Setter visibility is ignored, since trait items can't have their own visibility. Callers
+//!   must have the trait in scope.
+//! * Deprecated aliases are marked `#[deprecated]`, which has no effect in trait impls.
+//!   Deprecate the alias in the trait declaration instead.
+//! * The trait must not declare other items without default implementations.
+//!
+//! ## Custom Setters
+//!
+//! `#[builder(ext_trait)]` generates a sealed trait `LoremBuilderExt`, which is only
+//! implemented by the builder. It has no items of its own, but extends `AsMut<LoremBuilder>`.
+//! This gives you a blessed extension point: declare your custom setters in your own trait and
+//! implement it for all `T: LoremBuilderExt`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder, Debug, PartialEq)]
+//! #[builder(ext_trait)]
+//! struct Lorem {
+//!     ipsum: String,
+//! }
+//!
+//! trait LoremSetters {
+//!     fn shout(&mut self, value: &str) -> &mut Self;
+//! }
+//!
+//! impl<T: LoremBuilderExt> LoremSetters for T {
+//!     fn shout(&mut self, value: &str) -> &mut Self {
+//!         self.as_mut().ipsum(value.to_uppercase());
+//!         self
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().shout("sit").build().unwrap();
+//!
+//!     assert_eq!(x, Lorem { ipsum: "SIT".to_string() });
+//! }
+//! ```
+//!
+//! ## Printing the Configuration
+//!
+//! `#[builder(display)]` implements `Display` for the builder, e.g. for a `--show-config` flag
+//! of your CLI. It prints one line per field with its name and value. Fields which have not
+//! been set are printed as `<default>` if they have a default value, or `<unset>` otherwise.
+//! All field types must implement `Display`; fields with skipped setters are not printed.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! #[builder(display)]
+//! struct Lorem {
+//!     ipsum: String,
+//!     #[builder(default = "42")]
+//!     dolor: u16,
+//!     sit: bool,
+//! }
+//!
+//! fn main() {
+//!     let mut builder = LoremBuilder::default();
+//!     builder.ipsum("amet".to_string());
+//!
+//!     assert_eq!(builder.to_string(), "ipsum: amet\ndolor: <default>\nsit: <unset>\n");
+//! }
+//! ```
+//!
+//! ## Documentation Comments and Attributes
+//!
+//! `#[derive(Builder)]` copies doc comments and attributes (`#[...]`) from your fields
+//! to the according builder fields and setter-methods, if it is one of the following:
+//!
+//! * `/// ...`
+//! * `#[doc = ...]`
+//! * `#[cfg(...)]`
+//! * `#[allow(...)]`
+//!
+//! The whitelisting minimizes interference with other custom attributes like
+//! those used by Serde, Diesel, or others.
+//!
+//! A `#[deprecated(...)]` field deprecates its setters, including aliases and variants like
+//! `try_` setters, so callers get a warning. The builder field itself is not deprecated.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! struct Lorem {
+//!     /// `ipsum` may be any `String` (be creative).
+//!     ipsum: String,
+//!     #[doc = r"`dolor` is the estimated amount of work."]
+//!     dolor: i32,
+//!     // `#[derive(Builder)]` understands conditional compilation via cfg-attributes,
+//!     // i.e. => "no field = no setter".
+//!     #[cfg(target_os = "macos")]
+//!     #[allow(non_snake_case)]
+//!     Im_a_Mac: bool,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! If the setter deserves different documentation than the field, use
+//! `#[builder(setter(doc = "..."))]`. The given string replaces the forwarded doc comments on
+//! the setter, while the builder field keeps the doc comments of the field.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! struct Lorem {
+//!     /// Number of retries before giving up.
+//!     #[builder(setter(doc = "Sets the number of retries, `0` disables retrying."))]
+//!     retries: u32,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! On top of that, the builder struct, its build method and other generated items get doc
+//! comments of their own. `#[builder(no_docs)]` leaves those out, while forwarded doc comments
+//! and `setter(doc)` are still applied. Keep in mind that `#![deny(missing_docs)]` then
+//! rejects a public builder.
+//!
+//! To document the build method yourself, e.g. to describe the checks of a validator, use
+//! `#[builder(build_fn(doc = "..."))]`. It replaces the generated doc comment of the build
+//! method and is applied even with `no_docs`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(Builder)]
+//! #[builder(build_fn(doc = "Builds a new `Lorem`, failing if `ipsum` is empty."))]
+//! struct Lorem {
+//!     ipsum: String,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ## Attribute Namespace
+//!
+//! If another derive on the same struct reads `#[builder(...)]` attributes too, derive
+//! `NamespacedBuilder` instead of `Builder`. It takes the same options, but reads them from
+//! `#[derive_builder(...)]` and ignores any `#[builder(...)]` attributes, leaving them to the
+//! other derive.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate derive_builder;
+//! #
+//! #[derive(NamespacedBuilder, Debug, PartialEq)]
+//! #[derive_builder(pattern = "owned")]
+//! struct Lorem {
+//!     #[derive_builder(setter(into))]
+//!     ipsum: String,
+//! }
+//!
+//! fn main() {
+//!     let x = LoremBuilder::default().ipsum("ipsum").build().unwrap();
+//!
+//!     assert_eq!(x, Lorem { ipsum: "ipsum".to_string() });
+//! }
+//! ```
+//!
+//! # **`#![no_std]`** Support (on Nightly)
 //!
 //! You can activate support for `#![no_std]` by adding `#[builder(no_std)]` to your struct
 //! and `#![feature(collections)] extern crate collections` to your crate.
 //!
 //! The latter requires the _nightly_ toolchain.
 //!
+//! Use `#[builder(no_std(alloc))]` instead to take `String` and `Vec`, e.g. of the error type
+//! and of collection setters, from `extern crate alloc` instead of `collections`.
+//!
 //! # Troubleshooting
 //!
 //! ## Gotchas
 //!
 //! - Tuple structs and unit structs are not supported as they have no field
 //!   names.
-//! - Generic setters introduce a type parameter `VALUE: Into<_>`. Therefore you can't use
-//!  `VALUE` as a type parameter on a generic struct in combination with generic setters.
+//! - Const generic parameters, e.g. `struct Lorem<const N: usize>`, are not supported yet,
+//!   because the underlying version of `syn` can't parse them.
 //! - The `try_setter` attribute and `owned` builder pattern are not compatible in practice;
 //!   an error during building will consume the builder, making it impossible to continue
 //!   construction.
 //! - When re-exporting the underlying struct under a different name, the
 //!   auto-generated documentation will not match.
+//! - Re-exporting or renaming `derive_builder` itself, e.g. in a facade crate, needs no further
+//!   configuration. The generated code never refers to `::derive_builder`, only to `std` or
+//!   `core` - and to `serde`, `serde_json` or `clap` if you opt into the respective features.
+//!   That's why there is no `#[builder(crate = "...")]` option to relocate it; a framework can
+//!   simply `pub use derive_builder::Builder` under a path of its own.
 //! - If derive_builder depends on your crate, and vice versa, then a cyclic
 //!   dependency would occur. To break it you could try to depend on the
 //!   [`derive_builder_core`] crate instead.
@@ -538,7 +1800,7 @@ extern crate derive_builder_core;
 mod log_disabled;
 mod options;
 
-use proc_macro::TokenStream;
+use proc_macro::{TokenStream, TokenTree, Group};
 #[cfg(feature = "logging")]
 use std::sync::{Once, ONCE_INIT};
 use options::{struct_options_from, field_options_from};
@@ -546,50 +1808,340 @@ use options::{struct_options_from, field_options_from};
 #[cfg(feature = "logging")]
 static INIT_LOGGER: Once = ONCE_INIT;
 
+/// Replaces the `r#` prefix of raw identifiers while parsing, because `syn`
+/// doesn't support them.
+const RAW_IDENT_PREFIX: &'static str = "__derive_builder_raw_";
+
+/// Attribute which `NamespacedBuilder` reads its options from, instead of `builder`.
+const NAMESPACED_ATTRIBUTE_IDENT: &'static str = "derive_builder";
+
 #[doc(hidden)]
 #[proc_macro_derive(Builder, attributes(builder))]
 pub fn derive(input: TokenStream) -> TokenStream {
+    expand(input, false)
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(NamespacedBuilder, attributes(derive_builder))]
+pub fn derive_namespaced(input: TokenStream) -> TokenStream {
+    expand(input, true)
+}
+
+/// Expand the derive, reading the options from `#[derive_builder(...)]` instead of
+/// `#[builder(...)]` if `namespaced` is set.
+fn expand(input: TokenStream, namespaced: bool) -> TokenStream {
     #[cfg(feature = "logging")]
     INIT_LOGGER.call_once(|| {
         env_logger::init().unwrap();
     });
 
-    let input = input.to_string();
+    reject_const_generics(&input);
+    let input = escape_raw_idents(input).to_string();
 
-    let ast = syn::parse_macro_input(&input).expect("Couldn't parse item");
+    let mut ast = syn::parse_macro_input(&input).expect("Couldn't parse item");
+    if namespaced {
+        rename_namespaced_attributes(&mut ast.attrs);
+    }
+    match ast.body {
+        syn::Body::Struct(syn::VariantData::Struct(ref mut fields)) => {
+            unescape_raw_idents(fields);
+            if namespaced {
+                for field in fields {
+                    rename_namespaced_attributes(&mut field.attrs);
+                }
+            }
+        },
+        syn::Body::Enum(ref mut variants) => {
+            for variant in variants {
+                if namespaced {
+                    rename_namespaced_attributes(&mut variant.attrs);
+                }
+                if let syn::VariantData::Struct(ref mut fields) = variant.data {
+                    unescape_raw_idents(fields);
+                    if namespaced {
+                        for field in fields {
+                            rename_namespaced_attributes(&mut field.attrs);
+                        }
+                    }
+                }
+            }
+        },
+        _ => {},
+    }
 
-    let result = builder_for_struct(ast).to_string();
+    let tokens = match ast.body {
+        syn::Body::Enum(_) => builders_for_enum(ast),
+        syn::Body::Struct(_) => builder_for_struct(ast, None),
+    };
+    // field names are restored above, this restores all other raw identifiers
+    let result = tokens.to_string().replace(RAW_IDENT_PREFIX, "r#");
     debug!("generated tokens: {}", result);
 
     result.parse().expect(&format!("Couldn't parse `{}` to tokens", result))
 }
 
-fn builder_for_struct(ast: syn::MacroInput) -> quote::Tokens {
+/// Replace the `r#` prefix of raw identifiers with `RAW_IDENT_PREFIX`, e.g.
+/// `r#type` with `__derive_builder_raw_type`.
+fn escape_raw_idents(input: TokenStream) -> TokenStream {
+    input.into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(ref ident) if ident.to_string().starts_with("r#") => {
+                let name = format!("{}{}", RAW_IDENT_PREFIX, &ident.to_string()[2..]);
+                TokenTree::Ident(proc_macro::Ident::new(&name, ident.span()))
+            },
+            TokenTree::Group(ref group) => {
+                let mut escaped = Group::new(group.delimiter(), escape_raw_idents(group.stream()));
+                escaped.set_span(group.span());
+                TokenTree::Group(escaped)
+            },
+            tt => tt,
+        })
+        .collect()
+}
+
+/// Panic if the generics of the item declare a const parameter, e.g. `struct Foo<const N: usize>`,
+/// because `syn` can't parse them.
+fn reject_const_generics(input: &TokenStream) {
+    let mut tokens = input.clone().into_iter()
+        .skip_while(|tt| match *tt {
+            TokenTree::Ident(ref ident) => {
+                let keyword = ident.to_string();
+                keyword != "struct" && keyword != "enum"
+            },
+            _ => true,
+        })
+        // skip the keyword and the name of the item
+        .skip(2);
+    match tokens.next() {
+        Some(TokenTree::Punct(ref punct)) if punct.as_char() == '<' => {},
+        _ => return,
+    }
+
+    // scan up to the matching `>`, skipping over groups like `Fn(u8)` and arrows like `-> u8`
+    let mut depth = 1;
+    let mut previous: Option<TokenTree> = None;
+    for tt in tokens {
+        match tt {
+            TokenTree::Punct(ref punct) if punct.as_char() == '<' => depth += 1,
+            TokenTree::Punct(ref punct) if punct.as_char() == '>' => {
+                let arrow = match previous {
+                    Some(TokenTree::Punct(ref p)) => p.as_char() == '-',
+                    _ => false,
+                };
+                if !arrow {
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+            },
+            TokenTree::Ident(ref ident) if depth == 1 && ident.to_string() == "const" => {
+                let starts_param = match previous {
+                    None => true,
+                    Some(TokenTree::Punct(ref punct)) => punct.as_char() == ',',
+                    _ => false,
+                };
+                if starts_param {
+                    panic!("`#[derive(Builder)]` does not support const generic parameters \
+                            yet, because they can't be parsed by `syn` 0.11.");
+                }
+            },
+            _ => {},
+        }
+        previous = Some(tt);
+    }
+}
+
+/// Restore the `r#` prefix of field names escaped by `escape_raw_idents`.
+fn unescape_raw_idents(fields: &mut [syn::Field]) {
+    for ident in fields.iter_mut().filter_map(|f| f.ident.as_mut()) {
+        if ident.as_ref().starts_with(RAW_IDENT_PREFIX) {
+            let name = format!("r#{}", &ident.as_ref()[RAW_IDENT_PREFIX.len()..]);
+            *ident = syn::Ident::new(name);
+        }
+    }
+}
+
+/// Replace `#[derive_builder(...)]` with `#[builder(...)]`, after dropping the `builder`
+/// attributes of some other derive.
+fn rename_namespaced_attributes(attrs: &mut Vec<syn::Attribute>) {
+    attrs.retain(|attr| attr.value.name() != "builder");
+    let namespaced = attrs.iter_mut()
+        .filter(|attr| attr.value.name() == NAMESPACED_ATTRIBUTE_IDENT);
+    for attr in namespaced {
+        match attr.value {
+            syn::MetaItem::Word(ref mut ident) |
+            syn::MetaItem::List(ref mut ident, _) |
+            syn::MetaItem::NameValue(ref mut ident, _) => *ident = syn::Ident::new("builder"),
+        }
+    }
+}
+
+/// Derive one builder per struct-like variant, e.g. `FooBarBuilder` for
+/// `Foo::Bar { .. }`. Unit and tuple variants are skipped.
+fn builders_for_enum(ast: syn::MacroInput) -> quote::Tokens {
+    debug!("Deriving Builders for enum `{}`.", ast.ident);
+    let variants = match ast.body {
+        syn::Body::Enum(ref variants) => variants.clone(),
+        _ => unreachable!(),
+    };
+
+    let builders: Vec<_> = variants.into_iter()
+        .filter_map(|variant| match variant.data {
+            syn::VariantData::Struct(fields) => {
+                // options of the enum apply to all variants, options of a variant add to them
+                let variant_ast = syn::MacroInput {
+                    ident: ast.ident.clone(),
+                    vis: ast.vis.clone(),
+                    attrs: ast.attrs.iter().chain(&variant.attrs).cloned().collect(),
+                    generics: ast.generics.clone(),
+                    body: syn::Body::Struct(syn::VariantData::Struct(fields)),
+                };
+                Some(builder_for_struct(variant_ast, Some(&variant.ident)))
+            },
+            _ => {
+                trace!("Skipping variant `{}::{}` without named fields.", ast.ident, variant.ident);
+                None
+            },
+        })
+        .collect();
+
+    quote!(#(#builders)*)
+}
+
+fn builder_for_struct(ast: syn::MacroInput, variant: Option<&syn::Ident>) -> quote::Tokens {
     debug!("Deriving Builder for `{}`.", ast.ident);
-    let (opts, field_defaults) = struct_options_from(&ast);
+    let (opts, field_defaults) = struct_options_from(&ast, variant);
 
     let fields = match ast.body {
         syn::Body::Struct(syn::VariantData::Struct(fields)) => fields,
         _ => panic!("`#[derive(Builder)]` can only be used with braced structs"),
     };
 
+    let field_opts: Vec<_> = fields.into_iter()
+        .map(|f| field_options_from(f, &field_defaults))
+        .collect();
+
     let mut builder = opts.as_builder();
     let mut build_fn = opts.as_build_method();
+    let mut build_ref_fn = opts.as_build_ref_method();
+    let mut build_with_base_fn = opts.as_build_with_base_method();
+    let mut from_pairs = opts.as_from_pairs();
+    let mut update_struct = opts.as_update_struct();
+    let mut view = opts.as_builder_view();
+    let mut display_fn = opts.as_display_fn();
+    let mut clap_args = opts.as_clap_args();
+    let mut to_json_fn = opts.as_to_json_fn();
+    let mut merge_fn = opts.as_merge_fn();
+    let mut changed_from_fn = opts.as_changed_from_fn();
+    let mut apply_fn = opts.as_apply_fn();
+    let mut ext_trait = opts.as_ext_trait();
+    let mut error = opts.as_builder_error();
+    let mut target_default = opts.as_target_default();
 
-    builder.doc_comment(format!(include_str!("doc_tpl/builder_struct.md"),
-                                struct_name = ast.ident.as_ref()));
-    build_fn.doc_comment(format!(include_str!("doc_tpl/builder_method.md"),
-                                struct_name = ast.ident.as_ref()));
+    if opts.docs_enabled {
+        let (struct_name, struct_href) = match variant {
+            Some(variant) => (format!("{}::{}", ast.ident, variant),
+                              format!("enum.{}.html#variant.{}", ast.ident, variant)),
+            None => (ast.ident.to_string(), format!("struct.{}.html", ast.ident)),
+        };
+        builder.doc_comment(format!(include_str!("doc_tpl/builder_struct.md"),
+                                    struct_name = struct_name,
+                                    struct_href = struct_href));
+        build_fn.doc_comment(format!(include_str!("doc_tpl/builder_method.md"),
+                                    struct_name = struct_name));
+        build_fn.build_all_doc_comment(format!(include_str!("doc_tpl/builder_all_method.md"),
+                                               struct_name = struct_name));
+        build_ref_fn.doc_comment(format!(include_str!("doc_tpl/builder_ref_method.md"),
+                                         struct_name = struct_name));
+        build_with_base_fn.doc_comment(format!(include_str!("doc_tpl/builder_with_base_method.md"),
+                                               struct_name = struct_name));
+        update_struct.doc_comment(format!("Partial update for [`{}`]({}).",
+                                          struct_name,
+                                          struct_href));
+        view.doc_comment(format!("Borrowed view of [`{builder_name}`](struct.{builder_name}.html).",
+                                 builder_name = opts.builder_ident.as_ref()));
+        ext_trait.doc_comment(format!("Extension point for custom setters of \
+                                       [`{builder_name}`](struct.{builder_name}.html).",
+                                      builder_name = opts.builder_ident.as_ref()));
+        error.doc_comment(format!("Error of the build method of \
+                                   [`{builder_name}`](struct.{builder_name}.html).",
+                                  builder_name = opts.builder_ident.as_ref()));
+    } else {
+        trace!("Skipping generated docs for `{}`.", ast.ident);
+    }
 
-    for f in fields {
-        let f_opts = field_options_from(f, &field_defaults);
+    if let Some(ref doc) = opts.build_fn_doc {
+        build_fn.doc_comment(doc.clone());
+    }
 
+    for f_opts in &field_opts {
         builder.push_field(f_opts.as_builder_field());
         builder.push_setter_fn(f_opts.as_setter());
+        let mut getter = f_opts.as_getter();
+        getter.error_ty = opts.error_ident.as_ref();
+        if opts.docs_enabled {
+            getter.doc_comment(format!("Returns the value of `{}`, failing if it has not been set.",
+                                       f_opts.field_ident));
+        }
+        builder.push_getter_fn(getter);
         build_fn.push_initializer(f_opts.as_initializer());
+        if opts.build_fn_by_ref {
+            build_ref_fn.push_initializer(f_opts.as_by_ref_initializer());
+        }
+        if opts.build_fn_with_base {
+            build_with_base_fn.push_initializer(f_opts.as_with_base_initializer());
+        }
+        from_pairs.push_field(f_opts.as_builder_field());
+        update_struct.push_field(f_opts.as_builder_field());
+        view.push_field(f_opts.as_builder_field());
+        display_fn.push_field(f_opts.as_builder_field(), f_opts.has_default());
+        clap_args.push_field(f_opts.as_builder_field());
+        to_json_fn.push_field(f_opts.as_builder_field(), f_opts.has_default());
+        merge_fn.push_field(f_opts.as_builder_field(), f_opts.has_default());
+        changed_from_fn.push_field(f_opts.as_builder_field(), f_opts.has_default());
+        if opts.take_enabled {
+            builder.push_take_fn(f_opts.as_builder_field());
+        }
+        apply_fn.push_field(f_opts.as_builder_field(), f_opts.has_default());
+        target_default.push_initializer(f_opts.as_initializer());
     }
 
     builder.push_build_fn(build_fn);
+    builder.push_build_fn(build_ref_fn);
+    builder.push_build_fn(build_with_base_fn);
+    builder.push_fields_fn(display_fn);
+    builder.push_fields_fn(to_json_fn);
+    builder.push_fields_fn(merge_fn);
+    builder.push_fields_fn(changed_from_fn);
+    builder.push_fields_fn(apply_fn);
 
-    quote!(#builder)
+    let tokens = quote!(#builder #from_pairs #update_struct #view #clap_args #ext_trait #error
+                        #target_default);
+
+    match opts.module_ident {
+        Some(ref module) => {
+            let vis = &opts.reexport_visibility;
+            let mut reexports = vec![&opts.builder_ident];
+            reexports.extend(opts.update_struct_ident.as_ref());
+            reexports.extend(opts.error_ident.as_ref());
+            if opts.view_enabled {
+                reexports.push(&opts.view_ident);
+            }
+            if opts.ext_trait_enabled {
+                reexports.push(&opts.ext_trait_ident);
+            }
+
+            quote!(
+                mod #module {
+                    use super::*;
+                    #tokens
+                }
+
+                #[allow(unused_imports)]
+                #vis use self::#module::{#(#reexports),*};
+            )
+        },
+        None => tokens,
+    }
 }
@@ -1,14 +1,47 @@
 use syn;
-use options::{OptionsBuilder, OptionsBuilderMode, FieldOptions};
-use derive_builder_core::{DeprecationNotes, Bindings};
+use options::{OptionsBuilder, OptionsBuilderMode, FieldOptions, parse_lit_as_string,
+              parse_lit_as_bool, parse_lit_as_path, visibility_in_submodule,
+              DefaultExpression};
+use derive_builder_core::{DeprecationNotes, Bindings, BuilderPattern, SetterAlias, SetterOnce,
+                          ident_name};
 
 #[derive(Clone, Debug)]
 pub struct FieldMode {
     field_ident: syn::Ident,
     field_type: syn::Ty,
     setter_attrs: Option<Vec<syn::Attribute>>,
+    /// `#[deprecated]` attribute of the target field, forwarded to the setters.
+    setter_deprecated: Option<syn::Attribute>,
     deprecation_notes: DeprecationNotes,
+    depends_on: Vec<syn::Ident>,
+    forbid_default: bool,
+    initial_value: Option<String>,
+    validate_fn: Option<syn::Path>,
+    setter_aliases: Vec<SetterAlias>,
+    setter_each: Option<String>,
+    setter_each_into: bool,
+    setter_each_ty: Option<syn::Ty>,
+    setter_into_ty: Option<syn::Ty>,
+    setter_into_iter: bool,
+    setter_collection: bool,
+    setter_as_ref: bool,
+    setter_append: bool,
+    setter_strip_option: Option<usize>,
+    setter_strip_option_ty: Option<syn::Ty>,
+    setter_option: bool,
+    setter_strip_bool: bool,
+    setter_skip_private: bool,
+    setter_doc: Option<String>,
     pub use_default_struct: bool,
+    pub build_fn_reusable: bool,
+    /// Whether unset `Option` fields fall back to `None`.
+    pub option_as_optional: bool,
+    /// Whether eligible setters are `const fn`, see `#[builder(const_setters)]`.
+    pub const_setters: bool,
+    /// Whether builder fields get `serde` attributes, see `#[builder(serde)]`.
+    pub serde: bool,
+    /// Whether the builder is wrapped in a module, see `visibility_in_submodule`.
+    pub in_submodule: bool,
 }
 
 impl Default for FieldMode {
@@ -17,8 +50,33 @@ impl Default for FieldMode {
            field_ident: syn::Ident::new(""),
            field_type: syn::Ty::Never,
            setter_attrs: None,
+           setter_deprecated: None,
            deprecation_notes: Default::default(),
+           depends_on: vec![],
+           forbid_default: false,
+           initial_value: None,
+           validate_fn: None,
+           setter_aliases: vec![],
+           setter_each: None,
+           setter_each_into: false,
+           setter_each_ty: None,
+           setter_into_ty: None,
+           setter_into_iter: false,
+           setter_collection: false,
+           setter_as_ref: false,
+           setter_append: false,
+           setter_strip_option: None,
+           setter_strip_option_ty: None,
+           setter_option: false,
+           setter_strip_bool: false,
+           setter_skip_private: false,
+           setter_doc: None,
            use_default_struct: false,
+           build_fn_reusable: false,
+           option_as_optional: false,
+           const_setters: false,
+           serde: false,
+           in_submodule: false,
        }
     }
 }
@@ -33,8 +91,33 @@ impl OptionsBuilder<FieldMode> {
             field_ident: ident,
             field_type: f.ty,
             setter_attrs: None,
+            setter_deprecated: None,
             deprecation_notes: Default::default(),
+            depends_on: vec![],
+            forbid_default: false,
+            initial_value: None,
+            validate_fn: None,
+            setter_aliases: vec![],
+            setter_each: None,
+            setter_each_into: false,
+            setter_each_ty: None,
+            setter_into_ty: None,
+            setter_into_iter: false,
+            setter_collection: false,
+            setter_as_ref: false,
+            setter_append: false,
+            setter_strip_option: None,
+            setter_strip_option_ty: None,
+            setter_option: false,
+            setter_strip_bool: false,
+            setter_skip_private: false,
+            setter_doc: None,
             use_default_struct: false,
+            build_fn_reusable: false,
+            option_as_optional: false,
+            const_setters: false,
+            serde: false,
+            in_submodule: false,
         });
 
         builder.parse_attributes(&f.attrs);
@@ -49,6 +132,10 @@ impl OptionsBuilder<FieldMode> {
             })
             .map(|x| x.clone())
             .collect());
+        builder.mode.setter_deprecated = f.attrs
+            .iter()
+            .find(|a| a.style == syn::AttrStyle::Outer && a.value.name() == "deprecated")
+            .cloned();
 
         builder
     }
@@ -69,8 +156,33 @@ impl OptionsBuilder<FieldMode> {
             field_ident: self.mode.field_ident,
             field_type: self.mode.field_type,
             setter_attrs: f!(mode.setter_attrs),
+            setter_deprecated: self.mode.setter_deprecated,
             deprecation_notes: deprecation_notes,
+            depends_on: self.mode.depends_on,
+            forbid_default: self.mode.forbid_default,
+            initial_value: self.mode.initial_value,
+            validate_fn: self.mode.validate_fn,
+            setter_aliases: self.mode.setter_aliases,
+            setter_each: self.mode.setter_each,
+            setter_each_into: self.mode.setter_each_into,
+            setter_each_ty: self.mode.setter_each_ty,
+            setter_into_ty: self.mode.setter_into_ty,
+            setter_into_iter: self.mode.setter_into_iter,
+            setter_collection: self.mode.setter_collection,
+            setter_as_ref: self.mode.setter_as_ref,
+            setter_append: self.mode.setter_append,
+            setter_strip_option: self.mode.setter_strip_option,
+            setter_strip_option_ty: self.mode.setter_strip_option_ty,
+            setter_option: self.mode.setter_option,
+            setter_strip_bool: self.mode.setter_strip_bool,
+            setter_skip_private: self.mode.setter_skip_private,
+            setter_doc: self.mode.setter_doc,
             use_default_struct: self.mode.use_default_struct || defaults.mode.use_default_struct,
+            build_fn_reusable: defaults.mode.build_fn_reusable,
+            option_as_optional: defaults.mode.option_as_optional,
+            const_setters: defaults.mode.const_setters,
+            serde: defaults.mode.serde,
+            in_submodule: defaults.mode.in_submodule,
         };
 
         OptionsBuilder::<FieldMode> {
@@ -82,14 +194,38 @@ impl OptionsBuilder<FieldMode> {
             field_vis: f!(field_vis),
             default_expression: f!(default_expression),
             setter_into: f!(setter_into),
+            setter_auto_into: f!(setter_auto_into),
+            setter_into_only: f!(setter_into_only),
+            setter_impl_trait: f!(setter_impl_trait),
+            setter_with_plain: f!(setter_with_plain),
+            setter_once: f!(setter_once),
+            setter_charset: f!(setter_charset),
+            setter_const: f!(setter_const),
+            setter_try_into: f!(setter_try_into),
+            setter_try_into_error: f!(setter_try_into_error),
+            setter_inline: f!(setter_inline),
             try_setter: f!(try_setter),
+            try_setter_error: f!(try_setter_error),
+            getter_try: f!(getter_try),
             no_std: f!(no_std),
+            no_std_alloc: f!(no_std_alloc),
             mode: mode,
         }
     }
 }
 
 
+impl FieldMode {
+    /// e.g. `ty = "u32"` in `#[builder(setter(strip_option(ty = "u32")))]`
+    fn parse_setter_type(&self, lit: &syn::Lit) -> syn::Ty {
+        let value = parse_lit_as_string(lit).unwrap();
+        syn::parse_type(value)
+            .expect(&format!("Unable to interpret `{}` as type {}.",
+                             value,
+                             self.where_diagnostics()))
+    }
+}
+
 impl OptionsBuilderMode for FieldMode {
     fn parse_builder_name(&mut self, _name: &syn::Lit) {
         panic!("Builder name can only be set on the struct level (but found {}).",
@@ -101,6 +237,11 @@ impl OptionsBuilderMode for FieldMode {
                self.where_diagnostics())
     }
 
+    fn parse_derive_target(&mut self, _nested: &[syn::NestedMetaItem]) {
+        panic!("Target derive declarations can only be added on the struct level \
+                (but found {}).", self.where_diagnostics())
+    }
+
     fn push_deprecation_note<T: Into<String>>(&mut self, x: T) -> &mut Self {
         self.deprecation_notes.push(x.into());
         self
@@ -119,53 +260,707 @@ impl OptionsBuilderMode for FieldMode {
         panic!("Build function options can only be set on the struct level (but found {}).", 
                self.where_diagnostics())
     }
+
+    fn parse_from_pairs_options(&mut self, _: &[syn::NestedMetaItem]) {
+        panic!("`from_pairs` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    /// e.g. `depends_on("a", "b")` in `#[builder(field(depends_on("a", "b")))]`
+    fn parse_depends_on(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing field dependencies `{:?}`", nested);
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::Literal(ref lit) => {
+                    let value = parse_lit_as_string(lit).unwrap();
+                    self.depends_on.push(syn::Ident::new(value.as_str()));
+                },
+                _ => panic!("Field dependencies must be string literals, but found `{:?}` {}.",
+                            x,
+                            self.where_diagnostics())
+            }
+        }
+    }
+
+    fn parse_forbid_default(&mut self) {
+        trace!("Parsing forbid_default.");
+        self.forbid_default = true;
+    }
+
+    /// e.g. `default = "vec![]"` in `#[builder(field(default = "vec![]"))]`
+    fn parse_field_initial_value(&mut self, lit: &syn::Lit) {
+        trace!("Parsing initial value of the builder field `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        if value.is_empty() {
+            panic!(r#"Empty initial values `field(default = "")` are not supported {}."#,
+                   self.where_diagnostics())
+        }
+        self.initial_value = Some(value.clone());
+    }
+
+    /// e.g. `validate = "check_port"` in `#[builder(validate = "check_port")]`
+    fn parse_field_validate(&mut self, lit: &syn::Lit) {
+        trace!("Parsing field validator path `{:?}`", lit);
+        let value = parse_lit_as_path(lit).unwrap();
+        if self.validate_fn.is_some() {
+            panic!("Failed to set field validator to `{}` (already defined) {}.",
+                   quote!(#value),
+                   self.where_diagnostics())
+        }
+        self.validate_fn = Some(value);
+    }
+
+    /// e.g. `alias = "old"` in `#[builder(setter(alias = "old"))]`
+    fn parse_setter_alias(&mut self, lit: &syn::Lit) {
+        trace!("Parsing setter alias `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        self.setter_aliases.push(SetterAlias {
+            ident: syn::Ident::new(value.as_str()),
+            deprecated: true,
+        });
+    }
+
+    /// e.g. `"len", "l", deprecated = false` in
+    /// `#[builder(setter(alias("len", "l", deprecated = false)))]`
+    fn parse_setter_alias_options(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing setter aliases `{:?}`", nested);
+        let mut names = vec![];
+        let mut deprecated = true;
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::Literal(ref lit) => {
+                    let value = parse_lit_as_string(lit).unwrap();
+                    names.push(syn::Ident::new(value.as_str()));
+                },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(ref ident, ref lit))
+                    if ident == "deprecated" => {
+                    deprecated = parse_lit_as_bool(lit).unwrap();
+                },
+                _ => panic!("Unknown alias option `{:?}` {}.", x, self.where_diagnostics())
+            }
+        }
+        if names.is_empty() {
+            panic!("Missing names in `alias(...)` {}.", self.where_diagnostics())
+        }
+        self.setter_aliases.extend(names.into_iter().map(|ident| {
+            SetterAlias {
+                ident: ident,
+                deprecated: deprecated,
+            }
+        }));
+    }
+
+    /// e.g. `doc = "..."` in `#[builder(setter(doc = "Sets the answer."))]`
+    fn parse_setter_doc(&mut self, lit: &syn::Lit) {
+        trace!("Parsing setter doc `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        self.setter_doc = Some(value.clone());
+    }
+
+    /// e.g. `each = "arg"` in `#[builder(setter(each = "arg"))]`
+    fn parse_setter_each(&mut self, lit: &syn::Lit) {
+        trace!("Parsing each setter `{:?}`", lit);
+        if self.setter_each.is_some() {
+            panic!("Multiple `each` setters are not supported (found {}).",
+                   self.where_diagnostics())
+        }
+        let value = parse_lit_as_string(lit).unwrap();
+        self.setter_each = Some(value.clone());
+    }
+
+    /// e.g. `name = "arg", into` in `#[builder(setter(each(name = "arg", into)))]`,
+    /// or `ty = "u32"` to declare the item type.
+    fn parse_setter_each_options(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing each setter options `{:?}`", nested);
+        let mut name = None;
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(ref ident, ref lit))
+                    if ident == "name" => {
+                    name = Some(lit);
+                },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref ident))
+                    if ident == "into" => {
+                    self.setter_each_into = true;
+                },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(ref ident, ref lit))
+                    if ident == "ty" => {
+                    self.setter_each_ty = Some(self.parse_setter_type(lit));
+                },
+                _ => panic!("Unknown each option `{:?}` {}.", x, self.where_diagnostics())
+            }
+        }
+        match name {
+            Some(lit) => self.parse_setter_each(lit),
+            None => panic!("Missing `name` in `each(...)` {}.", self.where_diagnostics()),
+        }
+    }
+
+    /// e.g. `into = "PathBuf"` in `#[builder(setter(into = "PathBuf"))]`
+    fn parse_setter_into_type(&mut self, lit: &syn::Lit) {
+        trace!("Parsing into setter type `{:?}`", lit);
+        self.setter_into_ty = Some(self.parse_setter_type(lit));
+    }
+
+    fn parse_setter_into_iter(&mut self) {
+        trace!("Parsing into_iter setter.");
+        self.setter_into_iter = true;
+    }
+
+    fn parse_setter_collection(&mut self) {
+        trace!("Parsing collection setters.");
+        self.setter_collection = true;
+    }
+
+    fn parse_setter_as_ref(&mut self) {
+        trace!("Parsing as_ref setter.");
+        self.setter_as_ref = true;
+    }
+
+    fn parse_setter_append(&mut self) {
+        trace!("Parsing append setter.");
+        self.setter_append = true;
+    }
+
+    fn parse_setter_strip_option(&mut self) {
+        trace!("Parsing strip_option setter.");
+        self.setter_strip_option = Some(1);
+    }
+
+    fn parse_setter_option(&mut self) {
+        trace!("Parsing option setter.");
+        self.setter_option = true;
+    }
+
+    fn parse_setter_strip_bool(&mut self) {
+        trace!("Parsing strip_bool setter.");
+        self.setter_strip_bool = true;
+    }
+
+    /// e.g. `private` in `#[builder(setter(skip(private)))]`
+    fn parse_setter_skip_options(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing skip setter options `{:?}`", nested);
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref ident))
+                    if ident == "private" => {
+                    self.setter_skip_private = true;
+                },
+                _ => panic!("Unknown skip option `{:?}` {}.", x, self.where_diagnostics())
+            }
+        }
+    }
+
+    /// e.g. `depth = 2` in `#[builder(setter(strip_option(depth = 2)))]`,
+    /// or `ty = "u32"` to declare the inner type.
+    fn parse_setter_strip_option_options(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing strip_option setter options `{:?}`", nested);
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(ref ident,
+                                                                       syn::Lit::Int(depth, _)))
+                    if ident == "depth" => {
+                    if depth == 0 {
+                        panic!("`strip_option` depth must be at least 1 (but found 0 {}).",
+                               self.where_diagnostics())
+                    }
+                    self.setter_strip_option = Some(depth as usize);
+                },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(ref ident, ref lit))
+                    if ident == "ty" => {
+                    self.setter_strip_option_ty = Some(self.parse_setter_type(lit));
+                },
+                _ => panic!("Unknown strip_option option `{:?}` {}.", x, self.where_diagnostics())
+            }
+        }
+        if self.setter_strip_option.is_none() {
+            self.setter_strip_option = Some(1);
+        }
+    }
+
+    fn parse_new_fn(&mut self) {
+        panic!("`new` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_build_all(&mut self) {
+        panic!("`build_all` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_non_exhaustive(&mut self) {
+        panic!("`non_exhaustive` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_view(&mut self) {
+        panic!("`view` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_display(&mut self) {
+        panic!("`display` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_fields_default(&mut self) {
+        panic!("`fields_default` can only be set on the struct level (but found {}), \
+                use `default` instead.",
+               self.where_diagnostics())
+    }
+
+    fn parse_option_as_optional(&mut self) {
+        panic!("`option_as_optional` can only be set on the struct level (but found {}), \
+                use `default` instead.",
+               self.where_diagnostics())
+    }
+
+    fn parse_const_setters(&mut self) {
+        panic!("`const_setters` can only be set on the struct level (but found {}), \
+                use `setter(const_fn)` instead.",
+               self.where_diagnostics())
+    }
+
+    fn parse_serde(&mut self) {
+        panic!("`serde` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_to_json(&mut self) {
+        panic!("`to_json` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_merge(&mut self) {
+        panic!("`merge` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_diff(&mut self) {
+        panic!("`diff` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_field_take(&mut self) {
+        panic!("`field_take` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_clap(&mut self) {
+        panic!("`clap` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_ext_trait(&mut self) {
+        panic!("`ext_trait` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_no_docs(&mut self) {
+        panic!("`no_docs` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_update_struct(&mut self, _lit: &syn::Lit) {
+        panic!("Update struct name can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_partial(&mut self, _lit: &syn::Lit) {
+        panic!("Partial structs can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_derive_default(&mut self, _lit: &syn::Lit) {
+        panic!("`derive_default` can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_impl_trait(&mut self, _lit: &syn::Lit) {
+        panic!("Setter traits can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_module(&mut self, _lit: &syn::Lit) {
+        panic!("Builder module name can only be set on the struct level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_builder_vis(&mut self, _lit: &syn::Lit) {
+        panic!("Builder visibility can only be set on the struct level (but found {}). Use \
+                `setter(vis = \"...\")` or `field(vis = \"...\")` instead.",
+               self.where_diagnostics())
+    }
 }
 
 impl From<OptionsBuilder<FieldMode>> for FieldOptions {
     fn from(b: OptionsBuilder<FieldMode>) -> FieldOptions {
         let field_ident = b.mode.field_ident;
         let field_type = b.mode.field_type;
-        let setter_prefix = b.setter_prefix;
-        let setter_ident = b.setter_name
-            .as_ref()
-            .map(|name| syn::Ident::new(name.as_str()))
-            .unwrap_or_else(|| {
-                match setter_prefix {
-                    Some(ref prefix) if !prefix.is_empty() => {
-                        syn::Ident::new(format!("{}_{}", prefix, field_ident))
-                    },
-                    _ => syn::Ident::new(field_ident.clone()),
-                }});
-                
-        let setter_vis = b.setter_vis.unwrap_or(syn::Visibility::Public);
-
-        let field_vis = b.field_vis.unwrap_or_else(|| if cfg!(feature = "private_fields") {
+        // An explicit name is used verbatim, even if a prefix is inherited from the struct.
+        // An empty prefix on the field opts out of the struct prefix.
+        let setter_ident = match (b.setter_name, b.setter_prefix) {
+            (Some(name), _) => syn::Ident::new(name),
+            (None, Some(ref prefix)) if !prefix.is_empty() => {
+                syn::Ident::new(format!("{}_{}", prefix, ident_name(&field_ident)))
+            },
+            (None, _) => syn::Ident::new(field_ident.clone()),
+        };
+
+        // a skipped setter may still be reachable from within the crate, e.g. from tests
+        let mut setter_vis = if b.mode.setter_skip_private {
+            syn::Visibility::Crate
+        } else {
+            b.setter_vis.unwrap_or(syn::Visibility::Public)
+        };
+
+        let mut field_vis = b.field_vis.unwrap_or_else(|| if cfg!(feature = "private_fields") {
             syn::Visibility::Inherited
         } else {
             setter_vis.clone()
         });
 
+        if b.mode.in_submodule {
+            setter_vis = visibility_in_submodule(setter_vis);
+            field_vis = visibility_in_submodule(field_vis);
+        }
+
+        let option_as_optional = b.mode.option_as_optional &&
+                                 option_inner_type(&field_type).is_some();
+
+        let setter_strip_option_ty = b.mode.setter_strip_option_ty;
+        let setter_strip_option_depth = match b.mode.setter_strip_option {
+            // the `option` setter implies stripping one layer from the primary setter
+            None if b.mode.setter_option => Some(1),
+            depth => depth,
+        };
+        let setter_strip_option = setter_strip_option_depth.map(|depth| {
+            (depth,
+             strip_option_type(&field_ident,
+                               &field_type,
+                               depth,
+                               setter_strip_option_ty.as_ref()))
+        });
+
+        // collection setters operate on the collection inside the stripped `Option` layers
+        let collection_type = setter_strip_option.as_ref()
+            .map(|&(_, ref inner_type)| inner_type)
+            .unwrap_or(&field_type);
+
+        let setter_each_ty = b.mode.setter_each_ty;
+        let setter_each = b.mode.setter_each.as_ref().map(|name| {
+            (syn::Ident::new(name.as_str()),
+             item_type(&field_ident, collection_type, "each", setter_each_ty.as_ref()))
+        });
+        // the `each` setter of a map takes the key and the value as separate arguments
+        let setter_each_key_value = setter_each_ty.is_none() && is_map(collection_type);
+
+        let setter_into_iter = if b.mode.setter_into_iter {
+            Some(item_type(&field_ident, collection_type, "into_iter", None))
+        } else {
+            None
+        };
+
+        let setter_collection = if b.mode.setter_collection {
+            if setter_strip_option.is_some() {
+                panic!("`setter(collection)` can not be combined with `setter(strip_option)` \
+                        (but found on field `{}`).", field_ident)
+            }
+            Some(item_type(&field_ident, &field_type, "collection", None))
+        } else {
+            None
+        };
+
+        let setter_option = if b.mode.setter_option {
+            Some(syn::Ident::new(format!("maybe_{}", setter_ident.as_ref())))
+        } else {
+            None
+        };
+
+        let setter_strip_bool = b.mode.setter_strip_bool;
+        if setter_strip_bool && !is_bool(&field_type) {
+            panic!("`setter(strip_bool)` requires a field of type `bool` \
+                    (but found `{}` on field `{}`).",
+                   quote!(#field_type),
+                   field_ident)
+        }
+        let setter_append = b.mode.setter_append;
+        if setter_append && (setter_strip_option.is_some() || setter_strip_bool) {
+            panic!("`setter(append)` can not be combined with `setter(strip_option)` or \
+                    `setter(strip_bool)` (but found on field `{}`).", field_ident)
+        }
+        // a flag which has never been raised is `false`, a collection without items is empty
+        let implicit_default = (setter_strip_bool || setter_each.is_some() || setter_append ||
+                                b.mode.setter_skip_private) &&
+                               !b.mode.use_default_struct;
+        let default_expression = match b.default_expression {
+            None if implicit_default => Some(DefaultExpression::Trait),
+            default_expression => default_expression,
+        };
+
+        let initial_value = b.mode.initial_value.as_ref().map(|value| {
+            value.parse().expect(&format!("Couldn't parse initial value `{}` of field `{}`",
+                                          value,
+                                          field_ident))
+        });
+
+        let auto_into = b.setter_auto_into.unwrap_or(false);
+        let setter_into = match (b.setter_into, b.setter_into_only) {
+            (Some(setter_into), _) => setter_into,
+            (None, Some(types)) => is_listed_type(&field_type, &types),
+            (None, None) => auto_into && is_listed_type(&field_type, AUTO_INTO_TYPES),
+        };
+
+        let builder_pattern = b.builder_pattern.unwrap_or_default();
+        let setter_try_into = b.setter_try_into.unwrap_or(false);
+        // `const_setters` skips setters which need trait methods or may drop the old value, or
+        // emit deprecation notes, which call a regular fn
+        let const_eligible = b.mode.const_setters && builder_pattern == BuilderPattern::Owned &&
+                             b.mode.deprecation_notes.is_empty() &&
+                             !setter_into && !setter_try_into && !setter_append &&
+                             !b.mode.setter_as_ref && setter_into_iter.is_none() &&
+                             b.setter_once != Some(SetterOnce::Error) &&
+                             is_const_type(&field_type);
+        let setter_const = b.setter_const.unwrap_or(const_eligible);
+        if setter_const && setter_append {
+            panic!("`setter(const_fn)` can not be combined with `setter(append)` \
+                    (but found on field `{}`).", field_ident)
+        }
+        if setter_const && setter_into {
+            panic!("`setter(const_fn)` can not be combined with `setter(into)` \
+                    (but found on field `{}`).", field_ident)
+        }
+        if setter_const && setter_try_into {
+            panic!("`setter(const_fn)` can not be combined with `setter(try_into)` \
+                    (but found on field `{}`).", field_ident)
+        }
+        if setter_const && !b.mode.deprecation_notes.is_empty() {
+            panic!("`setter(const_fn)` can not be combined with deprecated options, e.g. \
+                    `public` (but found on field `{}`).", field_ident)
+        }
+        if setter_const && builder_pattern != BuilderPattern::Owned {
+            panic!("`setter(const_fn)` requires `pattern = \"owned\"` \
+                    (but found on field `{}`).", field_ident)
+        }
+        // these setters modify a value which has already been set
+        if b.setter_once.is_some() && (setter_each.is_some() || setter_collection.is_some()) {
+            panic!("`setter(once)` can not be combined with `setter(each)` or \
+                    `setter(collection)` (but found on field `{}`).", field_ident)
+        }
+
         FieldOptions {
             setter_enabled: b.setter_enabled.unwrap_or(true),
-            builder_pattern: b.builder_pattern.unwrap_or_default(),
+            builder_pattern: builder_pattern,
             setter_ident: setter_ident,
             field_visibility: field_vis,
             setter_visibility: setter_vis,
             field_ident: field_ident,
             field_type: field_type,
-            setter_into: b.setter_into.unwrap_or(false),
+            setter_into: setter_into,
+            setter_into_ty: b.mode.setter_into_ty,
+            setter_impl_trait: b.setter_impl_trait.unwrap_or(false),
+            setter_with_plain: b.setter_with_plain.unwrap_or(false),
+            setter_once: b.setter_once,
+            setter_const: setter_const,
+            setter_try_into: setter_try_into,
+            setter_try_into_error: b.setter_try_into_error,
+            setter_inline: b.setter_inline.unwrap_or(false),
+            setter_charset: b.setter_charset,
             try_setter: b.try_setter.unwrap_or(false),
+            try_setter_error: b.try_setter_error,
+            getter_try: b.getter_try.unwrap_or(false),
             deprecation_notes: b.mode.deprecation_notes,
-            default_expression: b.default_expression,
+            depends_on: b.mode.depends_on,
+            forbid_default: b.mode.forbid_default,
+            initial_value: initial_value,
+            validate_fn: b.mode.validate_fn,
+            setter_aliases: b.mode.setter_aliases,
+            setter_each: setter_each,
+            setter_each_into: b.mode.setter_each_into,
+            setter_each_key_value: setter_each_key_value,
+            setter_into_iter: setter_into_iter,
+            setter_collection: setter_collection,
+            setter_as_ref: b.mode.setter_as_ref,
+            setter_append: setter_append,
+            setter_strip_option: setter_strip_option,
+            setter_option: setter_option,
+            setter_strip_bool: setter_strip_bool,
+            setter_doc: b.mode.setter_doc,
+            default_expression: default_expression,
             use_default_struct: b.mode.use_default_struct,
+            build_fn_reusable: b.mode.build_fn_reusable,
+            option_as_optional: option_as_optional,
+            serde: b.mode.serde,
             bindings: Bindings {
                 no_std: b.no_std.unwrap_or(false),
+                alloc: b.no_std_alloc.unwrap_or(false),
             },
+            setter_deprecated: b.mode.setter_deprecated,
             attrs: b.mode.setter_attrs.unwrap_or_default(),
         }
     }
 }
 
+/// Infer the item type of a collection field for its `each` or `into_iter` setter.
+///
+/// This is the first type parameter, e.g. `T` for `Vec<T>` or `HashSet<T, S>`,
+/// or the key-value pair `(K, V)` for maps like `HashMap<K, V>`. The `each`
+/// setter of a `String` appends a `str`.
+///
+/// The syntactic inference can't see through type aliases, so an explicit
+/// `explicit_ty`, e.g. from `each(ty = "...")`, takes precedence.
+fn item_type(field_ident: &syn::Ident,
+             field_type: &syn::Ty,
+             setter: &str,
+             explicit_ty: Option<&syn::Ty>)
+             -> syn::Ty {
+    if let Some(ty) = explicit_ty {
+        return ty.clone();
+    }
+    if let syn::Ty::Path(None, ref path) = *field_type {
+        if let Some(segment) = path.segments.last() {
+            if segment.ident == "Option" {
+                panic!("Unable to infer the item type for the `{}` setter on field `{}` of type \
+                        `{}`. Use `setter(strip_option)` to add items to the collection \
+                        inside the `Option`.",
+                       setter,
+                       field_ident.as_ref(),
+                       quote!(#field_type))
+            }
+            if setter == "each" && segment.ident == "String" && segment.parameters.is_empty() {
+                return syn::parse_type("str").unwrap();
+            }
+            if let syn::PathParameters::AngleBracketed(ref data) = segment.parameters {
+                let is_map = is_map(field_type);
+                match data.types.len() {
+                    n if is_map && n >= 2 => return syn::Ty::Tup(data.types[..2].to_vec()),
+                    n if !is_map && n >= 1 => return data.types[0].clone(),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    panic!("Unable to infer the item type for the `{}` setter on field `{}`. Expected a \
+            collection like `Vec<T>`, `HashSet<T>` or `HashMap<K, V>`, but found `{}`. \
+            Declare the item type of type aliases via `each(name = \"...\", ty = \"...\")`.",
+           setter,
+           field_ident.as_ref(),
+           quote!(#field_type))
+}
+
+/// Strip `depth` layers of `Option` from the type of a field for its
+/// `strip_option` setter, e.g. `T` for `Option<Option<T>>` and `depth = 2`.
+///
+/// An explicit `explicit_ty`, e.g. from `strip_option(ty = "...")`, takes
+/// precedence for type aliases.
+fn strip_option_type(field_ident: &syn::Ident,
+                     field_type: &syn::Ty,
+                     depth: usize,
+                     explicit_ty: Option<&syn::Ty>)
+                     -> syn::Ty {
+    if let Some(ty) = explicit_ty {
+        return ty.clone();
+    }
+    let mut ty = field_type;
+    for _ in 0..depth {
+        ty = match option_inner_type(ty) {
+            Some(inner) => inner,
+            None => {
+                panic!("Unable to strip {} layer(s) of `Option` for the `strip_option` setter \
+                        on field `{}`, found `{}`. Declare the inner type of type aliases via \
+                        `strip_option(ty = \"...\")`.",
+                       depth,
+                       field_ident.as_ref(),
+                       quote!(#field_type))
+            },
+        };
+    }
+    ty.clone()
+}
+
+/// The type parameter `T` of `Option<T>`, if `ty` is an `Option`.
+fn option_inner_type(ty: &syn::Ty) -> Option<&syn::Ty> {
+    if let syn::Ty::Path(None, ref path) = *ty {
+        if let Some(segment) = path.segments.last() {
+            if let syn::PathParameters::AngleBracketed(ref data) = segment.parameters {
+                if segment.ident == "Option" && data.types.len() == 1 {
+                    return Some(&data.types[0]);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Types which are usually constructed from a borrowed counterpart, e.g. `String` from `&str`.
+const AUTO_INTO_TYPES: &'static [&'static str] = &["String", "PathBuf", "OsString", "CString",
+                                                   "Cow"];
+
+/// Whether `#[builder(setter(auto_into))]` or `#[builder(setter(into(only(...))))]` should
+/// make the setter for `field_type` generic over `Into<_>`.
+///
+/// Only the last path segment is compared, so `String` and `std::string::String` both match.
+fn is_listed_type<S: AsRef<str>>(field_type: &syn::Ty, types: &[S]) -> bool {
+    if let syn::Ty::Path(None, ref path) = *field_type {
+        if let Some(segment) = path.segments.last() {
+            return types.iter().any(|ty| ty.as_ref() == segment.ident.as_ref());
+        }
+    }
+    false
+}
+
+/// Primitive types without drop glue, which `#[builder(const_setters)]` can assign in a
+/// `const fn`.
+const CONST_TYPES: &'static [&'static str] = &["bool", "char", "u8", "u16", "u32", "u64",
+                                               "u128", "usize", "i8", "i16", "i32", "i64",
+                                               "i128", "isize", "f32", "f64"];
+
+/// Whether `field_type` is known to have no drop glue, i.e. a primitive, a reference, or an
+/// `Option`, tuple or array of those.
+///
+/// Any other type might implement `Drop`, which can't run in a `const fn`.
+fn is_const_type(field_type: &syn::Ty) -> bool {
+    match *field_type {
+        syn::Ty::Rptr(..) => true,
+        syn::Ty::Paren(ref ty) |
+        syn::Ty::Array(ref ty, _) => is_const_type(ty),
+        syn::Ty::Tup(ref tys) => tys.iter().all(is_const_type),
+        syn::Ty::Path(None, ref path) => {
+            match option_inner_type(field_type) {
+                Some(ty) => is_const_type(ty),
+                None => {
+                    path.segments.len() == 1 &&
+                    path.segments.last().map_or(false, |s| {
+                        s.parameters.is_empty() && CONST_TYPES.contains(&s.ident.as_ref())
+                    })
+                },
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Whether `field_type` is `bool`, e.g. for `#[builder(setter(strip_bool))]`.
+fn is_bool(field_type: &syn::Ty) -> bool {
+    match *field_type {
+        syn::Ty::Path(None, ref path) => {
+            path.segments.last().map_or(false, |s| s.ident == "bool" && s.parameters.is_empty())
+        },
+        _ => false,
+    }
+}
+
+/// Whether `field_type` looks like a map, e.g. `HashMap<K, V>` or `BTreeMap<K, V>`.
+fn is_map(field_type: &syn::Ty) -> bool {
+    match *field_type {
+        syn::Ty::Path(None, ref path) => {
+            path.segments.last().map_or(false, |s| s.ident.as_ref().ends_with("Map"))
+        },
+        _ => false,
+    }
+}
+
 fn filter_attr(attr: &&syn::Attribute) -> bool {
     if attr.style != syn::AttrStyle::Outer {
         return false
@@ -1,6 +1,7 @@
 use syn;
-use derive_builder_core::{DeprecationNotes, BuilderPattern, Setter, Initializer, BuilderField,
-                          Block, Bindings};
+use derive_builder_core::{DeprecationNotes, BuilderPattern, Setter, SetterAlias, EachSetter,
+                          StripOption, Getter,
+                          SetterOnce, Charset, Initializer, BuilderField, Block, Bindings, doc_comment_from};
 use options::DefaultExpression;
 
 /// These field options define how the builder interacts with the field.
@@ -12,6 +13,30 @@ pub struct FieldOptions {
     pub builder_pattern: BuilderPattern,
     /// The setter name.
     pub setter_ident: syn::Ident,
+    /// Alternative setter names, e.g. `#[builder(setter(alias = "old"))]`.
+    pub setter_aliases: Vec<SetterAlias>,
+    /// Name and item type of the setter adding a single item to a collection field.
+    pub setter_each: Option<(syn::Ident, syn::Ty)>,
+    /// Make the `each` setter generic over `Into<_>`.
+    pub setter_each_into: bool,
+    /// Let the `each` setter of a map take the key and the value separately.
+    pub setter_each_key_value: bool,
+    /// Item type of the collection, making the setter generic over `IntoIterator`.
+    pub setter_into_iter: Option<syn::Ty>,
+    /// Item type of the collection, adding `set_{setter}` and `clear_{setter}`.
+    pub setter_collection: Option<syn::Ty>,
+    /// Make the setter generic over `AsRef<_>` of the borrowed field type.
+    pub setter_as_ref: bool,
+    /// Extend the collection instead of replacing it, e.g. `#[builder(setter(append))]`.
+    pub setter_append: bool,
+    /// Number of `Option` layers to strip from the setter argument and the remaining type.
+    pub setter_strip_option: Option<(usize, syn::Ty)>,
+    /// Name of the additional setter taking an `Option`, e.g. `#[builder(setter(option))]`.
+    pub setter_option: Option<syn::Ident>,
+    /// Take no argument and set a `bool` field to `true`, e.g. `#[builder(setter(strip_bool))]`.
+    pub setter_strip_bool: bool,
+    /// Setter-specific documentation, replacing the forwarded field docs.
+    pub setter_doc: Option<String>,
     /// Visibility of the setter, e.g. `syn::Visibility::Public`.
     pub setter_visibility: syn::Visibility,
     /// Visibility of the field, e.g. `syn::Visibility::Public`.
@@ -20,37 +45,79 @@ pub struct FieldOptions {
     pub default_expression: Option<DefaultExpression>,
     /// Whether the build_method defines a default struct.
     pub use_default_struct: bool,
+    /// Whether the build_method moves values out of the builder for reuse.
+    pub build_fn_reusable: bool,
+    /// Whether the field is an `Option`, which falls back to `None` if it is not set.
+    pub option_as_optional: bool,
+    /// Whether the builder field gets `serde` attributes, e.g. to skip it if it is unset.
+    pub serde: bool,
     /// The field name, may deviate from `setter_ident`.
     pub field_ident: syn::Ident,
     /// The field type.
     pub field_type: syn::Ty,
     /// Make the setter generic over `Into<_>`.
     pub setter_into: bool,
+    /// Conversion type of the `Into<_>` bound, if it differs from the field type.
+    pub setter_into_ty: Option<syn::Ty>,
+    /// Use `impl Into<_>` in argument position instead of a type parameter.
+    pub setter_impl_trait: bool,
+    /// Keep the setter plain and add a `{setter}_into` setter generic over `Into<_>`.
+    pub setter_with_plain: bool,
+    /// Reject a second call to the setter, e.g. `#[builder(setter(once))]`.
+    pub setter_once: Option<SetterOnce>,
+    /// Characters the value must consist of, e.g. `#[builder(setter(ascii))]`.
+    pub setter_charset: Option<Charset>,
+    /// Declare the setter as `const fn`, e.g. `#[builder(setter(const_fn))]`.
+    pub setter_const: bool,
+    /// Make the setter fallible, converting via `TryInto`, e.g. `#[builder(setter(try_into))]`.
+    pub setter_try_into: bool,
+    /// Error type of the fallible setter, e.g. `#[builder(setter(try_into = "MyError"))]`.
+    pub setter_try_into_error: Option<syn::Path>,
+    /// Mark the setter `#[inline]`, e.g. `#[builder(setter(inline))]`.
+    pub setter_inline: bool,
     /// Emit deprecation notes to the user,
     /// e.g. if a deprecated attribute was used in `derive_builder`.
     pub deprecation_notes: DeprecationNotes,
+    /// `#[deprecated]` attribute of the target field, attached to the setters.
+    pub setter_deprecated: Option<syn::Attribute>,
     /// Setter attributes, e.g. `#[allow(non_snake_case)]`.
     pub attrs: Vec<syn::Attribute>,
     /// Bindings to libstd or libcore.
     pub bindings: Bindings,
     /// Enables code generation for the TryInto setter.
     pub try_setter: bool,
+    /// Error type of the TryInto setter, converted via `From` (default to `TryInto::Error`).
+    pub try_setter_error: Option<syn::Path>,
+    /// Enables code generation for the fallible getter, e.g. `#[builder(getter(try))]`.
+    pub getter_try: bool,
+    /// Fields whose defaults must be evaluated before this field's default.
+    pub depends_on: Vec<syn::Ident>,
+    /// Reject a value equal to `Default::default()` in the build method,
+    /// e.g. `#[builder(field(forbid_default))]`.
+    pub forbid_default: bool,
+    /// Initial value of the builder field instead of `None`,
+    /// e.g. `#[builder(field(default = "vec![]"))]`.
+    pub initial_value: Option<Block>,
+    /// Validator for the value, if it has been set, e.g. `#[builder(validate = "check_port")]`.
+    pub validate_fn: Option<syn::Path>,
 }
 
 impl DefaultExpression {
-    pub fn parse_block(&self, no_std: bool) -> Block {
+    pub fn parse_block(&self, bindings: Bindings) -> Block {
         let expr = match *self {
             DefaultExpression::Explicit(ref s) => {
                 if s.is_empty() {
                     panic!(r#"Empty default expressions `default=""` are not supported."#);
                 }
-                s
+                s.clone()
             },
-            DefaultExpression::Trait => if no_std {
-                "::core::default::Default::default()"
-            } else {
-                "::std::default::Default::default()"
+            DefaultExpression::Into(ref s) => {
+                if s.is_empty() {
+                    panic!(r#"Empty default expressions `default(into = "")` are not supported."#);
+                }
+                format!("{}::into({})", bindings.into_trait().as_str(), s)
             },
+            DefaultExpression::Trait => format!("{}::default()", bindings.default_trait().as_str()),
         };
 
         expr.parse().expect(&format!("Couldn't parse default expression `{:?}`", self))
@@ -63,14 +130,59 @@ impl FieldOptions {
         Setter {
             enabled: self.setter_enabled,
             try_setter: self.try_setter,
+            try_setter_error: self.try_setter_error.as_ref(),
+            try_into: self.setter_try_into,
+            try_into_error: self.setter_try_into_error.as_ref(),
             visibility: &self.setter_visibility,
             pattern: self.builder_pattern,
             attrs: &self.attrs,
             ident: &self.setter_ident,
+            aliases: &self.setter_aliases,
             field_ident: &self.field_ident,
             field_type: &self.field_type,
             generic_into: self.setter_into,
+            with_plain: self.setter_with_plain,
+            into_ty: self.setter_into_ty.as_ref(),
+            impl_trait: self.setter_impl_trait,
+            each: self.setter_each.as_ref().map(|&(ref ident, ref item_type)| EachSetter {
+                ident: ident,
+                item_type: item_type,
+                generic_into: self.setter_each_into,
+                key_value: self.setter_each_key_value,
+            }),
+            into_iter: self.setter_into_iter.as_ref(),
+            as_ref: self.setter_as_ref,
+            strip_option: self.setter_strip_option.as_ref().map(|&(depth, ref inner_type)| {
+                StripOption {
+                    depth: depth,
+                    inner_type: inner_type,
+                }
+            }),
+            option: self.setter_option.as_ref(),
+            strip_bool: self.setter_strip_bool,
+            once: self.setter_once,
+            const_fn: self.setter_const,
+            inline: self.setter_inline,
+            collection: self.setter_collection.as_ref(),
+            append: self.setter_append,
+            doc_comment: self.setter_doc.clone().map(doc_comment_from),
             deprecation_notes: &self.deprecation_notes,
+            deprecated: self.setter_deprecated.as_ref(),
+            bindings: self.bindings,
+        }
+    }
+
+    /// Returns a fallible `Getter` according to the options.
+    pub fn as_getter<'a>(&'a self) -> Getter<'a> {
+        Getter {
+            enabled: self.getter_try && self.setter_enabled,
+            visibility: &self.setter_visibility,
+            attrs: &self.attrs,
+            doc_comment: None,
+            field_ident: &self.field_ident,
+            field_type: &self.field_type,
+            // set by the caller, like the error type of the build method
+            error_ty: None,
             bindings: self.bindings,
         }
     }
@@ -84,15 +196,50 @@ impl FieldOptions {
         Initializer {
             setter_enabled: self.setter_enabled,
             field_ident: &self.field_ident,
+            field_type: &self.field_type,
             builder_pattern: self.builder_pattern,
             default_value: self.default_expression
                 .as_ref()
-                .map(|x| { x.parse_block(self.bindings.no_std) }),
+                .map(|x| { x.parse_block(self.bindings) }),
             use_default_struct: self.use_default_struct,
+            option_as_optional: self.option_as_optional,
+            depends_on: &self.depends_on,
+            take_from_builder: self.build_fn_reusable,
+            initial_value: self.initial_value.as_ref(),
+            checked_by_build_fn: false,
+            charset: self.setter_charset,
+            validate_fn: self.validate_fn.as_ref(),
+            forbid_default: self.forbid_default,
+            // set by `BuildMethod::push_initializer`
+            error_ty: None,
+            attrs: &self.attrs,
             bindings: self.bindings,
         }
     }
 
+    /// Returns an `Initializer` for the build method taking `&self`, which
+    /// clones the value regardless of the builder pattern.
+    pub fn as_by_ref_initializer<'a>(&'a self) -> Initializer<'a> {
+        let mut initializer = self.as_initializer();
+        initializer.builder_pattern = BuilderPattern::Immutable;
+        initializer.take_from_builder = false;
+        initializer
+    }
+
+    /// Returns an `Initializer` for the build method taking a base value, which
+    /// moves the value out of the base if it is not set, ignoring any defaults.
+    pub fn as_with_base_initializer<'a>(&'a self) -> Initializer<'a> {
+        let mut initializer = self.as_initializer();
+        initializer.default_value = None;
+        initializer.use_default_struct = true;
+        initializer
+    }
+
+    /// Whether the field falls back to a default value if it is not set.
+    pub fn has_default(&self) -> bool {
+        self.default_expression.is_some() || self.use_default_struct || self.option_as_optional
+    }
+
     /// Returns a `BuilderField` according to the options.
     pub fn as_builder_field<'a>(&'a self) -> BuilderField<'a> {
         BuilderField {
@@ -101,6 +248,8 @@ impl FieldOptions {
             setter_enabled: self.setter_enabled,
             field_visibility: &self.field_visibility,
             attrs: &self.attrs,
+            serde: self.serde,
+            initial_value: self.initial_value.as_ref(),
             bindings: self.bindings,
         }
     }
@@ -12,7 +12,7 @@
 //!    `FieldOptions` instances.
 
 use syn;
-use derive_builder_core::BuilderPattern;
+use derive_builder_core::{BuilderPattern, SetterOnce, Charset, Block};
 
 #[macro_use]
 mod macros;
@@ -30,12 +30,18 @@ pub use self::struct_options::StructOptions;
 #[derive(Debug, Clone)]
 pub enum DefaultExpression {
     Explicit(String),
+    /// Converted into the field type via `Into`, e.g. `default(into = "\"hi\"")`.
+    Into(String),
     Trait,
 }
 
 /// Get the tuple of `StructOptions` and field defaults (`OptionsBuilder<FieldMode>`) from the AST.
-pub fn struct_options_from(ast: &syn::MacroInput) -> (StructOptions, OptionsBuilder<FieldMode>) {
-    OptionsBuilder::<StructMode>::parse(ast).into()
+///
+/// If `variant` is given, `ast` describes that struct-like variant of an enum.
+pub fn struct_options_from(ast: &syn::MacroInput,
+                           variant: Option<&syn::Ident>)
+                           -> (StructOptions, OptionsBuilder<FieldMode>) {
+    OptionsBuilder::<StructMode>::parse(ast, variant).into()
 }
 
 ///  Get the `FieldOptions` for a field with respect to some custom default values.
@@ -57,8 +63,21 @@ pub struct OptionsBuilder<Mode> {
     field_vis: Option<syn::Visibility>,
     default_expression: Option<DefaultExpression>,
     setter_into: Option<bool>,
+    setter_auto_into: Option<bool>,
+    setter_into_only: Option<Vec<String>>,
+    setter_impl_trait: Option<bool>,
+    setter_with_plain: Option<bool>,
+    setter_once: Option<SetterOnce>,
+    setter_charset: Option<Charset>,
+    setter_const: Option<bool>,
+    setter_try_into: Option<bool>,
+    setter_try_into_error: Option<syn::Path>,
+    setter_inline: Option<bool>,
     try_setter: Option<bool>,
+    try_setter_error: Option<syn::Path>,
+    getter_try: Option<bool>,
     no_std: Option<bool>,
+    no_std_alloc: Option<bool>,
     mode: Mode,
 }
 
@@ -66,12 +85,55 @@ pub struct OptionsBuilder<Mode> {
 pub trait OptionsBuilderMode: ::std::fmt::Debug {
     fn parse_builder_name(&mut self, lit: &syn::Lit);
     fn parse_derive(&mut self, nested: &[syn::NestedMetaItem]);
+    fn parse_derive_target(&mut self, nested: &[syn::NestedMetaItem]);
     fn push_deprecation_note<T: Into<String>>(&mut self, x: T) -> &mut Self;
     /// Provide a diagnostic _where_-clause for panics.
     fn where_diagnostics(&self) -> String;
     fn struct_mode(&self) -> bool;
     
     fn parse_build_fn_options(&mut self, nested: &[syn::NestedMetaItem]);
+    fn parse_from_pairs_options(&mut self, nested: &[syn::NestedMetaItem]);
+    fn parse_new_fn(&mut self);
+    fn parse_build_all(&mut self);
+    fn parse_non_exhaustive(&mut self);
+    fn parse_view(&mut self);
+    fn parse_display(&mut self);
+    fn parse_clap(&mut self);
+    fn parse_fields_default(&mut self);
+    fn parse_option_as_optional(&mut self);
+    fn parse_const_setters(&mut self);
+    fn parse_to_json(&mut self);
+    fn parse_serde(&mut self);
+    fn parse_merge(&mut self);
+    fn parse_diff(&mut self);
+    fn parse_field_take(&mut self);
+    fn parse_ext_trait(&mut self);
+    fn parse_no_docs(&mut self);
+    fn parse_depends_on(&mut self, nested: &[syn::NestedMetaItem]);
+    fn parse_forbid_default(&mut self);
+    fn parse_field_initial_value(&mut self, lit: &syn::Lit);
+    fn parse_field_validate(&mut self, lit: &syn::Lit);
+    fn parse_setter_alias(&mut self, lit: &syn::Lit);
+    fn parse_setter_alias_options(&mut self, nested: &[syn::NestedMetaItem]);
+    fn parse_setter_doc(&mut self, lit: &syn::Lit);
+    fn parse_setter_each(&mut self, lit: &syn::Lit);
+    fn parse_setter_into_type(&mut self, lit: &syn::Lit);
+    fn parse_setter_each_options(&mut self, nested: &[syn::NestedMetaItem]);
+    fn parse_setter_strip_option(&mut self);
+    fn parse_setter_option(&mut self);
+    fn parse_setter_strip_bool(&mut self);
+    fn parse_setter_skip_options(&mut self, nested: &[syn::NestedMetaItem]);
+    fn parse_setter_into_iter(&mut self);
+    fn parse_setter_collection(&mut self);
+    fn parse_setter_as_ref(&mut self);
+    fn parse_setter_append(&mut self);
+    fn parse_setter_strip_option_options(&mut self, nested: &[syn::NestedMetaItem]);
+    fn parse_update_struct(&mut self, lit: &syn::Lit);
+    fn parse_partial(&mut self, lit: &syn::Lit);
+    fn parse_derive_default(&mut self, lit: &syn::Lit);
+    fn parse_module(&mut self, lit: &syn::Lit);
+    fn parse_impl_trait(&mut self, lit: &syn::Lit);
+    fn parse_builder_vis(&mut self, lit: &syn::Lit);
 }
 
 impl<Mode> From<Mode> for OptionsBuilder<Mode> {
@@ -83,10 +145,23 @@ impl<Mode> From<Mode> for OptionsBuilder<Mode> {
             setter_name: None,
             setter_vis: None,
             try_setter: None,
+            try_setter_error: None,
+            getter_try: None,
             field_vis: None,
             default_expression: None,
             setter_into: None,
+            setter_auto_into: None,
+            setter_into_only: None,
+            setter_impl_trait: None,
+            setter_with_plain: None,
+            setter_once: None,
+            setter_charset: None,
+            setter_const: None,
+            setter_try_into: None,
+            setter_try_into_error: None,
+            setter_inline: None,
             no_std: None,
+            no_std_alloc: None,
             mode: mode,
         }
     }
@@ -113,24 +188,108 @@ impl<Mode> OptionsBuilder<Mode>
         map: |x: bool| { if x { syn::Visibility::Public } else { syn::Visibility::Inherited } },
     }
 
+    impl_setter!{
+        ident: field_vis,
+        desc: "field visibility",
+        map: |x: syn::Visibility| { x },
+    }
+
     impl_setter!{
         ident: setter_public for setter_vis,
         desc: "setter visibility",
         map: |x: bool| { if x { syn::Visibility::Public } else { syn::Visibility::Inherited } },
     }
 
+    impl_setter!{
+        ident: setter_vis,
+        desc: "setter visibility",
+        map: |x: syn::Visibility| { x },
+    }
+
     impl_setter!{
         ident: setter_into,
         desc: "setter type conversion",
         map: |x: bool| { x },
     }
 
+    impl_setter!{
+        ident: setter_auto_into,
+        desc: "setter type conversion for string-like fields",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: setter_into_only,
+        desc: "setter type conversion for listed types",
+        map: |x: Vec<String>| { x },
+    }
+
+    impl_setter!{
+        ident: setter_impl_trait,
+        desc: "setter `impl Trait` arguments",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: setter_with_plain,
+        desc: "plain setter next to the `Into` setter",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: setter_once,
+        desc: "setter call-once semantics",
+        map: |x: SetterOnce| { x },
+    }
+
+    impl_setter!{
+        ident: setter_charset,
+        desc: "setter charset",
+        map: |x: Charset| { x },
+    }
+
+    impl_setter!{
+        ident: setter_const,
+        desc: "setter `const fn`",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: setter_try_into,
+        desc: "setter `TryInto`",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: setter_try_into_error,
+        desc: "setter `TryInto` error type",
+        map: |x: syn::Path| { x },
+    }
+
+    impl_setter!{
+        ident: setter_inline,
+        desc: "setter `#[inline]`",
+        map: |x: bool| { x },
+    }
+
     impl_setter!{
         ident: try_setter,
         desc: "try_setter activation",
         map: |x: bool| { x },
     }
 
+    impl_setter!{
+        ident: try_setter_error,
+        desc: "try_setter error type",
+        map: |x: syn::Path| { x },
+    }
+
+    impl_setter!{
+        ident: getter_try,
+        desc: "fallible getter activation",
+        map: |x: bool| { x },
+    }
+
     impl_setter!{
         ident: default_expression,
         desc: "default expression",
@@ -143,6 +302,12 @@ impl<Mode> OptionsBuilder<Mode>
         map: |x: bool| { x },
     }
 
+    impl_setter!{
+        ident: no_std_alloc,
+        desc: "no_std alloc support",
+        map: |x: bool| { x },
+    }
+
     impl_setter!{
         ident: setter_prefix,
         desc: "setter prefix",
@@ -230,11 +395,13 @@ impl<Mode> OptionsBuilder<Mode>
     fn parse_builder_options_word(&mut self, ident: &syn::Ident) {
         trace!("Parsing word `{}`", ident.as_ref());
         match ident.as_ref() {
-            "public" => {
-                self.setter_public(true)
-            },
-            "private" => {
-                self.setter_public(false)
+            "public" | "private" => {
+                let where_diagnostics = self.where_diagnostics();
+                self.mode.push_deprecation_note(format!(
+                    "warning: deprecated syntax `#[builder({})]`, \
+                     please use `#[builder(setter({}))]` instead {}.",
+                    ident, ident, where_diagnostics));
+                self.setter_public(ident == "public")
             },
             "setter" => {
                 // setter implicitly enabled
@@ -257,6 +424,65 @@ impl<Mode> OptionsBuilder<Mode>
 
                 self.default_expression(DefaultExpression::Trait)
             },
+            "fields_default" => {
+                self.mode.parse_fields_default()
+            },
+            "option_as_optional" => {
+                self.mode.parse_option_as_optional()
+            },
+            "const_setters" => {
+                self.mode.parse_const_setters()
+            },
+            "from_pairs" => {
+                self.mode.parse_from_pairs_options(&[])
+            },
+            "new" => {
+                self.mode.parse_new_fn()
+            },
+            "build_all" => {
+                self.mode.parse_build_all()
+            },
+            "non_exhaustive" => {
+                self.mode.parse_non_exhaustive()
+            },
+            "view" => {
+                self.mode.parse_view()
+            },
+            "display" => {
+                self.mode.parse_display()
+            },
+            "clap" => {
+                self.mode.parse_clap()
+            },
+            "to_json" => {
+                self.mode.parse_to_json()
+            },
+            "serde" => {
+                self.mode.parse_serde()
+            },
+            "merge" => {
+                self.mode.parse_merge()
+            },
+            "diff" => {
+                self.mode.parse_diff()
+            },
+            "field_take" => {
+                self.mode.parse_field_take()
+            },
+            "ext_trait" => {
+                self.mode.parse_ext_trait()
+            },
+            "no_docs" => {
+                self.mode.parse_no_docs()
+            },
+            "inline" => {
+                if self.mode.struct_mode() {
+                    self.setter_inline(true)
+                } else {
+                    panic!("`inline` can only be set on the struct level (but found {}), \
+                            use `setter(inline)` instead.", self.where_diagnostics())
+                }
+            },
             "no_std" => {
                 if self.mode.struct_mode() {
                     self.no_std(true)
@@ -291,6 +517,27 @@ impl<Mode> OptionsBuilder<Mode>
             "name" => {
                 self.mode.parse_builder_name(lit)
             },
+            "update_struct" => {
+                self.mode.parse_update_struct(lit)
+            },
+            "partial" => {
+                self.mode.parse_partial(lit)
+            },
+            "derive_default" => {
+                self.mode.parse_derive_default(lit)
+            },
+            "module" => {
+                self.mode.parse_module(lit)
+            },
+            "impl_trait" => {
+                self.mode.parse_impl_trait(lit)
+            },
+            "vis" => {
+                self.mode.parse_builder_vis(lit)
+            },
+            "validate" => {
+                self.mode.parse_field_validate(lit)
+            },
             "default" => {
                 self.parse_default_expression(lit)
             },
@@ -318,15 +565,75 @@ impl<Mode> OptionsBuilder<Mode>
             "derive" => {
                 self.mode.parse_derive(nested);
             }
+            "derive_target" => {
+                self.mode.parse_derive_target(nested);
+            }
+            "from_pairs" => {
+                self.mode.parse_from_pairs_options(nested);
+            }
+            "try_setter" => {
+                self.parse_try_setter_options(nested);
+                // try_setter implicitly enabled
+                self.try_setter(true);
+            }
+            "getter" => {
+                self.parse_getter_options(nested);
+            }
             "field" => {
                 self.parse_field_options(nested);
             }
+            "no_std" => {
+                self.parse_no_std_options(nested);
+            }
+            "default" => {
+                self.parse_default_options(nested);
+            }
             _ => {
                 panic!("Unknown option `{}` {}.", ident.as_ref(), self.where_diagnostics())
             }
         }
     }
 
+    /// e.g `alloc` in `#[builder(no_std(alloc))]`
+    fn parse_no_std_options(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing no_std options.");
+        if !self.mode.struct_mode() {
+            panic!("Support for `#![no_std]` can only be set on the struct level \
+                    (but found {}).", self.where_diagnostics())
+        }
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref ident))
+                    if ident == "alloc" => {
+                    self.no_std_alloc(true)
+                },
+                _ => panic!("Unknown no_std option `{:?}`. {}", x, self.where_diagnostics())
+            }
+        }
+        self.no_std(true);
+    }
+
+    /// e.g `into = "\"hi\""` in `#[builder(default(into = "\"hi\""))]`
+    fn parse_default_options(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing default options.");
+        if self.mode.struct_mode() {
+            panic!("Default expressions can only be set on the field level \
+                    (but found {}).", self.where_diagnostics())
+        }
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(ref ident, ref lit))
+                    if ident == "into" => {
+                    trace!("Parsing default expression `{:?}` converted via `Into`", lit);
+                    let value = parse_lit_as_string(lit).unwrap();
+                    self.check_default_expression(value);
+                    self.default_expression(DefaultExpression::Into(value.clone()))
+                },
+                _ => panic!("Unknown default option `{:?}`. {}", x, self.where_diagnostics())
+            }
+        }
+    }
+
     fn parse_field_options(&mut self, nested: &[syn::NestedMetaItem]) {
         trace!("Parsing field options.");
         for x in nested {
@@ -335,14 +642,59 @@ impl<Mode> OptionsBuilder<Mode>
                     match ident.as_ref() {
                         "private" => self.field_public(false),
                         "public" => self.field_public(true),
+                        "forbid_default" => self.mode.parse_forbid_default(),
                         _ => panic!("Unknown field word `{:?}`. {}", ident, self.where_diagnostics())
                     }
                 },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::List(ref ident, ref nested))
+                    if ident == "depends_on" => {
+                    self.mode.parse_depends_on(nested)
+                },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(ref ident, ref lit))
+                    if ident == "vis" => {
+                    trace!("Parsing field visibility `{:?}`", lit);
+                    let value = parse_lit_as_visibility(lit).unwrap();
+                    self.field_vis(value)
+                },
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(ref ident, ref lit))
+                    if ident == "default" => {
+                    self.mode.parse_field_initial_value(lit)
+                },
                 _ => panic!("Unknown field option `{:?}`. {}", x, self.where_diagnostics())
             }
         }
     }
 
+    /// e.g `error = "MyError"` in `#[builder(try_setter(error = "MyError"))]`
+    fn parse_try_setter_options(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing try_setter options.");
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(ref ident, ref lit))
+                    if ident == "error" => {
+                    trace!("Parsing try_setter error type `{:?}`", lit);
+                    let value = parse_lit_as_path(lit).unwrap();
+                    self.try_setter_error(value);
+                },
+                _ => panic!("Unknown try_setter option `{:?}` {}.", x, self.where_diagnostics())
+            }
+        }
+    }
+
+    /// e.g `try` in `#[builder(getter(try))]`
+    fn parse_getter_options(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing getter options.");
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref ident))
+                    if ident == "try" => {
+                    self.getter_try(true)
+                },
+                _ => panic!("Unknown getter option `{:?}` {}.", x, self.where_diagnostics())
+            }
+        }
+    }
+
     /// e.g `skip` in `#[builder(setter(skip))]`
     #[allow(non_snake_case)]
     fn parse_setter_options(&mut self, nested: &[syn::NestedMetaItem]) {
@@ -394,6 +746,55 @@ impl<Mode> OptionsBuilder<Mode>
             "into" => {
                 self.setter_into(true)
             }
+            "auto_into" => {
+                self.setter_auto_into(true)
+            }
+            "impl_trait" => {
+                self.setter_impl_trait(true)
+            }
+            "with_plain" => {
+                self.setter_with_plain(true);
+                // `into` implicitly enabled
+                if self.setter_into.is_none() {
+                    self.setter_into(true)
+                }
+            }
+            "once" => {
+                self.setter_once(SetterOnce::Panic)
+            }
+            "ascii" => {
+                self.setter_charset(Charset::Ascii)
+            }
+            "const_fn" => {
+                self.setter_const(true)
+            }
+            "inline" => {
+                self.setter_inline(true)
+            }
+            "strip_option" => {
+                self.mode.parse_setter_strip_option()
+            }
+            "option" => {
+                self.mode.parse_setter_option()
+            }
+            "strip_bool" => {
+                self.mode.parse_setter_strip_bool()
+            }
+            "try_into" => {
+                self.setter_try_into(true)
+            }
+            "into_iter" => {
+                self.mode.parse_setter_into_iter()
+            }
+            "collection" => {
+                self.mode.parse_setter_collection()
+            }
+            "as_ref" => {
+                self.mode.parse_setter_as_ref()
+            }
+            "append" => {
+                self.mode.parse_setter_append()
+            }
             _ => {
                 panic!("Unknown setter option `{}` {}.", ident.as_ref(), self.where_diagnostics())
             }
@@ -411,6 +812,31 @@ impl<Mode> OptionsBuilder<Mode>
             "name" => {
                 self.parse_setter_name(lit)
             },
+            "alias" => {
+                self.mode.parse_setter_alias(lit)
+            },
+            "vis" => {
+                self.parse_setter_vis(lit)
+            },
+            "doc" => {
+                self.mode.parse_setter_doc(lit)
+            },
+            "each" => {
+                self.mode.parse_setter_each(lit)
+            },
+            "into" => {
+                self.mode.parse_setter_into_type(lit);
+                self.setter_into(true)
+            },
+            "once" => {
+                self.parse_setter_once(lit)
+            },
+            "charset" => {
+                self.parse_setter_charset(lit)
+            },
+            "try_into" => {
+                self.parse_setter_try_into(lit)
+            },
             "skip" => {
                 self.parse_setter_skip(lit)
             },
@@ -425,12 +851,55 @@ impl<Mode> OptionsBuilder<Mode>
     fn parse_setter_options_list(&mut self, ident: &syn::Ident, nested: &[syn::NestedMetaItem]) {
         trace!("Setter Options - Parsing list `{}({:?})`", ident.as_ref(), nested);
         match ident.as_ref() {
+            "each" => {
+                self.mode.parse_setter_each_options(nested)
+            },
+            "strip_option" => {
+                self.mode.parse_setter_strip_option_options(nested)
+            },
+            "alias" => {
+                self.mode.parse_setter_alias_options(nested)
+            },
+            "skip" => {
+                self.mode.parse_setter_skip_options(nested)
+            },
+            "into" => {
+                self.parse_setter_into_options(nested)
+            },
             _ => {
                 panic!("Unknown option `{}` {}.", ident.as_ref(), self.where_diagnostics())
             }
         }
     }
 
+    /// e.g. `only("String", "PathBuf")` in
+    /// `#[builder(setter(into(only("String", "PathBuf"))))]`
+    fn parse_setter_into_options(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing into setter options `{:?}`", nested);
+        if !self.mode.struct_mode() {
+            panic!("`setter(into(only(...)))` can only be set on the struct level \
+                    (but found {}), use `setter(into)` instead.", self.where_diagnostics())
+        }
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::List(ref ident, ref types))
+                    if ident == "only" => {
+                    let types = types.iter()
+                        .map(|x| match *x {
+                            syn::NestedMetaItem::Literal(ref lit) => {
+                                parse_lit_as_string(lit).unwrap().clone()
+                            },
+                            _ => panic!("Types of `into(only(...))` must be string literals, \
+                                         but found `{:?}` {}.", x, self.where_diagnostics())
+                        })
+                        .collect();
+                    self.setter_into_only(types)
+                },
+                _ => panic!("Unknown into option `{:?}` {}.", x, self.where_diagnostics())
+            }
+        }
+    }
+
     fn parse_setter_prefix(&mut self, lit: &syn::Lit) {
         trace!("Parsing prefix `{:?}`", lit);
         let value = parse_lit_as_string(lit).unwrap();
@@ -448,17 +917,42 @@ impl<Mode> OptionsBuilder<Mode>
         }
     }
 
+    fn parse_setter_try_into(&mut self, lit: &syn::Lit) {
+        trace!("Parsing setter `TryInto` error type `{:?}`", lit);
+        let value = parse_lit_as_path(lit).unwrap();
+        self.setter_try_into_error(value);
+        self.setter_try_into(true);
+    }
+
+    fn parse_setter_vis(&mut self, lit: &syn::Lit) {
+        trace!("Parsing setter visibility `{:?}`", lit);
+        let value = parse_lit_as_visibility(lit).unwrap();
+        self.setter_vis(value);
+    }
+
     fn parse_default_expression(&mut self, lit: &syn::Lit) {
         trace!("Parsing default expression `{:?}`", lit);
         let value = parse_lit_as_string(lit).unwrap();
+        self.check_default_expression(value);
         self.default_expression(DefaultExpression::Explicit(value.clone()));
     }
 
+    /// Reject a default expression which can't be parsed while the attribute is at hand,
+    /// instead of failing without any location when the build method is generated.
+    fn check_default_expression(&self, value: &str) {
+        if let Err(err) = value.parse::<Block>() {
+            panic!("Unable to parse default expression `{}` {}: {}",
+                   value,
+                   self.where_diagnostics(),
+                   err)
+        }
+    }
+
     fn parse_builder_pattern(&mut self, lit: &syn::Lit) {
         trace!("Parsing pattern `{:?}`", lit);
         let value = parse_lit_as_string(lit).unwrap();
         match value.as_ref() {
-            "owned" => {
+            "owned" | "move" => {
                 self.builder_pattern(BuilderPattern::Owned)
             },
             "mutable" => {
@@ -473,9 +967,51 @@ impl<Mode> OptionsBuilder<Mode>
         };
     }
 
+    fn parse_setter_once(&mut self, lit: &syn::Lit) {
+        trace!("Parsing setter once `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        match value.as_ref() {
+            "panic" => {
+                self.setter_once(SetterOnce::Panic)
+            },
+            "error" => {
+                self.setter_once(SetterOnce::Error)
+            },
+            _ => {
+                panic!("Unknown setter once value `{}` {}.", value, self.where_diagnostics())
+            }
+        };
+    }
+
+    fn parse_setter_charset(&mut self, lit: &syn::Lit) {
+        trace!("Parsing setter charset `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        match value.as_ref() {
+            "ascii" => {
+                self.setter_charset(Charset::Ascii)
+            },
+            "alphanumeric" => {
+                self.setter_charset(Charset::Alphanumeric)
+            },
+            _ => {
+                panic!("Unknown setter charset `{}` {}.", value, self.where_diagnostics())
+            }
+        };
+    }
+
+    /// e.g. `skip = "true"` or `skip = "42"` in `#[builder(setter(skip = "..."))]`
+    ///
+    /// Any string other than `"true"` or `"false"` is the default expression of the
+    /// skipped field.
     fn parse_setter_skip(&mut self, skip: &syn::Lit) {
         trace!("Parsing skip setter `{:?}`", skip);
-        self.setter_enabled(!parse_lit_as_bool(skip).unwrap());
+        match parse_lit_as_bool(skip) {
+            Ok(skip) => self.setter_enabled(!skip),
+            Err(_) => {
+                self.setter_enabled(false);
+                self.parse_default_expression(skip);
+            },
+        }
     }
 
     /// Provide a diagnostic _where_-clause for panics.
@@ -519,4 +1055,37 @@ fn parse_lit_as_bool(lit: &syn::Lit) -> Result<bool, String> {
 fn parse_lit_as_path(lit: &syn::Lit) -> Result<syn::Path, String> {
     syn::parse_path(parse_lit_as_string(lit)?)
         .or_else(|_| Err(format!("Unable to interpret as path `{:?}`.", lit)))
-}
\ No newline at end of file
+}
+
+/// Parse a visibility like `pub`, `pub(crate)` or `pub(super)`. An empty string
+/// means private.
+///
+/// `syn` has no parser for visibilities on their own, so they are parsed as
+/// part of a dummy item. It doesn't support `pub(in path)` either.
+fn parse_lit_as_visibility(lit: &syn::Lit) -> Result<syn::Visibility, String> {
+    syn::parse_macro_input(&format!("{} struct __Visibility;", parse_lit_as_string(lit)?))
+        .map(|item| item.vis)
+        .or_else(|_| Err(format!("Unable to interpret as visibility `{:?}`, expected e.g. \
+                                  `pub` or `pub(crate)`.", lit)))
+}
+
+/// Translate a visibility from the module of the input struct into a submodule
+/// generated by `#[builder(module = "...")]`, so that the same code can still
+/// access the item.
+///
+/// Private items become `pub(super)`, whereas `pub` and `pub(crate)` are
+/// unaffected. Other restrictions like `pub(super)` would have to be nested
+/// one level deeper, which `syn` can't express, so they fall back to
+/// `pub(crate)`. This is fine, because the module itself is private and the
+/// builder is only reachable via its re-export.
+fn visibility_in_submodule(vis: syn::Visibility) -> syn::Visibility {
+    let parent = || syn::Visibility::Restricted(Box::new(syn::Path::from("super")));
+    match vis {
+        syn::Visibility::Inherited => parent(),
+        syn::Visibility::Restricted(ref path) if *path.as_ref() == syn::Path::from("self") => {
+            parent()
+        },
+        syn::Visibility::Restricted(_) => syn::Visibility::Crate,
+        vis => vis,
+    }
+}
@@ -1,43 +1,129 @@
 use syn;
 use options::{OptionsBuilder, OptionsBuilderMode, parse_lit_as_string, parse_lit_as_bool,
-              parse_lit_as_path, FieldMode, StructOptions};
-use derive_builder_core::{DeprecationNotes, Bindings};
+              parse_lit_as_path, parse_lit_as_visibility, visibility_in_submodule, FieldMode,
+              StructOptions, DefaultExpression};
+use derive_builder_core::{DeprecationNotes, Bindings, BuilderPattern};
 
 #[derive(Debug, Clone)]
 pub struct StructMode {
     build_fn_name: Option<String>,
     build_fn_enabled: Option<bool>,
+    build_fn_reusable: Option<bool>,
+    build_fn_into: Option<syn::Ty>,
+    build_fn_apply_to: Option<syn::Ty>,
+    build_fn_collect_errors: Option<bool>,
+    build_fn_typed_error: Option<bool>,
+    build_fn_by_ref: Option<bool>,
+    build_ref_fn_name: Option<String>,
+    build_fn_with_base: Option<bool>,
     build_target_name: String,
+    build_target_variant: Option<String>,
     build_target_generics: syn::Generics,
     build_target_vis: syn::Visibility,
     builder_name: Option<String>,
     builder_vis: Option<syn::Visibility>,
     derive_traits: Option<Vec<syn::Ident>>,
+    derive_inherit: Option<bool>,
+    /// Traits of the `#[derive(...)]` attributes on the target, if `derive_inherit` is set.
+    inherited_derives: Vec<syn::Ident>,
+    derive_default: Option<bool>,
+    /// Whether to implement `Default` for the target, see `#[builder(derive_target(Default))]`.
+    target_default: Option<bool>,
     deprecation_notes: DeprecationNotes,
     validate_fn: Option<syn::Path>,
+    prepare_fn: Option<syn::Path>,
+    map_fn: Option<syn::Path>,
+    build_fn_wrap: Option<syn::Path>,
+    build_fn_doc: Option<String>,
+    build_fn_vis: Option<syn::Visibility>,
+    setter_trait: Option<syn::Path>,
+    from_pairs_enabled: Option<bool>,
+    from_pairs_deny_unknown: Option<bool>,
+    new_fn: Option<bool>,
+    build_all: Option<bool>,
+    non_exhaustive: Option<bool>,
+    view_enabled: Option<bool>,
+    display_enabled: Option<bool>,
+    clap_enabled: Option<bool>,
+    fields_default: Option<bool>,
+    option_as_optional: Option<bool>,
+    const_setters: Option<bool>,
+    to_json_enabled: Option<bool>,
+    serde_enabled: Option<bool>,
+    merge_enabled: Option<bool>,
+    diff_enabled: Option<bool>,
+    take_enabled: Option<bool>,
+    ext_trait_enabled: Option<bool>,
+    docs_enabled: Option<bool>,
+    module_name: Option<String>,
+    update_struct_name: Option<String>,
+    partial_ty: Option<syn::Ty>,
     struct_size_hint: usize,
 }
 
 impl OptionsBuilder<StructMode> {
-    pub fn parse(ast: &syn::MacroInput) -> Self {
+    pub fn parse(ast: &syn::MacroInput, variant: Option<&syn::Ident>) -> Self {
         trace!("Parsing struct `{}`.", ast.ident.as_ref());
 
         // Note: Set `build_target_name` _before_ parsing attributes, for better diagnostics!
         let mut builder = Self::from(StructMode {
             build_target_name: ast.ident.as_ref().to_string(),
+            build_target_variant: variant.map(|v| v.as_ref().to_string()),
             build_target_generics: ast.generics.clone(),
             build_target_vis: ast.vis.clone(),
             builder_name: None,
             builder_vis: None,
             build_fn_enabled: None,
+            build_fn_reusable: None,
+            build_fn_into: None,
+            build_fn_apply_to: None,
+            build_fn_collect_errors: None,
+            build_fn_typed_error: None,
+            build_fn_by_ref: None,
+            build_ref_fn_name: None,
+            build_fn_with_base: None,
             build_fn_name: None,
             derive_traits: None,
+            derive_inherit: None,
+            inherited_derives: vec![],
+            derive_default: None,
+            target_default: None,
             deprecation_notes: Default::default(),
             validate_fn: None,
+            prepare_fn: None,
+            map_fn: None,
+            build_fn_wrap: None,
+            build_fn_doc: None,
+            build_fn_vis: None,
+            setter_trait: None,
+            from_pairs_enabled: None,
+            from_pairs_deny_unknown: None,
+            new_fn: None,
+            build_all: None,
+            non_exhaustive: None,
+            view_enabled: None,
+            display_enabled: None,
+            clap_enabled: None,
+            fields_default: None,
+            option_as_optional: None,
+            const_setters: None,
+            to_json_enabled: None,
+            serde_enabled: None,
+            merge_enabled: None,
+            diff_enabled: None,
+            take_enabled: None,
+            ext_trait_enabled: None,
+            docs_enabled: None,
+            module_name: None,
+            update_struct_name: None,
+            partial_ty: None,
             struct_size_hint: 0,
         });
 
         builder.parse_attributes(&ast.attrs);
+        if builder.mode.derive_inherit.unwrap_or(false) {
+            builder.mode.parse_inherited_derives(&ast.attrs);
+        }
 
         builder
     }
@@ -50,6 +136,12 @@ impl StructMode {
         map: |x: String| { x },
     }
 
+    impl_setter!{
+        ident: builder_vis,
+        desc: "builder visibility",
+        map: |x: syn::Visibility| { x },
+    }
+
     impl_setter!{
         ident: build_fn_name,
         desc: "build function name",
@@ -62,18 +154,266 @@ impl StructMode {
         map: |x: bool| { x },
     }
 
+    impl_setter!{
+        ident: build_fn_reusable,
+        desc: "build function reusability",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: build_fn_collect_errors,
+        desc: "build function error collection",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: build_fn_typed_error,
+        desc: "build function typed error",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: build_fn_by_ref,
+        desc: "build function by reference",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: build_ref_fn_name,
+        desc: "build by reference function name",
+        map: |x: String| { x },
+    }
+
+    impl_setter!{
+        ident: build_fn_with_base,
+        desc: "build function with base value",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: build_fn_into,
+        desc: "build function target type",
+        map: |x: syn::Ty| { x },
+    }
+
+    impl_setter!{
+        ident: build_fn_apply_to,
+        desc: "apply function target type",
+        map: |x: syn::Ty| { x },
+    }
+
     impl_setter!{
         ident: validate_fn,
         desc: "validator function path",
         map: |x: syn::Path| { x },
     }
 
+    impl_setter!{
+        ident: prepare_fn,
+        desc: "preparation function path",
+        map: |x: syn::Path| { x },
+    }
+
+    impl_setter!{
+        ident: map_fn,
+        desc: "build function mapping path",
+        map: |x: syn::Path| { x },
+    }
+
+    impl_setter!{
+        ident: build_fn_wrap,
+        desc: "build function wrapper type",
+        map: |x: syn::Path| { x },
+    }
+
+    impl_setter!{
+        ident: build_fn_doc,
+        desc: "build function doc comment",
+        map: |x: String| { x },
+    }
+
+    impl_setter!{
+        ident: build_fn_vis,
+        desc: "build function visibility",
+        map: |x: syn::Visibility| { x },
+    }
+
+    impl_setter!{
+        ident: setter_trait,
+        desc: "setter trait path",
+        map: |x: syn::Path| { x },
+    }
+
+    impl_setter!{
+        ident: from_pairs_enabled,
+        desc: "from_pairs activation",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: from_pairs_deny_unknown,
+        desc: "from_pairs unknown key handling",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: new_fn,
+        desc: "new constructor",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: build_all,
+        desc: "build method for a `Vec` of builders",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: non_exhaustive,
+        desc: "non-exhaustive builder",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: view_enabled,
+        desc: "builder view activation",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: display_enabled,
+        desc: "builder `Display` activation",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: clap_enabled,
+        desc: "`clap` interop activation",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: fields_default,
+        desc: "field defaults",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: option_as_optional,
+        desc: "optional `Option` fields",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: const_setters,
+        desc: "const setters where possible",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: serde_enabled,
+        desc: "serde field attributes",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: to_json_enabled,
+        desc: "`to_json` activation",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: merge_enabled,
+        desc: "`merge` activation",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: diff_enabled,
+        desc: "`changed_from` activation",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: take_enabled,
+        desc: "`take_*` activation",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: ext_trait_enabled,
+        desc: "extension trait activation",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: docs_enabled,
+        desc: "generated docs activation",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: module_name,
+        desc: "builder module name",
+        map: |x: String| { x },
+    }
+
+    impl_setter!{
+        ident: update_struct_name,
+        desc: "update struct name",
+        map: |x: String| { x },
+    }
+
+    impl_setter!{
+        ident: partial_ty,
+        desc: "partial struct type",
+        map: |x: syn::Ty| { x },
+    }
+
+    impl_setter!{
+        ident: derive_default,
+        desc: "`Default` derivation",
+        map: |x: bool| { x },
+    }
+
     impl_setter!{
         ident: derive_traits,
         desc: "derive traits",
         map: |x: Vec<syn::Ident>| { x },
     }
 
+    impl_setter!{
+        ident: derive_inherit,
+        desc: "derive inheritance",
+        map: |x: bool| { x },
+    }
+
+    impl_setter!{
+        ident: target_default,
+        desc: "`Default` impl of the target",
+        map: |x: bool| { x },
+    }
+
+    /// Collect the traits of the `#[derive(...)]` attributes on the target struct.
+    ///
+    /// Note: The compiler hides the `derive` attribute listing `Builder` and all `derive`
+    /// attributes before it from the macro, so only the ones after it are found.
+    fn parse_inherited_derives(&mut self, attrs: &[syn::Attribute]) {
+        trace!("Parsing inherited derives.");
+        for attr in attrs {
+            if let syn::MetaItem::List(ref ident, ref nested) = attr.value {
+                if ident != "derive" {
+                    continue;
+                }
+                for x in nested {
+                    if let syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref tr)) = *x {
+                        self.inherited_derives.push(tr.clone());
+                    }
+                }
+            }
+        }
+    }
+
     #[allow(non_snake_case)]
     fn parse_build_fn_options_metaItem(&mut self, meta_item: &syn::MetaItem) {
         trace!("Build Method Options - Parsing MetaItem `{:?}`.", meta_item);
@@ -103,6 +443,30 @@ impl StructMode {
             "validate" => {
                 self.parse_build_fn_validate(lit)
             },
+            "prepare" => {
+                self.parse_build_fn_prepare(lit)
+            },
+            "into" => {
+                self.parse_build_fn_into(lit)
+            },
+            "map" => {
+                self.parse_build_fn_map(lit)
+            },
+            "build_ref" => {
+                self.parse_build_ref_fn_name(lit)
+            },
+            "wrap" => {
+                self.parse_build_fn_wrap(lit)
+            },
+            "apply_to" => {
+                self.parse_build_fn_apply_to(lit)
+            },
+            "doc" => {
+                self.parse_build_fn_doc(lit)
+            },
+            "vis" => {
+                self.parse_build_fn_vis(lit)
+            },
             _ => {
                 panic!("Unknown build_fn option `{}` {}.", ident.as_ref(), self.where_diagnostics())
             }
@@ -116,6 +480,21 @@ impl StructMode {
             "skip" => {
                 self.build_fn_enabled(false);
             }
+            "reusable" => {
+                self.build_fn_reusable(true);
+            }
+            "collect_errors" => {
+                self.build_fn_collect_errors(true);
+            }
+            "typed_error" => {
+                self.build_fn_typed_error(true);
+            }
+            "by_ref" => {
+                self.build_fn_by_ref(true);
+            }
+            "with_base" => {
+                self.build_fn_with_base(true);
+            }
             _ => {
                 panic!("Unknown build_fn option `{}` {}.", ident.as_ref(), self.where_diagnostics())
             }
@@ -138,16 +517,83 @@ impl StructMode {
         self.build_fn_name(value.clone())
     }
 
+    fn parse_build_ref_fn_name(&mut self, lit: &syn::Lit) {
+        trace!("Parsing build by reference function name `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        self.build_ref_fn_name(value.clone());
+        // build by reference implicitly enabled
+        if self.build_fn_by_ref.is_none() {
+            self.build_fn_by_ref(true);
+        }
+    }
+
     #[allow(dead_code,unused_variables)]
     fn parse_build_fn_skip(&mut self, skip: &syn::Lit) {
         self.build_fn_enabled(!parse_lit_as_bool(skip).unwrap());
     }
 
+    fn parse_build_fn_into(&mut self, lit: &syn::Lit) {
+        trace!("Parsing build function target type `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        let ty = syn::parse_type(value)
+            .expect(&format!("Unable to interpret `{}` as type {}.",
+                             value,
+                             self.where_diagnostics()));
+        self.build_fn_into(ty);
+    }
+
+    fn parse_build_fn_apply_to(&mut self, lit: &syn::Lit) {
+        trace!("Parsing apply function target type `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        let ty = syn::parse_type(value)
+            .expect(&format!("Unable to interpret `{}` as type {}.",
+                             value,
+                             self.where_diagnostics()));
+        match ty {
+            syn::Ty::Rptr(_, ref mut_ty) if mut_ty.mutability == syn::Mutability::Mutable => {},
+            _ => panic!("`build_fn(apply_to)` expects a mutable reference like `&mut Foo`, \
+                         but found `{}` {}.",
+                        value,
+                        self.where_diagnostics()),
+        }
+        self.build_fn_apply_to(ty);
+    }
+
     fn parse_build_fn_validate(&mut self, lit: &syn::Lit) {
         trace!("Parsing build function validate path `{:?}`", lit);
         let value = parse_lit_as_path(lit).unwrap();
         self.validate_fn(value);
     }
+
+    fn parse_build_fn_prepare(&mut self, lit: &syn::Lit) {
+        trace!("Parsing build function prepare path `{:?}`", lit);
+        let value = parse_lit_as_path(lit).unwrap();
+        self.prepare_fn(value);
+    }
+
+    fn parse_build_fn_map(&mut self, lit: &syn::Lit) {
+        trace!("Parsing build function map path `{:?}`", lit);
+        let value = parse_lit_as_path(lit).unwrap();
+        self.map_fn(value);
+    }
+
+    fn parse_build_fn_wrap(&mut self, lit: &syn::Lit) {
+        trace!("Parsing build function wrapper path `{:?}`", lit);
+        let value = parse_lit_as_path(lit).unwrap();
+        self.build_fn_wrap(value);
+    }
+
+    fn parse_build_fn_vis(&mut self, lit: &syn::Lit) {
+        trace!("Parsing build function visibility `{:?}`", lit);
+        let value = parse_lit_as_visibility(lit).unwrap();
+        self.build_fn_vis(value);
+    }
+
+    fn parse_build_fn_doc(&mut self, lit: &syn::Lit) {
+        trace!("Parsing build function doc comment `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        self.build_fn_doc(value.clone());
+    }
 }
 
 impl OptionsBuilderMode for StructMode {
@@ -173,6 +619,257 @@ impl OptionsBuilderMode for StructMode {
         }
     }
 
+    /// e.g. `deny_unknown` in `#[builder(from_pairs(deny_unknown))]`
+    fn parse_from_pairs_options(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing from_pairs options.");
+        self.from_pairs_enabled(true);
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref ident)) => {
+                    match ident.as_ref() {
+                        "deny_unknown" => self.from_pairs_deny_unknown(true),
+                        _ => panic!("Unknown from_pairs option `{}` {}.",
+                                    ident.as_ref(),
+                                    self.where_diagnostics()),
+                    }
+                },
+                _ => panic!("Unknown from_pairs option `{:?}` {}.", x, self.where_diagnostics())
+            }
+        }
+    }
+
+    fn parse_depends_on(&mut self, _nested: &[syn::NestedMetaItem]) {
+        panic!("Field dependencies can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_forbid_default(&mut self) {
+        panic!("`forbid_default` can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_field_initial_value(&mut self, _lit: &syn::Lit) {
+        panic!("Initial values of builder fields can only be set on the field level \
+                (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_field_validate(&mut self, _lit: &syn::Lit) {
+        panic!("Field validators can only be set on the field level (but found {}). Use \
+                `build_fn(validate = \"...\")` to validate the whole builder.",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_alias(&mut self, _lit: &syn::Lit) {
+        panic!("Setter aliases can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_alias_options(&mut self, _nested: &[syn::NestedMetaItem]) {
+        panic!("Setter aliases can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_doc(&mut self, _lit: &syn::Lit) {
+        panic!("Setter docs can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_each(&mut self, _lit: &syn::Lit) {
+        panic!("`each` setters can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_into_type(&mut self, _lit: &syn::Lit) {
+        panic!("`setter(into = \"...\")` can only be set on the field level (but found {}), \
+                use `setter(into)` instead.",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_each_options(&mut self, _nested: &[syn::NestedMetaItem]) {
+        panic!("`each` setters can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_into_iter(&mut self) {
+        panic!("`into_iter` setters can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_as_ref(&mut self) {
+        panic!("`as_ref` setters can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_collection(&mut self) {
+        panic!("`collection` setters can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_append(&mut self) {
+        panic!("`append` setters can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_strip_option(&mut self) {
+        panic!("`strip_option` can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_option(&mut self) {
+        panic!("`option` setters can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_strip_bool(&mut self) {
+        panic!("`strip_bool` can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_skip_options(&mut self, _nested: &[syn::NestedMetaItem]) {
+        panic!("`skip(...)` can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_setter_strip_option_options(&mut self, _nested: &[syn::NestedMetaItem]) {
+        panic!("`strip_option` can only be set on the field level (but found {}).",
+               self.where_diagnostics())
+    }
+
+    fn parse_new_fn(&mut self) {
+        trace!("Parsing new constructor.");
+        self.new_fn(true);
+    }
+
+    fn parse_build_all(&mut self) {
+        trace!("Parsing `try_build_all`.");
+        self.build_all(true);
+    }
+
+    fn parse_non_exhaustive(&mut self) {
+        trace!("Parsing non-exhaustive builder.");
+        self.non_exhaustive(true);
+    }
+
+    fn parse_view(&mut self) {
+        trace!("Parsing builder view.");
+        self.view_enabled(true);
+    }
+
+    fn parse_display(&mut self) {
+        trace!("Parsing builder `Display`.");
+        self.display_enabled(true);
+    }
+
+    fn parse_clap(&mut self) {
+        trace!("Parsing `clap` interop.");
+        if !cfg!(feature = "clap") {
+            panic!("`#[builder(clap)]` requires the `clap` feature of `derive_builder` \
+                    (found {}).", self.where_diagnostics());
+        }
+        self.clap_enabled(true);
+    }
+
+    fn parse_fields_default(&mut self) {
+        trace!("Parsing field defaults.");
+        self.fields_default(true);
+    }
+
+    fn parse_option_as_optional(&mut self) {
+        trace!("Parsing optional `Option` fields.");
+        self.option_as_optional(true);
+    }
+
+    fn parse_const_setters(&mut self) {
+        trace!("Parsing const setters.");
+        self.const_setters(true);
+    }
+
+    fn parse_to_json(&mut self) {
+        trace!("Parsing `to_json`.");
+        if !cfg!(feature = "to_json") {
+            panic!("`#[builder(to_json)]` requires the `to_json` feature of `derive_builder` \
+                    (found {}).", self.where_diagnostics());
+        }
+        self.to_json_enabled(true);
+    }
+
+    fn parse_serde(&mut self) {
+        trace!("Parsing `serde`.");
+        if !cfg!(feature = "serde") {
+            panic!("`#[builder(serde)]` requires the `serde` feature of `derive_builder` \
+                    (found {}).", self.where_diagnostics());
+        }
+        self.serde_enabled(true);
+    }
+
+    fn parse_merge(&mut self) {
+        trace!("Parsing `merge`.");
+        self.merge_enabled(true);
+    }
+
+    fn parse_diff(&mut self) {
+        trace!("Parsing `diff`.");
+        self.diff_enabled(true);
+    }
+
+    fn parse_field_take(&mut self) {
+        trace!("Parsing `field_take`.");
+        self.take_enabled(true);
+    }
+
+    fn parse_ext_trait(&mut self) {
+        trace!("Parsing extension trait.");
+        self.ext_trait_enabled(true);
+    }
+
+    fn parse_no_docs(&mut self) {
+        trace!("Parsing `no_docs`.");
+        self.docs_enabled(false);
+    }
+
+    fn parse_update_struct(&mut self, lit: &syn::Lit) {
+        trace!("Parsing update struct name `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        self.update_struct_name(value.clone());
+    }
+
+    /// e.g. `partial = "PartialFoo"` in `#[builder(partial = "PartialFoo")]`
+    fn parse_partial(&mut self, lit: &syn::Lit) {
+        trace!("Parsing partial struct type `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        let ty = syn::parse_type(value)
+            .expect(&format!("Unable to interpret `{}` as type {}.",
+                             value,
+                             self.where_diagnostics()));
+        self.partial_ty(ty);
+    }
+
+    /// e.g. `derive_default = false` in `#[builder(derive_default = false)]`
+    fn parse_derive_default(&mut self, lit: &syn::Lit) {
+        trace!("Parsing `Default` derivation `{:?}`", lit);
+        let value = parse_lit_as_bool(lit).unwrap();
+        self.derive_default(value);
+    }
+
+    fn parse_impl_trait(&mut self, lit: &syn::Lit) {
+        trace!("Parsing setter trait path `{:?}`", lit);
+        let value = parse_lit_as_path(lit).unwrap();
+        self.setter_trait(value);
+    }
+
+    fn parse_builder_vis(&mut self, lit: &syn::Lit) {
+        trace!("Parsing builder visibility `{:?}`", lit);
+        let value = parse_lit_as_visibility(lit).unwrap();
+        self.builder_vis(value);
+    }
+
+    fn parse_module(&mut self, lit: &syn::Lit) {
+        trace!("Parsing builder module name `{:?}`", lit);
+        let value = parse_lit_as_string(lit).unwrap();
+        self.module_name(value.clone());
+    }
+
     /// Parse the `derive` list for struct-level builder declarations.
     fn parse_derive(&mut self, nested: &[syn::NestedMetaItem]) {
         let mut traits = vec![];
@@ -183,10 +880,13 @@ impl OptionsBuilderMode for StructMode {
         // only look for words.
                 syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref tr)) => {
                     match tr.as_ref() {
-                        "Default" | "Clone" => { self.push_deprecation_note(
-                            format!("The `Default` and `Clone` traits are automatically added to all \
-                            builders; explicitly deriving them is unnecessary ({})", where_diag));
+                        "inherit" => self.derive_inherit(true),
+                        "Default" => { self.push_deprecation_note(
+                            format!("The `Default` trait is automatically added to all builders; \
+                            explicitly deriving it is unnecessary ({})", where_diag));
                         },
+                        // `Clone` is only redundant for non-owned builders, which is checked
+                        // once the pattern is known.
                         _ => traits.push(tr.clone())
                     }
                 }
@@ -200,6 +900,22 @@ impl OptionsBuilderMode for StructMode {
         self.derive_traits(traits);
     }
 
+    /// Parse the `derive_target` list, e.g. `#[builder(derive_target(Default))]`.
+    fn parse_derive_target(&mut self, nested: &[syn::NestedMetaItem]) {
+        trace!("Parsing target derives `{:?}`", nested);
+        for x in nested {
+            match *x {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref tr)) if tr == "Default" => {
+                    self.target_default(true)
+                },
+                _ => {
+                    panic!("The derive_target(...) option only supports `Default` (but found \
+                            `{:?}` {}).", x, self.where_diagnostics())
+                }
+            }
+        }
+    }
+
     fn push_deprecation_note<T: Into<String>>(&mut self, x: T) -> &mut Self {
         self.deprecation_notes.push(x.into());
         self
@@ -207,7 +923,10 @@ impl OptionsBuilderMode for StructMode {
 
     /// Provide a diagnostic _where_-clause for panics.
     fn where_diagnostics(&self) -> String {
-        format!("on struct `{}`", self.build_target_name)
+        match self.build_target_variant {
+            Some(ref variant) => format!("on variant `{}::{}`", self.build_target_name, variant),
+            None => format!("on struct `{}`", self.build_target_name),
+        }
     }
 
     fn struct_mode(&self) -> bool {
@@ -236,6 +955,42 @@ impl From<OptionsBuilder<StructMode>> for (StructOptions, OptionsBuilder<FieldMo
         #[cfg(not(feature = "struct_default"))]
         let (field_default_expression, struct_default_expression) = (b.default_expression, None);
 
+        // fields without an explicit default fall back to this one in `with_defaults`
+        let fields_default = b.mode.fields_default.unwrap_or(false);
+        let field_default_expression = field_default_expression.or_else(|| {
+            if fields_default {
+                Some(DefaultExpression::Trait)
+            } else {
+                None
+            }
+        });
+
+        if b.mode.build_target_variant.is_some() {
+            if struct_default_expression.is_some() {
+                panic!("A struct-level default is not supported for enum variants, use \
+                        `fields_default` instead ({}).", b.mode.where_diagnostics())
+            }
+            if b.mode.diff_enabled.unwrap_or(false) {
+                panic!("`diff` is not supported for enum variants ({}).",
+                       b.mode.where_diagnostics())
+            }
+            if b.mode.build_fn_apply_to.is_some() {
+                panic!("`build_fn(apply_to)` is not supported for enum variants ({}).",
+                       b.mode.where_diagnostics())
+            }
+        }
+
+        if b.mode.const_setters.unwrap_or(false) &&
+           b.builder_pattern.unwrap_or_default() != BuilderPattern::Owned {
+            panic!("`const_setters` requires `pattern = \"owned\"` ({}).",
+                   b.mode.where_diagnostics())
+        }
+
+        if b.setter_auto_into.unwrap_or(false) && b.setter_into_only.is_some() {
+            panic!("`setter(auto_into)` can not be combined with `setter(into(only(...)))` ({}).",
+                   b.mode.where_diagnostics())
+        }
+
         let field_defaults = OptionsBuilder::<FieldMode> {
             setter_enabled: b.setter_enabled,
             builder_pattern: b.builder_pattern,
@@ -243,42 +998,170 @@ impl From<OptionsBuilder<StructMode>> for (StructOptions, OptionsBuilder<FieldMo
             setter_prefix: b.setter_prefix,
             setter_vis: b.setter_vis,
             setter_into: b.setter_into,
+            setter_auto_into: b.setter_auto_into,
+            setter_into_only: b.setter_into_only,
+            setter_impl_trait: b.setter_impl_trait,
+            setter_with_plain: b.setter_with_plain,
+            setter_once: b.setter_once,
+            setter_charset: b.setter_charset,
+            setter_const: b.setter_const,
+            setter_try_into: b.setter_try_into,
+            setter_try_into_error: b.setter_try_into_error,
+            setter_inline: b.setter_inline,
             try_setter: b.try_setter,
+            try_setter_error: b.try_setter_error,
+            getter_try: b.getter_try,
             field_vis: b.field_vis,
             default_expression: field_default_expression,
             no_std: b.no_std,
+            no_std_alloc: b.no_std_alloc,
             mode: {
                 let mut mode = FieldMode::default();
                 mode.use_default_struct = struct_default_expression.is_some();
+                mode.build_fn_reusable = b.mode.build_fn_reusable.unwrap_or(false);
+                mode.option_as_optional = b.mode.option_as_optional.unwrap_or(false);
+                mode.const_setters = b.mode.const_setters.unwrap_or(false);
+                mode.serde = b.mode.serde_enabled.unwrap_or(false);
+                mode.in_submodule = b.mode.module_name.is_some();
                 mode
             },
         };
 
-        let m = b.mode;
+        let mut m = b.mode;
 
         let pattern = b.builder_pattern.unwrap_or_default();
+        let mut derives = m.derive_traits.take().unwrap_or_default();
+        if pattern.requires_clone() && derives.iter().any(|x| x == "Clone") {
+            derives.retain(|x| x != "Clone");
+            let where_diagnostics = m.where_diagnostics();
+            m.push_deprecation_note(format!(
+                "The `Clone` trait is automatically added to all builders, except for the owned \
+                pattern; explicitly deriving it is unnecessary ({})", where_diagnostics));
+        }
+        for tr in m.inherited_derives.drain(..) {
+            // `Default` and `Clone` are derived on non-owned builders anyway
+            if tr == "Default" || (tr == "Clone" && pattern.requires_clone()) ||
+               derives.contains(&tr) {
+                continue;
+            }
+            derives.push(tr);
+        }
+        if !pattern.requires_clone() && derives.iter().any(|x| x == "Copy") &&
+           !derives.iter().any(|x| x == "Clone") {
+            panic!("Deriving `Copy` on an owned builder requires deriving `Clone`, too. Owned \
+                    builders don't derive `Clone` automatically, so use \
+                    `#[builder(derive(Clone, Copy))]` ({}).",
+                   m.where_diagnostics())
+        }
         let bindings = Bindings {
-            no_std: b.no_std.unwrap_or(false)
+            no_std: b.no_std.unwrap_or(false),
+            alloc: b.no_std_alloc.unwrap_or(false),
+        };
+
+        let build_fn_by_ref = m.build_fn_by_ref.unwrap_or(false);
+        if build_fn_by_ref && m.prepare_fn.is_some() {
+            panic!("`build_fn(by_ref)` can not be combined with `build_fn(prepare)`, \
+                    which requires mutable access to the builder ({}).",
+                   m.where_diagnostics())
+        }
+        let build_fn_with_base = m.build_fn_with_base.unwrap_or(false);
+        if build_fn_with_base && m.build_target_variant.is_some() {
+            panic!("`build_fn(with_base)` is not supported for enum variants ({}).",
+                   m.where_diagnostics())
+        }
+        let build_fn_collect_errors = m.build_fn_collect_errors.unwrap_or(false);
+        let build_fn_typed_error = m.build_fn_typed_error.unwrap_or(false);
+        if build_fn_typed_error && build_fn_collect_errors {
+            panic!("`build_fn(typed_error)` can not be combined with `build_fn(collect_errors)`, \
+                    which always returns `Vec<String>` ({}).",
+                   m.where_diagnostics())
+        }
+        let build_fn_name = m.build_fn_name.unwrap_or("build".to_string());
+
+        let builder_ident = syn::Ident::new(match (m.builder_name, &m.build_target_variant) {
+            (Some(name), _) => name,
+            (None, &Some(ref variant)) => format!("{}{}Builder", m.build_target_name, variant),
+            (None, &None) => format!("{}Builder", m.build_target_name),
+        });
+
+        // The builder is re-exported from its module with the requested visibility.
+        let reexport_vis = m.builder_vis.unwrap_or(m.build_target_vis);
+        let builder_vis = if m.module_name.is_some() {
+            visibility_in_submodule(reexport_vis.clone())
+        } else {
+            reexport_vis.clone()
+        };
+        let build_fn_vis = match m.build_fn_vis {
+            Some(vis) if m.module_name.is_some() => visibility_in_submodule(vis),
+            Some(vis) => vis,
+            None => builder_vis.clone(),
+        };
+
+        let build_target_ty = {
+            let ident = syn::Ident::new(m.build_target_name.as_str());
+            let (_, ty_generics, _) = m.build_target_generics.split_for_impl();
+            syn::parse_type(quote!(#ident #ty_generics).as_str())
+                .expect("target type should be a valid type")
         };
 
         let struct_options = StructOptions {
             build_fn_enabled: m.build_fn_enabled.unwrap_or(true),
-            build_fn_name: syn::Ident::new(
-                m.build_fn_name.unwrap_or("build".to_string())
-            ),
-            builder_ident: syn::Ident::new(
-                m.builder_name.unwrap_or(format!("{}Builder", m.build_target_name))
-            ),
-            builder_visibility: m.builder_vis.unwrap_or(m.build_target_vis),
+            build_fn_reusable: m.build_fn_reusable.unwrap_or(false),
+            build_fn_into: m.build_fn_into,
+            build_fn_apply_to: m.build_fn_apply_to,
+            build_fn_collect_errors: build_fn_collect_errors,
+            build_fn_by_ref: build_fn_by_ref,
+            build_ref_fn_name: syn::Ident::new(m.build_ref_fn_name
+                .unwrap_or_else(|| format!("{}_ref", build_fn_name))),
+            build_fn_with_base: build_fn_with_base,
+            build_with_base_fn_name: syn::Ident::new(format!("{}_with_defaults", build_fn_name)),
+            build_fn_name: syn::Ident::new(build_fn_name),
+            view_ident: syn::Ident::new(format!("{}View", builder_ident)),
+            error_ident: if build_fn_typed_error {
+                Some(syn::Ident::new(format!("{}Error", builder_ident)))
+            } else {
+                None
+            },
+            ext_trait_ident: syn::Ident::new(format!("{}Ext", builder_ident)),
+            builder_ident: builder_ident,
+            builder_visibility: builder_vis,
+            module_ident: m.module_name.map(syn::Ident::new),
+            reexport_visibility: reexport_vis,
             builder_pattern: pattern,
             build_target_ident: syn::Ident::new(m.build_target_name),
-            derives: m.derive_traits.unwrap_or_default(),
+            build_target_ty: build_target_ty,
+            build_target_variant: m.build_target_variant.map(syn::Ident::new),
+            derives: derives,
+            derive_default: m.derive_default.unwrap_or(true),
+            target_default: m.target_default.unwrap_or(false),
             deprecation_notes: m.deprecation_notes,
             generics: m.build_target_generics,
             struct_size_hint: m.struct_size_hint,
             bindings: bindings,
             default_expression: struct_default_expression,
             validate_fn: m.validate_fn,
+            prepare_fn: m.prepare_fn,
+            map_fn: m.map_fn,
+            build_fn_wrap: m.build_fn_wrap,
+            build_fn_doc: m.build_fn_doc,
+            build_fn_visibility: build_fn_vis,
+            setter_trait: m.setter_trait,
+            from_pairs_enabled: m.from_pairs_enabled.unwrap_or(false),
+            from_pairs_deny_unknown: m.from_pairs_deny_unknown.unwrap_or(false),
+            new_fn: m.new_fn.unwrap_or(false),
+            build_all: m.build_all.unwrap_or(false),
+            non_exhaustive: m.non_exhaustive.unwrap_or(false),
+            view_enabled: m.view_enabled.unwrap_or(false),
+            display_enabled: m.display_enabled.unwrap_or(false),
+            clap_enabled: m.clap_enabled.unwrap_or(false),
+            to_json_enabled: m.to_json_enabled.unwrap_or(false),
+            merge_enabled: m.merge_enabled.unwrap_or(false),
+            diff_enabled: m.diff_enabled.unwrap_or(false),
+            take_enabled: m.take_enabled.unwrap_or(false),
+            ext_trait_enabled: m.ext_trait_enabled.unwrap_or(false),
+            docs_enabled: m.docs_enabled.unwrap_or(true),
+            update_struct_ident: m.update_struct_name.map(syn::Ident::new),
+            partial_ty: m.partial_ty,
         };
 
         (struct_options, field_defaults)
@@ -1,5 +1,7 @@
 use syn;
-use derive_builder_core::{DeprecationNotes, BuilderPattern, Builder, BuildMethod, Bindings};
+use derive_builder_core::{DeprecationNotes, BuilderPattern, Builder, BuildMethod, Bindings,
+                          BuilderView, BuilderError, ClapArgs, ExtTrait, FieldsFn, FieldsFnKind,
+                          FromPairs, UpdateStruct, TargetDefault};
 use options::DefaultExpression;
 
 /// These struct options define how the builder is generated.
@@ -7,18 +9,48 @@ use options::DefaultExpression;
 pub struct StructOptions {
     /// Whether or not this struct should implement its own build method.
     pub build_fn_enabled: bool,
+    /// Whether the build method takes `&mut self` and leaves the builder empty for reuse.
+    pub build_fn_reusable: bool,
+    /// Type the build method converts the target struct into, e.g. `FooConfig`.
+    pub build_fn_into: Option<syn::Ty>,
+    /// Type patched by the `apply` method, e.g. `&mut Foo`.
+    pub build_fn_apply_to: Option<syn::Ty>,
+    /// Whether the build method returns all errors at once, i.e. `Vec<String>`.
+    pub build_fn_collect_errors: bool,
+    /// Whether to emit an additional build method taking `&self`, which clones the values.
+    pub build_fn_by_ref: bool,
     /// The name of the emitted build method.
     pub build_fn_name: syn::Ident,
+    /// The name of the additional build method taking `&self`, e.g. `build_ref`.
+    pub build_ref_fn_name: syn::Ident,
+    /// Whether to emit an additional build method taking a base value for unset fields.
+    pub build_fn_with_base: bool,
+    /// The name of the additional build method taking a base value, e.g. `build_with_defaults`.
+    pub build_with_base_fn_name: syn::Ident,
+    /// Whether to emit `try_build_all`, building a `Vec` of builders at once.
+    pub build_all: bool,
     /// Name of the builder struct, e.g. `FooBuilder`.
     pub builder_ident: syn::Ident,
+    /// Name of the typed error of the build method, e.g. `FooBuilderError`.
+    ///
+    /// The build method returns a `String` if this is `None`.
+    pub error_ident: Option<syn::Ident>,
     /// Visibility of the builder struct, e.g. `syn::Visibility::Public`.
     pub builder_visibility: syn::Visibility,
     /// The additional traits to derive on the builder.
     pub derives: Vec<syn::Ident>,
+    /// Whether to derive `Default` on the builder, instead of implementing it manually.
+    pub derive_default: bool,
+    /// Whether to implement `Default` for the target, using the default values of its fields.
+    pub target_default: bool,
     /// How the build method takes and returns `self` (e.g. mutably).
     pub builder_pattern: BuilderPattern,
     /// Target struct name.
     pub build_target_ident: syn::Ident,
+    /// Target struct type including its type parameters, e.g. `Foo<T>`.
+    pub build_target_ty: syn::Ty,
+    /// Target variant name, if the target is an enum.
+    pub build_target_variant: Option<syn::Ident>,
     /// Represents lifetimes and type parameters attached to the declaration of items.
     pub generics: syn::Generics,
     /// Emit deprecation notes to the user,
@@ -33,6 +65,62 @@ pub struct StructOptions {
     /// Path to the optional validation function to invoke before the
     /// macro-generated `build` method executes.
     pub validate_fn: Option<syn::Path>,
+    /// Function to call before validation, with mutable access to the builder.
+    pub prepare_fn: Option<syn::Path>,
+    /// Function to post-process the built struct with, e.g. `Arc::new`.
+    pub map_fn: Option<syn::Path>,
+    /// Pointer type to wrap the built value in, e.g. `#[builder(build_fn(wrap = "Arc"))]`.
+    pub build_fn_wrap: Option<syn::Path>,
+    /// Doc comment of the build method, replacing the generated one,
+    /// e.g. `#[builder(build_fn(doc = "..."))]`.
+    pub build_fn_doc: Option<String>,
+    /// Visibility of the build methods, e.g. `#[builder(build_fn(vis = "pub(crate)"))]`.
+    ///
+    /// Defaults to `builder_visibility`.
+    pub build_fn_visibility: syn::Visibility,
+    /// Trait declaring the setters, which the builder implements instead of
+    /// inherent setters.
+    pub setter_trait: Option<syn::Path>,
+    /// Whether to generate `from_pairs` and a `FromIterator` impl on the builder.
+    pub from_pairs_enabled: bool,
+    /// Whether `from_pairs` rejects keys which don't match any field.
+    pub from_pairs_deny_unknown: bool,
+    /// Whether to emit an inherent `new` constructor on the builder.
+    pub new_fn: bool,
+    /// Whether to mark the builder struct `#[non_exhaustive]`.
+    pub non_exhaustive: bool,
+    /// Whether to emit a borrowed view of the builder and a `view` method.
+    pub view_enabled: bool,
+    /// Name of the view struct, e.g. `FooBuilderView`.
+    pub view_ident: syn::Ident,
+    /// Whether to implement `Display` for the builder.
+    pub display_enabled: bool,
+    /// Name of the module wrapping the builder, e.g. `foo_builder`.
+    pub module_ident: Option<syn::Ident>,
+    /// Visibility of the builder re-export, if the builder is wrapped in a module.
+    ///
+    /// `builder_visibility` is relative to that module instead.
+    pub reexport_visibility: syn::Visibility,
+    /// Whether to emit `augment_args` and `from_matches` for `clap` interop.
+    pub clap_enabled: bool,
+    /// Whether to emit `to_json` serializing the set fields via `serde_json`.
+    pub to_json_enabled: bool,
+    /// Whether to emit `merge` copying the set fields of another builder.
+    pub merge_enabled: bool,
+    /// Whether to emit `changed_from` listing the fields which differ from a target struct.
+    pub diff_enabled: bool,
+    /// Whether to emit a `take_*` method per field, moving its value out of the builder.
+    pub take_enabled: bool,
+    /// Whether to emit a sealed extension trait implemented by the builder.
+    pub ext_trait_enabled: bool,
+    /// Name of the extension trait, e.g. `FooBuilderExt`.
+    pub ext_trait_ident: syn::Ident,
+    /// Whether to attach the generated doc-comments, e.g. to the builder struct.
+    pub docs_enabled: bool,
+    /// Name of the optional update struct, e.g. `FooUpdate`.
+    pub update_struct_ident: Option<syn::Ident>,
+    /// Struct to convert the builder from and into, e.g. `PartialFoo`.
+    pub partial_ty: Option<syn::Ty>,
 }
 
 impl StructOptions {
@@ -43,11 +131,23 @@ impl StructOptions {
             ident: &self.builder_ident,
             pattern: self.builder_pattern,
             derives: &self.derives,
+            derive_default: self.derive_default,
+            must_derive_clone: self.builder_pattern.requires_clone(),
             generics: Some(&self.generics),
             visibility: &self.builder_visibility,
             fields: Vec::with_capacity(self.struct_size_hint),
+            default_fields: Vec::with_capacity(self.struct_size_hint),
             functions: Vec::with_capacity(self.struct_size_hint),
+            setter_trait: self.setter_trait.as_ref(),
+            trait_functions: Vec::with_capacity(self.struct_size_hint),
             doc_comment: None,
+            new_fn: self.new_fn,
+            non_exhaustive: self.non_exhaustive,
+            partial: self.partial_ty.as_ref(),
+            from_partial_fields: Vec::with_capacity(self.struct_size_hint),
+            into_partial_fields: Vec::with_capacity(self.struct_size_hint),
+            display_fn: None,
+            display_bounds: vec![],
             deprecation_notes: self.deprecation_notes.clone(),
             bindings: self.bindings,
         }
@@ -58,17 +158,190 @@ impl StructOptions {
         BuildMethod {
             enabled: self.build_fn_enabled,
             ident: &self.build_fn_name,
-            visibility: &self.builder_visibility,
+            visibility: &self.build_fn_visibility,
             pattern: self.builder_pattern,
+            reusable: self.build_fn_reusable,
             target_ty: &self.build_target_ident,
+            target_variant: self.build_target_variant.as_ref(),
             target_ty_generics: Some(ty_generics),
+            into_ty: self.build_fn_into.as_ref(),
             initializers: Vec::with_capacity(self.struct_size_hint),
+            initializer_deps: Vec::with_capacity(self.struct_size_hint),
             doc_comment: None,
             bindings: self.bindings,
             default_struct: self.default_expression
                 .as_ref()
-                .map(|x| { x.parse_block(self.bindings.no_std) }),
+                .map(|x| { x.parse_block(self.bindings) }),
+            base_param: false,
+            build_all: self.build_all,
+            build_all_doc_comment: None,
             validate_fn: self.validate_fn.as_ref(),
+            prepare_fn: self.prepare_fn.as_ref(),
+            map_fn: self.map_fn.as_ref(),
+            wrap: self.build_fn_wrap.as_ref(),
+            collect_errors: self.build_fn_collect_errors,
+            error_ty: self.error_ident.as_ref(),
+            missing_field_checks: Vec::with_capacity(self.struct_size_hint),
+            unset_values: Vec::with_capacity(self.struct_size_hint),
+            charset_checks: Vec::new(),
+            validation_checks: Vec::new(),
+        }
+    }
+
+    /// Returns the additional `BuildMethod` taking `&self` according to the options.
+    ///
+    /// It clones the values regardless of the builder pattern, so the builder is left intact.
+    pub fn as_build_ref_method<'a>(&'a self) -> BuildMethod<'a> {
+        let mut build_method = self.as_build_method();
+        build_method.enabled = self.build_fn_enabled && self.build_fn_by_ref;
+        build_method.ident = &self.build_ref_fn_name;
+        build_method.pattern = BuilderPattern::Immutable;
+        build_method.reusable = false;
+        build_method.build_all = false;
+        build_method
+    }
+
+    /// Returns the additional `BuildMethod` taking a base value according to the options.
+    ///
+    /// Unset fields are moved out of the base value, instead of falling back to their defaults.
+    pub fn as_build_with_base_method<'a>(&'a self) -> BuildMethod<'a> {
+        let mut build_method = self.as_build_method();
+        build_method.enabled = self.build_fn_enabled && self.build_fn_with_base;
+        build_method.ident = &self.build_with_base_fn_name;
+        build_method.base_param = true;
+        build_method.build_all = false;
+        build_method
+    }
+
+    /// Returns the `Display::fmt` `FieldsFn` according to the options.
+    pub fn as_display_fn<'a>(&'a self) -> FieldsFn<'a> {
+        self.as_fields_fn(self.display_enabled, FieldsFnKind::Display)
+    }
+
+    /// Returns the `to_json` `FieldsFn` according to the options.
+    pub fn as_to_json_fn<'a>(&'a self) -> FieldsFn<'a> {
+        self.as_fields_fn(self.to_json_enabled, FieldsFnKind::ToJson)
+    }
+
+    /// Returns the `merge` `FieldsFn` according to the options.
+    pub fn as_merge_fn<'a>(&'a self) -> FieldsFn<'a> {
+        self.as_fields_fn(self.merge_enabled, FieldsFnKind::Merge)
+    }
+
+    /// Returns the `changed_from` `FieldsFn` according to the options.
+    pub fn as_changed_from_fn<'a>(&'a self) -> FieldsFn<'a> {
+        self.as_fields_fn(self.diff_enabled, FieldsFnKind::ChangedFrom(&self.build_target_ty))
+    }
+
+    /// Returns the `apply` `FieldsFn` according to the options.
+    pub fn as_apply_fn<'a>(&'a self) -> FieldsFn<'a> {
+        // the target type is irrelevant if `apply` is disabled
+        let target_ty = self.build_fn_apply_to.as_ref().unwrap_or(&self.build_target_ty);
+        self.as_fields_fn(self.build_fn_apply_to.is_some(), FieldsFnKind::Apply(target_ty))
+    }
+
+    fn as_fields_fn<'a>(&'a self, enabled: bool, kind: FieldsFnKind<'a>) -> FieldsFn<'a> {
+        FieldsFn {
+            enabled: enabled,
+            kind: kind,
+            visibility: &self.builder_visibility,
+            statements: Vec::with_capacity(self.struct_size_hint),
+            field_bounds: Vec::with_capacity(self.struct_size_hint),
+            bindings: self.bindings,
+        }
+    }
+
+    /// Returns a `FromPairs` according to the options.
+    pub fn as_from_pairs<'a>(&'a self) -> FromPairs<'a> {
+        FromPairs {
+            enabled: self.from_pairs_enabled,
+            builder_ident: &self.builder_ident,
+            generics: Some(&self.generics),
+            visibility: &self.builder_visibility,
+            deny_unknown: self.from_pairs_deny_unknown,
+            arms: Vec::with_capacity(self.struct_size_hint),
+            field_bounds: Vec::with_capacity(self.struct_size_hint),
+            bindings: self.bindings,
+        }
+    }
+
+    /// Returns a `ClapArgs` according to the options.
+    pub fn as_clap_args<'a>(&'a self) -> ClapArgs<'a> {
+        ClapArgs {
+            enabled: self.clap_enabled,
+            builder_ident: &self.builder_ident,
+            generics: Some(&self.generics),
+            visibility: &self.builder_visibility,
+            args: Vec::with_capacity(self.struct_size_hint),
+            assignments: Vec::with_capacity(self.struct_size_hint),
+            field_bounds: Vec::with_capacity(self.struct_size_hint),
+            bindings: self.bindings,
+        }
+    }
+
+    /// Returns a `TargetDefault` according to the options.
+    pub fn as_target_default<'a>(&'a self) -> TargetDefault<'a> {
+        TargetDefault {
+            enabled: self.target_default,
+            target_ty: &self.build_target_ident,
+            target_variant: self.build_target_variant.as_ref(),
+            generics: Some(&self.generics),
+            initializers: Vec::with_capacity(self.struct_size_hint),
+            initializer_deps: Vec::with_capacity(self.struct_size_hint),
+            bindings: self.bindings,
+        }
+    }
+
+    /// Returns an `ExtTrait` according to the options.
+    pub fn as_ext_trait<'a>(&'a self) -> ExtTrait<'a> {
+        ExtTrait {
+            enabled: self.ext_trait_enabled,
+            ident: &self.ext_trait_ident,
+            builder_ident: &self.builder_ident,
+            generics: Some(&self.generics),
+            visibility: &self.builder_visibility,
+            doc_comment: None,
+            bindings: self.bindings,
+        }
+    }
+
+    /// Returns a `BuilderView` according to the options.
+    pub fn as_builder_view<'a>(&'a self) -> BuilderView<'a> {
+        BuilderView {
+            enabled: self.view_enabled,
+            ident: &self.view_ident,
+            builder_ident: &self.builder_ident,
+            generics: Some(&self.generics),
+            visibility: &self.builder_visibility,
+            fields: Vec::with_capacity(self.struct_size_hint),
+            initializers: Vec::with_capacity(self.struct_size_hint),
+            doc_comment: None,
+            bindings: self.bindings,
+        }
+    }
+
+    /// Returns a `BuilderError` according to the options.
+    pub fn as_builder_error<'a>(&'a self) -> BuilderError<'a> {
+        BuilderError {
+            ident: self.error_ident.as_ref(),
+            visibility: &self.builder_visibility,
+            doc_comment: None,
+            bindings: self.bindings,
+        }
+    }
+
+    /// Returns an `UpdateStruct` according to the options.
+    pub fn as_update_struct<'a>(&'a self) -> UpdateStruct<'a> {
+        UpdateStruct {
+            ident: self.update_struct_ident.as_ref(),
+            builder_ident: &self.builder_ident,
+            pattern: self.builder_pattern,
+            generics: Some(&self.generics),
+            visibility: &self.builder_visibility,
+            fields: Vec::with_capacity(self.struct_size_hint),
+            assignments: Vec::with_capacity(self.struct_size_hint),
+            doc_comment: None,
+            bindings: self.bindings,
         }
     }
 }
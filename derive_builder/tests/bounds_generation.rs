@@ -25,6 +25,13 @@ pub struct OwnedLorem<T> {
     ipsum: T,
 }
 
+/// Owned builders move their fields in `build`, so they don't need to be `Clone`.
+#[derive(Debug, Builder, PartialEq, Eq)]
+#[builder(pattern="owned", field(private))]
+pub struct OwnedSit {
+    amet: Dolor,
+}
+
 #[test]
 fn generic_field_with_clone_has_builder_impl() {
     assert_eq!(LoremBuilder::default()
@@ -72,4 +79,15 @@ fn generic_field_without_clone_has_owned_builder() {
                OwnedLorem {
                    ipsum: Dolor::default()
                });
-}
\ No newline at end of file
+}
+
+#[test]
+fn non_clone_field_has_owned_builder() {
+    assert_eq!(OwnedSitBuilder::default()
+                   .amet(Dolor("amet".to_string()))
+                   .build()
+                   .unwrap(),
+               OwnedSit {
+                   amet: Dolor("amet".to_string())
+               });
+}
@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder, Clone)]
+#[builder(build_all)]
+struct Lorem {
+    ipsum: u32,
+    #[builder(default, setter(into))]
+    dolor: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_all, pattern = "owned", build_fn(collect_errors))]
+struct Sit {
+    amet: u32,
+    consectetur: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_all, build_fn(reusable, name = "finish"))]
+struct Adipiscing {
+    elit: u32,
+}
+
+#[test]
+fn all_valid() {
+    let builders = vec![LoremBuilder::default().ipsum(1).clone(),
+                        LoremBuilder::default().ipsum(2).dolor("dolor").clone()];
+
+    assert_eq!(LoremBuilder::try_build_all(builders),
+               Ok(vec![Lorem { ipsum: 1, dolor: String::new() },
+                       Lorem { ipsum: 2, dolor: "dolor".to_string() }]));
+}
+
+#[test]
+fn indexed_errors() {
+    let builders = vec![LoremBuilder::default().dolor("dolor").clone(),
+                        LoremBuilder::default().ipsum(1).clone(),
+                        LoremBuilder::default()];
+
+    assert_eq!(LoremBuilder::try_build_all(builders),
+               Err(vec![(0, "`ipsum` must be initialized".to_string()),
+                        (2, "`ipsum` must be initialized".to_string())]));
+}
+
+#[test]
+fn empty() {
+    assert_eq!(LoremBuilder::try_build_all(vec![]), Ok(vec![]));
+}
+
+#[test]
+fn collect_errors() {
+    let builders = vec![SitBuilder::default().amet(1).consectetur(2),
+                        SitBuilder::default().amet(3)];
+
+    assert_eq!(SitBuilder::try_build_all(builders),
+               Err(vec![(1, vec!["`consectetur` must be initialized".to_string()])]));
+}
+
+#[test]
+fn reusable() {
+    let mut builder = AdipiscingBuilder::default();
+    builder.elit(1);
+
+    assert_eq!(AdipiscingBuilder::try_build_all(vec![builder]),
+               Ok(vec![Adipiscing { elit: 1 }]));
+}
@@ -1,6 +1,9 @@
 #[macro_use]
 extern crate derive_builder;
 
+use std::rc::Rc;
+use std::sync::Arc;
+
 #[derive(Debug, Builder, PartialEq)]
 #[builder(build_fn(skip))]
 pub struct Lorem {
@@ -13,6 +16,149 @@ pub struct Ipsum {
     percentile: u8,
 }
 
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(reusable))]
+pub struct Dolor {
+    sit: String,
+    #[builder(default = "42")]
+    amet: u8,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(reusable))]
+pub struct Consectetur {
+    adipiscing: String,
+    #[builder(setter(each = "elit_item"), field(default = "vec![0]"))]
+    elit: Vec<u8>,
+    #[builder(default = "self.adipiscing.clone().unwrap_or_default().len()")]
+    sed: usize,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(into = "SitConfig", validate = "SitBuilder::validate"))]
+pub struct Sit {
+    amet: u8,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(pattern = "owned", build_fn(by_ref))]
+pub struct Amet {
+    consectetur: String,
+    #[builder(default = "42")]
+    adipiscing: u8,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(name = "finish", by_ref))]
+pub struct Elit {
+    sed: u8,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(pattern = "owned", build_fn(build_ref = "try_peek"))]
+pub struct Labore {
+    dolore: u8,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(apply_to = "&mut Tempor"))]
+pub struct Tempor {
+    incididunt: String,
+    ut: u8,
+    #[builder(setter(skip))]
+    labore: bool,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(map = "Arc::new", into = "Arc<Magna>", validate = "MagnaBuilder::validate"))]
+pub struct Magna {
+    aliqua: String,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(map = "Enim::normalize"))]
+pub struct Enim {
+    minim: String,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(wrap = "Arc"))]
+pub struct Veniam {
+    quis: u32,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(wrap = "Rc", into = "SitConfig"))]
+pub struct Nostrud {
+    amet: u32,
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(doc = "Builds a new `Exercitation`, requiring `ullamco`."))]
+pub struct Exercitation {
+    ullamco: u32,
+}
+
+mod ullamco {
+    /// A public builder, which can only be finalized within the crate.
+    #[derive(Debug, Builder, PartialEq)]
+    #[builder(build_fn(vis = "pub(crate)", by_ref))]
+    pub struct Laboris {
+        pub nisi: u32,
+    }
+}
+
+#[derive(Debug, Builder, PartialEq)]
+#[builder(build_fn(with_base))]
+pub struct Commodo {
+    incididunt: String,
+    #[builder(default = "42")]
+    labore: u32,
+    #[builder(setter(skip))]
+    dolore: Vec<u32>,
+}
+
+impl From<Nostrud> for SitConfig {
+    fn from(x: Nostrud) -> SitConfig {
+        SitConfig { amet: x.amet.to_string() }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SitConfig {
+    amet: String,
+}
+
+impl From<Sit> for SitConfig {
+    fn from(x: Sit) -> SitConfig {
+        SitConfig { amet: x.amet.to_string() }
+    }
+}
+
+impl SitBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.amet {
+            Some(0) => Err("amet must not be zero".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl MagnaBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.aliqua {
+            Some(ref aliqua) if aliqua.is_empty() => Err("aliqua must not be empty".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Enim {
+    fn normalize(self) -> Self {
+        Enim { minim: self.minim.trim().to_lowercase() }
+    }
+}
+
 impl Lorem {
     pub fn new(pct: u8) -> Result<Self, String> {
         if pct <= 100 {
@@ -70,3 +216,161 @@ fn rename() {
         .unwrap();
     assert_eq!(Ipsum { percentile: 110 }, ipsum);
 }
+
+#[test]
+fn reusable() {
+    let mut builder = DolorBuilder::default();
+    builder.sit("sit".to_string()).amet(7);
+
+    let dolor = builder.build().unwrap();
+    assert_eq!(Dolor { sit: "sit".to_string(), amet: 7 }, dolor);
+
+    // the builder has been emptied by the first build
+    assert_eq!("`sit` must be initialized", &builder.build().unwrap_err());
+
+    let dolor = builder.sit("consectetur".to_string()).build().unwrap();
+    assert_eq!(Dolor { sit: "consectetur".to_string(), amet: 42 }, dolor);
+}
+
+#[test]
+fn reusable_failed_build() {
+    let mut builder = ConsecteturBuilder::default();
+    builder.elit_item(1);
+
+    // nothing is moved out of the builder if building fails
+    assert_eq!("`adipiscing` must be initialized", &builder.build().unwrap_err());
+    assert_eq!(Some(vec![0, 1]), builder.elit);
+
+    let consectetur = builder.adipiscing("adipiscing".to_string()).build().unwrap();
+    assert_eq!(Consectetur {
+                   adipiscing: "adipiscing".to_string(),
+                   elit: vec![0, 1],
+                   sed: 10,
+               },
+               consectetur);
+
+    // the builder is back at its initial values
+    assert_eq!(None, builder.adipiscing);
+    assert_eq!(Some(vec![0]), builder.elit);
+}
+
+#[test]
+fn into() {
+    let x: SitConfig = SitBuilder::default().amet(42).build().unwrap();
+    assert_eq!(x, SitConfig { amet: "42".to_string() });
+}
+
+#[test]
+fn into_errors() {
+    let err = SitBuilder::default().build().unwrap_err();
+    assert_eq!(&err, "`amet` must be initialized");
+
+    let err = SitBuilder::default().amet(0).build().unwrap_err();
+    assert_eq!(&err, "amet must not be zero");
+}
+
+#[test]
+fn by_ref() {
+    let builder = AmetBuilder::default().consectetur("consectetur".to_string());
+
+    let amet = builder.build_ref().unwrap();
+    assert_eq!(Amet { consectetur: "consectetur".to_string(), adipiscing: 42 }, amet);
+
+    // the owned builder is left intact and can still be consumed
+    let amet = builder.adipiscing(7).build().unwrap();
+    assert_eq!(Amet { consectetur: "consectetur".to_string(), adipiscing: 7 }, amet);
+
+    let err = AmetBuilder::default().build_ref().unwrap_err();
+    assert_eq!(&err, "`consectetur` must be initialized");
+}
+
+#[test]
+fn by_ref_rename() {
+    let elit = ElitBuilder::default().sed(1).finish_ref().unwrap();
+    assert_eq!(Elit { sed: 1 }, elit);
+}
+
+#[test]
+fn by_ref_custom_name() {
+    let builder = LaboreBuilder::default();
+    assert_eq!(&builder.try_peek().unwrap_err(), "`dolore` must be initialized");
+
+    let builder = builder.dolore(1);
+    assert_eq!(Labore { dolore: 1 }, builder.try_peek().unwrap());
+    assert_eq!(Labore { dolore: 1 }, builder.try_peek().unwrap());
+    assert_eq!(Labore { dolore: 1 }, builder.build().unwrap());
+}
+
+#[test]
+fn apply_to() {
+    let mut tempor = Tempor { incididunt: "incididunt".to_string(), ut: 1, labore: true };
+
+    TemporBuilder::default().apply(&mut tempor);
+    assert_eq!(Tempor { incididunt: "incididunt".to_string(), ut: 1, labore: true }, tempor);
+
+    TemporBuilder::default().ut(2).apply(&mut tempor);
+    assert_eq!(Tempor { incididunt: "incididunt".to_string(), ut: 2, labore: true }, tempor);
+}
+
+#[test]
+fn map() {
+    let magna: Arc<Magna> = MagnaBuilder::default().aliqua("aliqua".to_string()).build().unwrap();
+    assert_eq!(Magna { aliqua: "aliqua".to_string() }, *magna);
+
+    let err = MagnaBuilder::default().aliqua(String::new()).build().unwrap_err();
+    assert_eq!(&err, "aliqua must not be empty");
+}
+
+#[test]
+fn map_same_type() {
+    let enim = EnimBuilder::default().minim(" Minim ".to_string()).build().unwrap();
+    assert_eq!(Enim { minim: "minim".to_string() }, enim);
+}
+
+#[test]
+fn wrap_arc() {
+    let veniam: Arc<Veniam> = VeniamBuilder::default().quis(1).build().unwrap();
+    assert_eq!(Veniam { quis: 1 }, *veniam);
+}
+
+#[test]
+fn wrap_rc() {
+    let nostrud: Rc<SitConfig> = NostrudBuilder::default().amet(1).build().unwrap();
+    assert_eq!(SitConfig { amet: "1".to_string() }, *nostrud);
+}
+
+#[test]
+fn doc() {
+    let exercitation = ExercitationBuilder::default().ullamco(1).build().unwrap();
+    assert_eq!(Exercitation { ullamco: 1 }, exercitation);
+}
+
+#[test]
+fn vis() {
+    use ullamco::{Laboris, LaborisBuilder};
+
+    let mut builder = LaborisBuilder::default();
+    builder.nisi(1);
+    // `pub(crate)` build methods are callable from outside of `ullamco`
+    assert_eq!(builder.build_ref().unwrap(), builder.build().unwrap());
+    assert_eq!(builder.build().unwrap(), Laboris { nisi: 1 });
+}
+
+#[test]
+fn with_base() {
+    let base = Commodo {
+        incididunt: "base".to_string(),
+        labore: 1,
+        dolore: vec![2],
+    };
+    let x = CommodoBuilder::default().labore(3).build_with_defaults(base).unwrap();
+
+    // unset fields are taken from the base, even if they have a default
+    assert_eq!(x,
+               Commodo {
+                   incididunt: "base".to_string(),
+                   labore: 3,
+                   dolore: vec![2],
+               });
+    assert!(CommodoBuilder::default().build().is_err());
+}
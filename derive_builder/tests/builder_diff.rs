@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(diff)]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+    #[builder(setter(skip))]
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", diff)]
+struct Amet<T> {
+    consectetur: T,
+    adipiscing: Option<T>,
+}
+
+fn base() -> Lorem {
+    Lorem {
+        ipsum: 1,
+        dolor: "dolor".to_string(),
+        sit: true,
+    }
+}
+
+#[test]
+fn changed_fields() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(2).dolor("dolor".to_string());
+
+    assert_eq!(builder.changed_from(&base()), vec!["ipsum"]);
+
+    builder.dolor("sit".to_string());
+
+    assert_eq!(builder.changed_from(&base()), vec!["ipsum", "dolor"]);
+}
+
+#[test]
+fn unset_fields_are_skipped() {
+    assert!(LoremBuilder::default().changed_from(&base()).is_empty());
+}
+
+#[test]
+fn generic() {
+    let base = Amet {
+        consectetur: 1,
+        adipiscing: None,
+    };
+    let builder = AmetBuilder::default().consectetur(1).adipiscing(Some(1));
+
+    assert_eq!(builder.changed_from(&base), vec!["adipiscing"]);
+}
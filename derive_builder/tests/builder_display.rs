@@ -0,0 +1,45 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(display)]
+struct Lorem {
+    /// The ipsum.
+    ipsum: u32,
+    #[builder(default = "\"dolor\".to_string()")]
+    dolor: String,
+    sit: bool,
+    #[builder(setter(skip))]
+    amet: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(display, pattern = "owned")]
+struct Consectetur<T: Display> {
+    adipiscing: T,
+}
+
+#[test]
+fn display() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(42).sit(true);
+
+    assert_eq!(builder.to_string(), "ipsum: 42\ndolor: <default>\nsit: true\n");
+}
+
+#[test]
+fn display_empty() {
+    assert_eq!(LoremBuilder::default().to_string(),
+               "ipsum: <unset>\ndolor: <default>\nsit: <unset>\n");
+}
+
+#[test]
+fn display_generic() {
+    let builder = ConsecteturBuilder::default().adipiscing("elit");
+
+    assert_eq!(format!("{}", builder), "adipiscing: elit\n");
+}
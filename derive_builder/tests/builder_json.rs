@@ -0,0 +1,64 @@
+#![cfg(feature = "to_json")]
+
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+extern crate serde;
+#[macro_use]
+extern crate serde_json;
+
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(to_json)]
+struct Lorem {
+    ipsum: String,
+    dolor: u32,
+    #[builder(default)]
+    sit: Vec<bool>,
+    #[builder(setter(skip))]
+    amet: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(to_json)]
+struct Generic<T> {
+    consectetur: T,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(to_json)]
+struct Adipiscing {
+    elit: HashMap<(u8, u8), u8>,
+}
+
+#[test]
+fn only_set_fields() {
+    let json = LoremBuilder::default().ipsum("ipsum".to_string()).sit(vec![true]).to_json().unwrap();
+
+    assert_eq!(json, json!({
+        "ipsum": "ipsum",
+        "sit": [true],
+    }));
+}
+
+#[test]
+fn empty() {
+    assert_eq!(LoremBuilder::default().to_json().unwrap(), json!({}));
+}
+
+#[test]
+fn generic() {
+    let json = GenericBuilder::default().consectetur(Some(42)).to_json().unwrap();
+
+    assert_eq!(json, json!({ "consectetur": 42 }));
+}
+
+#[test]
+fn serialization_error() {
+    let mut elit = HashMap::new();
+    elit.insert((1, 2), 3);
+
+    assert!(AdipiscingBuilder::default().elit(elit).to_json().is_err());
+}
@@ -0,0 +1,63 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(merge)]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+    #[builder(setter(skip))]
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", merge)]
+struct Amet<T> {
+    consectetur: T,
+    #[builder(default)]
+    adipiscing: Option<T>,
+}
+
+#[test]
+fn set_fields_override() {
+    let mut base = LoremBuilder::default();
+    base.ipsum(1).dolor("dolor".to_string());
+    let mut other = LoremBuilder::default();
+    other.ipsum(42);
+
+    let x = base.merge(&other).build().unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: 42,
+        dolor: "dolor".to_string(),
+        sit: false,
+    });
+}
+
+#[test]
+fn unset_fields_are_kept() {
+    let mut base = LoremBuilder::default();
+    base.ipsum(1).dolor("dolor".to_string());
+
+    let x = base.merge(&LoremBuilder::default()).build().unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: 1,
+        dolor: "dolor".to_string(),
+        sit: false,
+    });
+}
+
+#[test]
+fn generic_owned() {
+    let other = AmetBuilder::default().adipiscing(Some(2));
+    let mut base = AmetBuilder::default().consectetur(1);
+    base.merge(&other);
+
+    assert_eq!(base.build().unwrap(), Amet {
+        consectetur: 1,
+        adipiscing: Some(2),
+    });
+}
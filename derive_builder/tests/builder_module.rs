@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+mod inner {
+    #[derive(Debug, PartialEq, Default, Builder, Clone)]
+    #[builder(module = "lorem_builder", setter(private), field(private))]
+    pub struct Lorem {
+        ipsum: u32,
+    }
+
+    impl LoremBuilder {
+        /// Private setters and fields must remain accessible from this module.
+        pub fn answer(&mut self) -> &mut Self {
+            assert!(self.ipsum.is_none());
+            self.ipsum(42)
+        }
+    }
+
+    #[derive(Debug, PartialEq, Default, Builder, Clone)]
+    #[builder(module = "dolor_builder", update_struct = "DolorUpdate", view)]
+    pub struct Dolor {
+        pub sit: String,
+    }
+
+    #[derive(Debug, PartialEq, Default, Builder, Clone)]
+    #[builder(module = "amet_builder")]
+    pub(super) struct Amet {
+        pub consectetur: bool,
+    }
+
+    #[test]
+    fn module_path() {
+        let x = dolor_builder::DolorBuilder::default().sit("sit".to_string()).build().unwrap();
+        let update: dolor_builder::DolorUpdate = Default::default();
+
+        assert_eq!(x, Dolor { sit: "sit".to_string() });
+        assert_eq!(update.sit, None);
+    }
+}
+
+use inner::{LoremBuilder, DolorBuilder, DolorUpdate, DolorBuilderView};
+
+#[test]
+fn private_members() {
+    let x = LoremBuilder::default().answer().build().unwrap();
+
+    assert_eq!(format!("{:?}", x), "Lorem { ipsum: 42 }");
+}
+
+#[test]
+fn reexports() {
+    let mut builder = DolorBuilder::default();
+    builder.apply_update(DolorUpdate { sit: Some("sit".to_string()) });
+    let view: DolorBuilderView = builder.view();
+
+    assert_eq!(view.sit, Some(&"sit".to_string()));
+    assert_eq!(builder.build().unwrap(), inner::Dolor { sit: "sit".to_string() });
+}
+
+#[test]
+fn restricted_visibility() {
+    let x = inner::AmetBuilder::default().consectetur(true).build().unwrap();
+
+    assert_eq!(x, inner::Amet { consectetur: true });
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(serde, derive(Serialize))]
+struct Lorem {
+    ipsum: String,
+    dolor: Option<u32>,
+    #[builder(default)]
+    sit: Vec<bool>,
+    #[builder(setter(skip))]
+    amet: u32,
+}
+
+#[test]
+fn unset_fields_are_omitted() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum("ipsum".to_string()).dolor(None);
+
+    assert_eq!(serde_json::to_value(&builder).unwrap(), json!({
+        "ipsum": "ipsum",
+        "dolor": null,
+    }));
+}
+
+#[test]
+fn empty() {
+    assert_eq!(serde_json::to_value(&LoremBuilder::default()).unwrap(), json!({}));
+}
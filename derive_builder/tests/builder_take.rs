@@ -0,0 +1,51 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(field_take)]
+struct Lorem {
+    ipsum: u32,
+    dolor: Vec<String>,
+    #[builder(setter(skip))]
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", field_take)]
+struct Amet<T> {
+    consectetur: T,
+}
+
+// compile test
+#[allow(dead_code)]
+impl LoremBuilder {
+    // only possible if no `take_sit` was generated for the skipped field
+    fn take_sit(&mut self) {}
+}
+
+#[test]
+fn take_leaves_field_unset() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum(42).dolor(vec!["dolor".to_string()]);
+
+    assert_eq!(builder.take_dolor(), Some(vec!["dolor".to_string()]));
+    assert_eq!(builder.take_dolor(), None);
+    assert_eq!(builder.build().unwrap_err(), "`dolor` must be initialized");
+}
+
+#[test]
+fn take_unset() {
+    let mut builder = LoremBuilder::default();
+
+    assert_eq!(builder.take_ipsum(), None);
+}
+
+#[test]
+fn take_generic() {
+    let mut builder = AmetBuilder::default().consectetur("adipiscing");
+
+    assert_eq!(builder.take_consectetur(), Some("adipiscing"));
+    assert!(builder.build().is_err());
+}
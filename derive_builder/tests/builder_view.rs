@@ -0,0 +1,51 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(view)]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+    #[builder(setter(skip))]
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(view, pattern = "owned", name = "AmetConfig")]
+struct Amet<'a, T> {
+    consectetur: &'a str,
+    adipiscing: T,
+}
+
+fn describe(view: LoremBuilderView) -> String {
+    match (view.ipsum, view.dolor) {
+        (Some(ipsum), Some(dolor)) => format!("{} {}", ipsum, dolor),
+        (Some(ipsum), None) => format!("{}", ipsum),
+        _ => "nothing".to_string(),
+    }
+}
+
+#[test]
+fn view() {
+    let mut builder = LoremBuilder::default();
+    assert_eq!(describe(builder.view()), "nothing");
+
+    builder.ipsum(42);
+    assert_eq!(describe(builder.view()), "42");
+
+    builder.dolor("elit".to_string());
+    let view = builder.view();
+    assert_eq!(view.ipsum, Some(&42));
+    assert_eq!(view.dolor, Some(&"elit".to_string()));
+}
+
+#[test]
+fn generic_view() {
+    let builder = AmetConfig::default().adipiscing(vec![1, 2]);
+    let view: AmetConfigView<Vec<u32>> = builder.view();
+
+    assert_eq!(view.consectetur, None);
+    assert_eq!(view.adipiscing, Some(&vec![1, 2]));
+}
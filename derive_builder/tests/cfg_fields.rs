@@ -0,0 +1,44 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+/// Integration tests are always compiled with `cfg(test)`, so `not(test)` acts
+/// like a disabled feature.
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    ipsum: u32,
+    #[cfg(not(test))]
+    dolor: String,
+    #[cfg(test)]
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(build_fn(collect_errors))]
+struct Amet {
+    #[cfg(not(test))]
+    consectetur: String,
+    #[cfg(not(test))]
+    #[builder(default = "42")]
+    adipiscing: u32,
+}
+
+#[test]
+fn disabled_field_is_skipped() {
+    let x = LoremBuilder::default().ipsum(1).sit(true).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: 1, sit: true });
+}
+
+#[test]
+fn enabled_field_is_required() {
+    let err = LoremBuilder::default().ipsum(1).build().unwrap_err();
+
+    assert_eq!(&err, "`sit` must be initialized");
+}
+
+#[test]
+fn disabled_field_is_not_checked() {
+    assert_eq!(AmetBuilder::default().build().unwrap(), Amet {});
+}
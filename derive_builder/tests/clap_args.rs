@@ -0,0 +1,54 @@
+#![cfg(feature = "clap")]
+
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+extern crate clap;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(clap, derive(Debug))]
+struct Lorem {
+    ipsum: String,
+    dolor_sit: u32,
+    #[builder(default = "true")]
+    amet: bool,
+    #[builder(setter(skip))]
+    consectetur: Vec<u32>,
+}
+
+fn command() -> clap::Command {
+    LoremBuilder::augment_args(clap::Command::new("lorem"))
+}
+
+#[test]
+fn from_matches() {
+    let matches = command()
+        .try_get_matches_from(vec!["lorem", "--ipsum", "adipiscing", "--dolor-sit", "42"])
+        .unwrap();
+    let x = LoremBuilder::from_matches(&matches).unwrap().build().unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: "adipiscing".to_string(),
+        dolor_sit: 42,
+        amet: true,
+        consectetur: vec![],
+    });
+}
+
+#[test]
+fn from_matches_invalid_value() {
+    let matches = command()
+        .try_get_matches_from(vec!["lorem", "--dolor-sit", "elit"])
+        .unwrap();
+
+    assert_eq!(LoremBuilder::from_matches(&matches).unwrap_err(),
+               "Unable to parse value for `dolor_sit`");
+}
+
+#[test]
+fn skipped_field_has_no_arg() {
+    assert!(command()
+        .try_get_matches_from(vec!["lorem", "--consectetur", "1"])
+        .is_err());
+}
@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(collect_errors))]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+    #[builder(default = "42")]
+    sit: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(collect_errors, validate = "AmetBuilder::validate"))]
+struct Amet {
+    consectetur: u32,
+    adipiscing: u32,
+}
+
+impl AmetBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.consectetur {
+            Some(0) => Err("consectetur must not be zero".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[test]
+fn success() {
+    let x = LoremBuilder::default()
+        .ipsum(1)
+        .dolor("elit".to_string())
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: 1,
+        dolor: "elit".to_string(),
+        sit: 42,
+    });
+}
+
+#[test]
+fn all_missing_fields() {
+    let errors = LoremBuilder::default().build().unwrap_err();
+
+    assert_eq!(errors, vec![
+        "`ipsum` must be initialized".to_string(),
+        "`dolor` must be initialized".to_string(),
+    ]);
+}
+
+#[test]
+fn validation_and_missing_fields() {
+    let errors = AmetBuilder::default().consectetur(0).build().unwrap_err();
+
+    assert_eq!(errors, vec![
+        "consectetur must not be zero".to_string(),
+        "`adipiscing` must be initialized".to_string(),
+    ]);
+}
@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate derive_builder;
+
+// `validate` must return a `Result`
+#[derive(Builder)]
+//~^ ERROR mismatched types
+//~| ERROR the `?` operator can only be applied to values that implement `Try`
+#[builder(build_fn(validate = "LoremBuilder::validate"))]
+struct Lorem {
+    ipsum: u32,
+}
+
+impl LoremBuilder {
+    fn validate(&self) -> bool {
+        self.ipsum.is_some()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#[macro_use]
+extern crate derive_builder;
+
+/// This builder is in an inner module to make sure the private build method isn't accessible
+/// from the `main` function.
+mod inner {
+    /// The `LoremBuilder` struct is public, but its build method is private to `inner`.
+    #[derive(Debug, Builder)]
+    #[builder(build_fn(vis = ""))]
+    pub struct Lorem {
+        ipsum: u16,
+    }
+}
+
+fn main() {
+    use inner::LoremBuilder;
+
+    let mut lorem = LoremBuilder::default();
+    lorem.ipsum(15u16);
+    lorem.build();
+    //~^ ERROR method `build` is private
+}
@@ -0,0 +1,18 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+struct Lorem<const N: usize> {
+    ipsum: [u32; N],
+}
+
+// the parentheses of the bound must not hide the following const parameter
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+struct Dolor<T: Fn(u8) -> Vec<u8>, const N: usize> {
+    sit: T,
+    amet: [u32; N],
+}
+
+fn main() {}
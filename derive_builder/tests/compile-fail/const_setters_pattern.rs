@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+#[builder(const_setters)]
+struct Lorem {
+    ipsum: u32,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate derive_builder;
+
+// deny default expressions with unbalanced delimiters while parsing the attribute, so the
+// error names the field instead of failing when the build method is generated
+#[allow(dead_code)]
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+struct Lorem {
+    #[builder(default = "vec![1, 2")]
+    ipsum: Vec<u32>,
+}
+
+fn main() {}
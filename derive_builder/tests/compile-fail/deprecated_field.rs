@@ -0,0 +1,17 @@
+#![deny(deprecated)]
+
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, Builder)]
+pub struct Lorem {
+    #[deprecated(note = "use `dolor` instead")]
+    #[builder(setter(into))]
+    ipsum: u32,
+    dolor: u32,
+}
+
+fn main() {
+    LoremBuilder::default().ipsum(42u8);
+    //~^ ERROR use of deprecated
+}
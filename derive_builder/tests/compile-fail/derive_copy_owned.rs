@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate derive_builder;
+
+// deny `#[builder(derive(Copy))]` for owned builders, which don't derive `Clone` automatically
+#[allow(dead_code)]
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+
+#[builder(pattern = "owned", derive(Copy))]
+struct Lorem {
+    ipsum: u32,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate derive_builder;
+
+// `Lorem::default()` has no value for `ipsum`
+#[allow(dead_code)]
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+#[builder(derive_target(Default))]
+struct Lorem {
+    ipsum: u32,
+    #[builder(default)]
+    dolor: u32,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+//! Without generated docs, the builder trips `missing_docs`.
+#![deny(missing_docs)]
+
+#[macro_use]
+extern crate derive_builder;
+
+/// Lorem
+#[derive(Builder)]
+//~^ ERROR missing documentation for a struct
+//~| ERROR missing documentation for a method
+
+#[builder(no_docs)]
+pub struct Lorem {
+    /// Ipsum
+    pub ipsum: u32,
+}
+
+fn main() {}
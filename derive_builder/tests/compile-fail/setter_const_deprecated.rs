@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate derive_builder;
+
+// deny `#[builder(public, setter(const_fn))]`, because the deprecation note calls a regular fn
+#[allow(dead_code)]
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+
+#[builder(pattern = "owned")]
+struct Lorem {
+    #[builder(public, setter(const_fn))]
+    ipsum: u32,
+}
+
+fn main() {}
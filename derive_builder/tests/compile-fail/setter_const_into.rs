@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate derive_builder;
+
+// deny `#[builder(setter(const_fn, into))]`, because trait methods can't be called in `const fn`
+#[allow(dead_code)]
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+
+#[builder(pattern = "owned")]
+struct Lorem {
+    #[builder(setter(const_fn, into))]
+    ipsum: u32,
+}
+
+fn main() {}
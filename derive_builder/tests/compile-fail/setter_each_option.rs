@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate derive_builder;
+
+// deny `#[builder(setter(each = "..."))]` on `Option` fields without `strip_option`, because
+// items can only be added to the collection inside the `Option`
+#[allow(dead_code)]
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+struct Lorem {
+    #[builder(setter(each = "arg"))]
+    args: Option<Vec<String>>,
+}
+
+fn main() {}
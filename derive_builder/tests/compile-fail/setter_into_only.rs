@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+#[builder(setter(into(only("String"))))]
+struct Lorem {
+    ipsum: String,
+    dolor: u32,
+}
+
+fn main() {
+    let _ = LoremBuilder::default().ipsum("ipsum").dolor(42u8).build();
+    //~^ ERROR mismatched types
+}
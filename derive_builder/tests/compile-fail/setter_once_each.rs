@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+struct Lorem {
+    #[builder(setter(once, each = "ipsum_item"))]
+    ipsum: Vec<u32>,
+}
+
+fn main() {}
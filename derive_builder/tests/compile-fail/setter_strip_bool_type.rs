@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate derive_builder;
+
+// deny `#[builder(setter(strip_bool))]` on non-bool fields, because they can't be set to `true`
+#[allow(dead_code)]
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+struct Lorem {
+    #[builder(setter(strip_bool))]
+    ipsum: u32,
+}
+
+fn main() {}
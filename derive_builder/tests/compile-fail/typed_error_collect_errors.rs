@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Builder)]
+//~^ ERROR proc-macro derive panicked
+#[builder(build_fn(typed_error, collect_errors))]
+struct Lorem {
+    ipsum: u32,
+}
+
+fn main() {}
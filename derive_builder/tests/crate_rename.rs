@@ -0,0 +1,51 @@
+//! The generated code must not refer to `::derive_builder`, so the crate can be
+//! renamed or re-exported by a facade crate.
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder as builder_facade;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(default, setter(into), build_fn(validate = "LoremBuilder::validate"))]
+struct Lorem {
+    ipsum: String,
+    #[builder(setter(each = "push_dolor"))]
+    dolor: Vec<u32>,
+}
+
+impl LoremBuilder {
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// A framework re-exporting the derive under its own path.
+mod framework {
+    pub mod builder {
+        pub use builder_facade::Builder;
+    }
+}
+
+mod consumer {
+    use framework::builder::Builder;
+
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(setter(into))]
+    pub struct Sit {
+        pub amet: String,
+    }
+}
+
+#[test]
+fn renamed_crate() {
+    let x = LoremBuilder::default().ipsum("ipsum").push_dolor(1).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: "ipsum".to_string(), dolor: vec![1] });
+}
+
+#[test]
+fn reexported_derive() {
+    let x = consumer::SitBuilder::default().amet("amet").build().unwrap();
+
+    assert_eq!(x, consumer::Sit { amet: "amet".to_string() });
+}
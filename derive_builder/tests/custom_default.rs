@@ -107,6 +107,41 @@ mod struct_level {
         }
     }
 
+    /// Implements neither `Default` nor `Clone`.
+    #[cfg(feature = "struct_default")]
+    #[derive(Debug, PartialEq, Eq)]
+    struct NoDefault(u32);
+
+    #[cfg(feature = "struct_default")]
+    #[derive(Debug, PartialEq, Eq, Builder)]
+    #[builder(pattern = "owned", default = "Dolor::base()")]
+    struct Dolor {
+        sit: NoDefault,
+        amet: NoDefault,
+    }
+
+    #[cfg(feature = "struct_default")]
+    impl Dolor {
+        fn base() -> Dolor {
+            Dolor {
+                sit: NoDefault(1),
+                amet: NoDefault(2),
+            }
+        }
+    }
+
+    #[cfg(feature = "struct_default")]
+    #[test]
+    fn explicit_default_without_default_impl() {
+        let dolor = DolorBuilder::default().amet(NoDefault(42)).build().unwrap();
+
+        assert_eq!(dolor,
+                   Dolor {
+                       sit: NoDefault(1),
+                       amet: NoDefault(42),
+                   });
+    }
+
     #[test]
     fn explicit_defaults_are_equal() {
         let lorem = LoremBuilder::default().build().unwrap();
@@ -155,3 +190,77 @@ mod struct_level {
         assert_eq!(ipsum.not_type_default, None);
     }
 }
+
+mod default_into {
+    use std::path::PathBuf;
+
+    #[derive(Debug, PartialEq, Builder)]
+    struct Lorem {
+        #[builder(setter(into), default(into = "\"hi\""))]
+        greeting: String,
+        #[builder(setter(into), default(into = r#""/tmp""#))]
+        path: PathBuf,
+        #[builder(default(into = "42u8"))]
+        widened: u64,
+    }
+
+    #[test]
+    fn defaults_are_converted() {
+        let x = LoremBuilder::default().build().unwrap();
+
+        assert_eq!(x,
+                   Lorem {
+                       greeting: "hi".to_string(),
+                       path: PathBuf::from("/tmp"),
+                       widened: 42,
+                   });
+    }
+
+    #[test]
+    fn overrides_work() {
+        let x = LoremBuilder::default().greeting("hello").widened(1u64).build().unwrap();
+
+        assert_eq!(x.greeting, "hello");
+        assert_eq!(x.widened, 1);
+    }
+}
+
+mod target_default {
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(derive_target(Default), option_as_optional)]
+    struct Lorem {
+        #[builder(default = "42")]
+        ipsum: u32,
+        #[builder(default(into = "\"dolor\""))]
+        dolor: String,
+        sit: Option<u32>,
+        #[builder(setter(skip))]
+        amet: Vec<u32>,
+    }
+
+    #[derive(Debug, PartialEq, Builder)]
+    #[builder(derive_target(Default))]
+    struct Consectetur<T: Clone + Default> {
+        #[builder(default)]
+        adipiscing: T,
+    }
+
+    #[test]
+    fn target_default_equals_build() {
+        assert_eq!(Lorem::default(), LoremBuilder::default().build().unwrap());
+        assert_eq!(Lorem::default(),
+                   Lorem {
+                       ipsum: 42,
+                       dolor: "dolor".to_string(),
+                       sit: None,
+                       amet: vec![],
+                   });
+    }
+
+    #[test]
+    fn generic_target_default() {
+        let x: Consectetur<String> = Default::default();
+
+        assert_eq!(x, ConsecteturBuilder::default().build().unwrap());
+    }
+}
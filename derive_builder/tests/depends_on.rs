@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::cell::Cell;
+
+thread_local!(static COUNTER: Cell<u32> = Cell::new(0));
+
+/// Returns the number of previous calls on this thread.
+fn tick() -> u32 {
+    COUNTER.with(|c| {
+        let x = c.get();
+        c.set(x + 1);
+        x
+    })
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(default = "tick()", field(depends_on("dolor")))]
+    ipsum: u32,
+    #[builder(default = "tick()")]
+    dolor: u32,
+    #[builder(default = "tick()", field(depends_on("ipsum", "dolor")))]
+    sit: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(validate = "AmetBuilder::validate"))]
+struct Amet {
+    #[builder(default = "tick()")]
+    consectetur: u32,
+    #[builder(setter(skip = "tick()"))]
+    adipiscing: u32,
+    elit: u32,
+    #[builder(default = "tick()")]
+    sed: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(derive_target(Default))]
+struct Consectetur {
+    #[builder(default = "tick()", field(depends_on("elit")))]
+    adipiscing: u32,
+    #[builder(default = "tick()")]
+    elit: u32,
+}
+
+impl AmetBuilder {
+    fn validate(&self) -> Result<(), String> {
+        tick();
+        Ok(())
+    }
+}
+
+#[test]
+fn defaults_respect_dependencies() {
+    let x = LoremBuilder::default().build().unwrap();
+
+    assert_eq!(x,
+               Lorem {
+                   dolor: 0,
+                   ipsum: 1,
+                   sit: 2,
+               });
+}
+
+#[test]
+fn defaults_in_declaration_order() {
+    let x = AmetBuilder::default().elit(42).build().unwrap();
+
+    // `validate` comes first
+    assert_eq!(x,
+               Amet {
+                   consectetur: 1,
+                   adipiscing: 2,
+                   elit: 42,
+                   sed: 3,
+               });
+}
+
+#[test]
+fn target_default_respects_dependencies() {
+    assert_eq!(Consectetur::default(),
+               Consectetur {
+                   adipiscing: 1,
+                   elit: 0,
+               });
+}
@@ -15,9 +15,76 @@ struct Lorem {
     excluded: NotPartialEq,
 }
 
+/// Not `Default`, so a derived `Default` on `IpsumBuilder<NotDefault>` would not apply.
+#[derive(Debug, PartialEq)]
+struct NotDefault(u8);
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", derive_default = false)]
+struct Ipsum<T> {
+    dolor: T,
+    #[builder(setter(skip))]
+    sit: Option<T>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", derive(Clone, Copy))]
+struct Dolor {
+    sit: u8,
+}
+
+// only the `derive` attributes after the one listing `Builder` are visible to the macro
+#[derive(Builder)]
+#[derive(Debug, Clone, PartialEq, Default)]
+#[builder(derive(inherit))]
+struct Amet {
+    consectetur: u8,
+}
+
+#[derive(Builder)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[builder(pattern = "owned", derive(inherit, Eq))]
+struct Adipiscing {
+    elit: u8,
+}
+
+#[test]
+fn owned_clone_copy() {
+    let builder = DolorBuilder::default().sit(1);
+    let copy = builder;
+
+    assert_eq!(builder.clone().build().unwrap(), Dolor { sit: 1 });
+    assert_eq!(copy.build().unwrap(), Dolor { sit: 1 });
+}
+
+#[test]
+fn manual_default() {
+    let x = IpsumBuilder::<NotDefault>::default().dolor(NotDefault(1)).build().unwrap();
+
+    assert_eq!(x, Ipsum { dolor: NotDefault(1), sit: None });
+}
+
 #[test]
 fn defaults() {
     // This macro requires that the two sides implement `PartialEq` AND `Debug`,
     // so this one line is testing that the requested traits were really generated.
     assert_eq!(LoremBuilder::default(), LoremBuilder::default());
 }
+
+#[test]
+fn inherit() {
+    let mut builder = AmetBuilder::default();
+    builder.consectetur(1);
+
+    assert_eq!(builder.clone(), builder);
+    assert_eq!(format!("{:?}", builder), "AmetBuilder { consectetur: Some(1) }");
+}
+
+#[test]
+fn inherit_owned() {
+    let builder = AdipiscingBuilder::default().elit(1);
+    let copy = builder;
+
+    assert_eq!(builder, copy);
+    assert_eq!(copy.build().unwrap(), Adipiscing { elit: 1 });
+}
@@ -0,0 +1,60 @@
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(setter(into))]
+enum Lorem {
+    Ipsum { dolor: u32 },
+    #[builder(name = "SitLoremBuilder", pattern = "owned")]
+    Sit {
+        amet: String,
+        #[builder(default = "42")]
+        consectetur: usize,
+    },
+    #[allow(dead_code)]
+    Unit,
+    #[allow(dead_code)]
+    Tuple(u8),
+}
+
+#[derive(Debug, PartialEq, Builder)]
+enum Generic<T: Clone> {
+    Value { value: T },
+}
+
+#[test]
+fn variant_builder() {
+    let x = LoremIpsumBuilder::default()
+        .dolor(1u8)
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Lorem::Ipsum { dolor: 1 });
+}
+
+#[test]
+fn variant_options() {
+    let x = SitLoremBuilder::default()
+        .amet("adipiscing")
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Lorem::Sit { amet: "adipiscing".to_string(), consectetur: 42 });
+}
+
+#[test]
+fn uninitialized_field() {
+    let err = LoremIpsumBuilder::default().build().unwrap_err();
+
+    assert_eq!(&err, "`dolor` must be initialized");
+}
+
+#[test]
+fn generic_variant() {
+    let x = GenericValueBuilder::default()
+        .value("elit")
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Generic::Value { value: "elit" });
+}
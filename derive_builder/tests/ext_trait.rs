@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(ext_trait)]
+struct Lorem {
+    ipsum: String,
+    dolor: u32,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(ext_trait, pattern = "owned")]
+struct Sit<T> {
+    amet: Vec<T>,
+}
+
+/// Custom setters, available on all builders implementing `LoremBuilderExt`.
+trait LoremSetters {
+    fn ipsum_dolor(&mut self, ipsum: &str, dolor: u32) -> &mut Self;
+}
+
+impl<T: LoremBuilderExt> LoremSetters for T {
+    fn ipsum_dolor(&mut self, ipsum: &str, dolor: u32) -> &mut Self {
+        self.as_mut().ipsum(ipsum.to_string()).dolor(dolor);
+        self
+    }
+}
+
+trait SitSetters<T> {
+    fn single(self, value: T) -> Self;
+}
+
+impl<T: Clone, B: SitBuilderExt<T>> SitSetters<T> for B {
+    fn single(mut self, value: T) -> Self {
+        self.as_mut().amet = Some(vec![value]);
+        self
+    }
+}
+
+#[test]
+fn custom_setter() {
+    let x = LoremBuilder::default().ipsum_dolor("ipsum", 42).build().unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: "ipsum".to_string(),
+        dolor: 42,
+    });
+}
+
+#[test]
+fn custom_setter_generic() {
+    let x = SitBuilder::default().single(42).build().unwrap();
+
+    assert_eq!(x, Sit { amet: vec![42] });
+}
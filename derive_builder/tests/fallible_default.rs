@@ -0,0 +1,49 @@
+#[macro_use]
+extern crate derive_builder;
+
+fn parse_port(s: &str) -> Result<u16, String> {
+    s.parse().map_err(|_| format!("invalid port `{}`", s))
+}
+
+fn lookup_host() -> Result<String, &'static str> {
+    Err("no host configured")
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(default = "parse_port(\"8080\")?")]
+    ipsum: u16,
+    #[builder(default = "parse_port(\"eighty\")?")]
+    dolor: u16,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(collect_errors))]
+struct Ipsum {
+    #[builder(default = "lookup_host()?")]
+    sit: String,
+    amet: u16,
+}
+
+#[test]
+fn default_ok() {
+    let x = LoremBuilder::default().dolor(80).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: 8080, dolor: 80 });
+}
+
+#[test]
+fn default_err() {
+    let err = LoremBuilder::default().build().unwrap_err();
+
+    assert_eq!(&err, "invalid port `eighty`");
+}
+
+#[test]
+fn collect_errors() {
+    let x = IpsumBuilder::default().sit("localhost".to_string()).amet(80).build().unwrap();
+    assert_eq!(x, Ipsum { sit: "localhost".to_string(), amet: 80 });
+
+    let err = IpsumBuilder::default().amet(80).build().unwrap_err();
+    assert_eq!(err, vec!["no host configured".to_string()]);
+}
@@ -0,0 +1,41 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(new)]
+struct Lorem {
+    #[builder(setter(each = "ipsum_item"), field(default = "vec![]"))]
+    ipsum: Vec<u32>,
+    #[builder(field(default = "42"))]
+    dolor: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Sit<T> {
+    #[builder(field(default = "Vec::new()"))]
+    amet: Vec<T>,
+}
+
+#[test]
+fn initial_values_count_as_set() {
+    let x = LoremBuilder::default().build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: vec![], dolor: 42 });
+}
+
+#[test]
+fn each_setter_starts_from_initial_value() {
+    let x = LoremBuilder::new().ipsum_item(1).ipsum_item(2).dolor(3).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: vec![1, 2], dolor: 3 });
+}
+
+#[test]
+fn generic() {
+    let x: Sit<String> = SitBuilder::default().build().unwrap();
+
+    assert_eq!(x, Sit { amet: vec![] });
+}
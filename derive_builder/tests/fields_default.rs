@@ -0,0 +1,35 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(fields_default)]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+    #[builder(default = "42")]
+    sit: u32,
+}
+
+#[test]
+fn all_defaults() {
+    let x = LoremBuilder::default().build().unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: 0,
+        dolor: String::new(),
+        sit: 42,
+    });
+}
+
+#[test]
+fn set_fields() {
+    let x = LoremBuilder::default().ipsum(1).sit(2).build().unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: 1,
+        dolor: String::new(),
+        sit: 2,
+    });
+}
@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(fields_default)]
+struct Lorem {
+    #[builder(field(forbid_default))]
+    ipsum: String,
+    dolor: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(collect_errors))]
+struct Sit {
+    #[builder(field(forbid_default))]
+    amet: u32,
+}
+
+#[test]
+fn set_field() {
+    let x = LoremBuilder::default().ipsum("ipsum".to_string()).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: "ipsum".to_string(), dolor: 0 });
+}
+
+#[test]
+fn left_at_default() {
+    let err = LoremBuilder::default().dolor(1).build().unwrap_err();
+
+    assert_eq!(&err, "`ipsum` must not be left at its default value");
+}
+
+#[test]
+fn set_to_default() {
+    let err = LoremBuilder::default().ipsum(String::new()).build().unwrap_err();
+
+    assert_eq!(&err, "`ipsum` must not be left at its default value");
+}
+
+#[test]
+fn collect_errors() {
+    let errors = SitBuilder::default().amet(0).build().unwrap_err();
+
+    assert_eq!(errors, vec!["`amet` must not be left at its default value".to_string()]);
+}
@@ -0,0 +1,82 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(from_pairs)]
+struct Lorem {
+    ipsum: String,
+    dolor: u16,
+    #[builder(default)]
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(from_pairs(deny_unknown), derive(Debug))]
+struct Ipsum {
+    dolor: u16,
+}
+
+fn pairs(x: &[(&str, &str)]) -> Vec<(String, String)> {
+    x.iter().map(|&(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn from_pairs() {
+    let x = LoremBuilder::from_pairs(pairs(&[("ipsum", "lorem"), ("dolor", "42")]))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(x,
+               Lorem {
+                   ipsum: "lorem".to_string(),
+                   dolor: 42,
+                   sit: false,
+               });
+}
+
+#[test]
+fn from_iter() {
+    let builder: LoremBuilder = pairs(&[("ipsum", "lorem"), ("dolor", "42"), ("sit", "true")])
+        .into_iter()
+        .collect();
+
+    assert_eq!(builder.build().unwrap(),
+               Lorem {
+                   ipsum: "lorem".to_string(),
+                   dolor: 42,
+                   sit: true,
+               });
+}
+
+#[test]
+fn unknown_keys_are_ignored() {
+    let x = LoremBuilder::from_pairs(vec![("ipsum", "lorem"), ("dolor", "42"), ("amet", "?")])
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(x.dolor, 42);
+}
+
+#[test]
+fn unknown_keys_are_denied() {
+    let err = IpsumBuilder::from_pairs(vec![("dolor", "42"), ("amet", "?")]).unwrap_err();
+
+    assert_eq!(&err, "Unknown key `amet`");
+}
+
+#[test]
+fn invalid_value() {
+    let err = IpsumBuilder::from_pairs(vec![("dolor", "forty-two")]).unwrap_err();
+
+    assert_eq!(&err, "Unable to parse value for `dolor`");
+}
+
+#[test]
+#[should_panic(expected = "Unable to parse value for `dolor`")]
+fn from_iter_invalid_value() {
+    let _: IpsumBuilder = pairs(&[("dolor", "forty-two")]).into_iter().collect();
+}
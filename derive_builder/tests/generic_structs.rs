@@ -5,6 +5,8 @@ extern crate derive_builder;
 
 use std::fmt::Display;
 use std::clone::Clone;
+use std::marker::PhantomData;
+use std::ops::Add;
 
 #[derive(Debug, PartialEq, Default, Builder, Clone)]
 struct Generic<T: Display>
@@ -21,6 +23,47 @@ pub struct GenericReference<'a, T: 'a + Default>
     pub bar: Option<&'a T>,
 }
 
+/// The field types are only well-formed with the `where` clause, so it must be
+/// forwarded to the builder struct, its impls and the build method.
+#[derive(Debug, PartialEq, Builder)]
+#[builder(update_struct = "GenericWhereUpdate", merge, build_fn(by_ref))]
+pub struct GenericWhere<T>
+    where T: Iterator + Clone,
+          T::Item: Clone
+{
+    pub iter: T,
+    #[builder(default)]
+    pub first: Option<T::Item>,
+}
+
+/// The type parameter of generic setters must not collide with the struct's.
+#[derive(Debug, PartialEq, Builder)]
+#[builder(setter(into))]
+pub struct GenericValue<VALUE: Clone, ITEM: Clone> {
+    #[builder(setter(each(name = "push_value", into)))]
+    pub value: Vec<VALUE>,
+    #[builder(setter(into_iter))]
+    pub items: Vec<ITEM>,
+}
+
+/// Associated types are passed through to the `Into` bounds of the setters as they are.
+#[derive(Builder)]
+#[builder(setter(into))]
+pub struct GenericAssociated<T, I>
+    where T: Add<u32> + Clone,
+          T::Output: Clone,
+          I: IntoIterator + Clone,
+          I::Item: Clone
+{
+    pub sum: T::Output,
+    #[builder(setter(strip_option))]
+    pub item: Option<<I as IntoIterator>::Item>,
+    #[builder(setter(each(name = "push_sum", into)))]
+    pub sums: Vec<T::Output>,
+    #[builder(default)]
+    pub phantom: PhantomData<(T, I)>,
+}
+
 #[test]
 #[should_panic(expected="`ipsum` must be initialized")]
 fn panic_if_uninitialized() {
@@ -53,3 +96,42 @@ fn generic_reference_builder() {
 
     assert_eq!(x, GenericReference { bar: Some(&BAR) });
 }
+
+#[test]
+fn generic_where_builder() {
+    let mut builder = GenericWhereBuilder::default();
+    builder.iter(1..3).first(Some(1));
+
+    let x = builder.build_ref().unwrap();
+    assert_eq!(x, GenericWhere { iter: 1..3, first: Some(1) });
+
+    let update = GenericWhereUpdate { first: Some(None), ..Default::default() };
+    let x = builder.apply_update(update).build().unwrap();
+    assert_eq!(x, GenericWhere { iter: 1..3, first: None });
+}
+
+#[test]
+fn generic_value_builder() {
+    let x = GenericValueBuilder::default()
+        .value(vec![1u8])
+        .push_value(2u8)
+        .items(vec!["lorem"])
+        .build()
+        .unwrap();
+
+    assert_eq!(x, GenericValue { value: vec![1, 2], items: vec!["lorem"] });
+}
+
+#[test]
+fn generic_associated_builder() {
+    let x = GenericAssociatedBuilder::<u32, Vec<u8>>::default()
+        .sum(1u8)
+        .item(2u8)
+        .push_sum(3u16)
+        .build()
+        .unwrap();
+
+    assert_eq!(x.sum, 1);
+    assert_eq!(x.item, Some(2));
+    assert_eq!(x.sums, vec![3]);
+}
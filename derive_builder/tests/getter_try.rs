@@ -0,0 +1,54 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+struct Lorem {
+    #[builder(getter(try))]
+    ipsum: String,
+    dolor: u32,
+    #[builder(getter(try), setter(skip))]
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", getter(try))]
+struct Amet<T> {
+    consectetur: T,
+    #[builder(default = "42")]
+    adipiscing: u32,
+}
+
+// compile test
+#[allow(dead_code)]
+impl LoremBuilder {
+    // only possible if no `get_dolor` was generated without `getter(try)`
+    fn get_dolor(&self) {}
+    // only possible if no `get_sit` was generated for the skipped field
+    fn get_sit(&self) {}
+}
+
+#[test]
+fn getter_unset() {
+    let builder = LoremBuilder::default();
+
+    assert_eq!(builder.get_ipsum(), Err("`ipsum` must be initialized".to_string()));
+}
+
+#[test]
+fn getter_set() {
+    let mut builder = LoremBuilder::default();
+    builder.ipsum("elit".to_string());
+
+    assert_eq!(builder.get_ipsum(), Ok(&"elit".to_string()));
+}
+
+#[test]
+fn getter_struct_level() {
+    let builder = AmetBuilder::default().consectetur(vec![1u8]);
+
+    assert_eq!(builder.get_consectetur(), Ok(&vec![1u8]));
+    // defaults are only applied by the build method
+    assert_eq!(builder.get_adipiscing(), Err("`adipiscing` must be initialized".to_string()));
+}
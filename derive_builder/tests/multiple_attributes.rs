@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+#[builder(setter(prefix = "with"))]
+struct Lorem {
+    #[builder(setter(into))]
+    #[builder(default = "\"ipsum\".to_string()")]
+    ipsum: String,
+    /// Attributes of other derives in between don't matter.
+    #[builder(setter(name = "sit"))]
+    #[allow(dead_code)]
+    #[builder(default)]
+    dolor: u32,
+}
+
+#[test]
+fn options_of_all_attributes_apply() {
+    let x = LoremBuilder::default().build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: "ipsum".to_string(), dolor: 0 });
+
+    let x = LoremBuilder::default().with_ipsum("amet").sit(42).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: "amet".to_string(), dolor: 42 });
+}
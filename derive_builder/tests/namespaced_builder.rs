@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+/// Both derives read their own attributes only, so the builders don't interfere.
+#[derive(Debug, PartialEq, Default, Builder, NamespacedBuilder, Clone)]
+#[builder(name = "LoremConfig")]
+#[derive_builder(pattern = "owned")]
+struct Lorem {
+    #[builder(setter(into))]
+    #[derive_builder(setter(name = "with_ipsum"))]
+    ipsum: String,
+    #[derive_builder(default = "42")]
+    dolor: u32,
+}
+
+#[derive(Debug, PartialEq, NamespacedBuilder)]
+enum Sit {
+    #[derive_builder(setter(into))]
+    Amet { consectetur: String },
+}
+
+#[test]
+fn namespaced_options() {
+    let x = LoremBuilder::default().with_ipsum("ipsum".to_string()).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: "ipsum".to_string(), dolor: 42 });
+}
+
+#[test]
+fn builder_options() {
+    let x = LoremConfig::default().ipsum("ipsum").dolor(1).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: "ipsum".to_string(), dolor: 1 });
+}
+
+#[test]
+fn namespaced_enum() {
+    let x = SitAmetBuilder::default().consectetur("consectetur").build().unwrap();
+
+    assert_eq!(x, Sit::Amet { consectetur: "consectetur".to_string() });
+}
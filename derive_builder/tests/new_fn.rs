@@ -0,0 +1,30 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(new)]
+struct Lorem {
+    ipsum: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(new, pattern = "owned")]
+struct Dolor<T> {
+    sit: T,
+}
+
+#[test]
+fn new() {
+    let x = LoremBuilder::new().ipsum(42).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: 42 });
+}
+
+#[test]
+fn new_generic() {
+    let x = DolorBuilder::new().sit("amet").build().unwrap();
+
+    assert_eq!(x, Dolor { sit: "amet" });
+}
@@ -0,0 +1,31 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(non_exhaustive, derive(Debug, PartialEq))]
+pub struct Lorem {
+    ipsum: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(non_exhaustive, vis = "pub(crate)", pattern = "owned")]
+pub struct Dolor {
+    sit: String,
+}
+
+#[test]
+fn derives() {
+    let builder = LoremBuilder::default().ipsum(42).clone();
+
+    assert_eq!(builder, LoremBuilder::default().ipsum(42).clone());
+    assert_eq!(builder.build().unwrap(), Lorem { ipsum: 42 });
+}
+
+#[test]
+fn visibility() {
+    let x = DolorBuilder::default().sit("amet".to_string()).build().unwrap();
+
+    assert_eq!(x, Dolor { sit: "amet".to_string() });
+}
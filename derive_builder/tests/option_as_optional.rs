@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(option_as_optional)]
+struct Lorem {
+    ipsum: u32,
+    dolor: Option<String>,
+    #[builder(default = "Some(42)")]
+    sit: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(option_as_optional, build_fn(collect_errors))]
+struct Amet {
+    consectetur: Option<u32>,
+    adipiscing: u32,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Elit {
+    sed: Option<u32>,
+}
+
+#[test]
+fn unset_option_is_none() {
+    let x = LoremBuilder::default().ipsum(1).build().unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: 1,
+        dolor: None,
+        sit: Some(42),
+    });
+}
+
+#[test]
+fn set_option() {
+    let x = LoremBuilder::default()
+        .ipsum(1)
+        .dolor(Some("dolor".to_string()))
+        .sit(None)
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: 1,
+        dolor: Some("dolor".to_string()),
+        sit: None,
+    });
+}
+
+#[test]
+fn other_fields_required() {
+    assert_eq!(&LoremBuilder::default().build().unwrap_err(), "`ipsum` must be initialized");
+    assert_eq!(AmetBuilder::default().build().unwrap_err(),
+               vec!["`adipiscing` must be initialized".to_string()]);
+}
+
+#[test]
+fn disabled_by_default() {
+    assert_eq!(&ElitBuilder::default().build().unwrap_err(), "`sed` must be initialized");
+}
@@ -0,0 +1,58 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Default, Clone)]
+struct PartialLorem {
+    ipsum: Option<String>,
+    dolor: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(partial = "PartialLorem", derive(Debug))]
+struct Lorem {
+    ipsum: String,
+    #[builder(default = "42")]
+    dolor: u32,
+    #[builder(setter(skip))]
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Default, Clone)]
+struct PartialAmet<T> {
+    value: Option<T>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(partial = "PartialAmet<T>", pattern = "owned")]
+struct Amet<T> {
+    value: T,
+}
+
+#[test]
+fn from_partial() {
+    let partial = PartialLorem { ipsum: Some("ipsum".to_string()), dolor: None };
+    let x = LoremBuilder::from(partial).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: "ipsum".to_string(), dolor: 42, sit: false });
+}
+
+#[test]
+fn round_trip() {
+    let partial = PartialLorem { ipsum: None, dolor: Some(1) };
+    let mut builder = LoremBuilder::from(partial.clone());
+    assert_eq!(PartialLorem::from(builder.clone()), partial);
+
+    builder.ipsum("consectetur".to_string());
+    assert_eq!(PartialLorem::from(builder),
+               PartialLorem { ipsum: Some("consectetur".to_string()), dolor: Some(1) });
+}
+
+#[test]
+fn generic() {
+    let builder: AmetBuilder<u8> = PartialAmet { value: Some(7) }.into();
+    let partial: PartialAmet<u8> = builder.into();
+
+    assert_eq!(partial, PartialAmet { value: Some(7) });
+}
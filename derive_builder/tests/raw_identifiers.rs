@@ -0,0 +1,49 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(field_take)]
+struct Lorem {
+    r#type: u32,
+    #[builder(setter(into))]
+    r#async: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(setter(prefix = "with"))]
+struct Ipsum {
+    r#type: u32,
+    #[builder(setter(name = "dolor"))]
+    r#match: bool,
+}
+
+#[test]
+fn raw_setters() {
+    let x = LoremBuilder::default().r#type(42).r#async("sit").build().unwrap();
+
+    assert_eq!(x, Lorem { r#type: 42, r#async: "sit".to_string() });
+}
+
+#[test]
+fn raw_setters_prefix() {
+    let x = IpsumBuilder::default().with_type(42).dolor(true).build().unwrap();
+
+    assert_eq!(x, Ipsum { r#type: 42, r#match: true });
+}
+
+#[test]
+fn raw_take() {
+    let mut builder = LoremBuilder::default();
+    builder.r#type(42);
+
+    assert_eq!(builder.take_type(), Some(42));
+}
+
+#[test]
+fn raw_error_message() {
+    let err = LoremBuilder::default().r#type(42).build().unwrap_err();
+
+    assert_eq!(&err, "`async` must be initialized");
+}
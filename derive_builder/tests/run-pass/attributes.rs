@@ -8,6 +8,9 @@ extern crate derive_builder;
 struct Lorem {
     /// This is a doc comment for a field
     field_with_doc_comment: String,
+    /// This is a doc comment for a field, but not for its setter
+    #[builder(setter(doc = "This is a doc comment for a setter"))]
+    field_with_setter_doc: String,
     #[allow(missing_docs)]
     undocumented: String,
     #[allow(non_snake_case)]
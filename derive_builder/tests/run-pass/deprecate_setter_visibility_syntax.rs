@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate derive_builder;
+
+// this is meant to generate a deprecation warning! :-)
+#[allow(dead_code)]
+#[derive(Builder)]
+//~^ WARN  use of deprecated item: warning: deprecated syntax `#[builder(private)]`, please use `#[builder(setter(private))]` instead on field `ipsum`.
+//~| NOTE in this expansion of #[derive(Builder)]
+//~| NOTE #[warn(deprecated)] on by default
+//~| NOTE in this expansion of #[derive(Builder)]
+#[builder(field(private))]
+struct Lorem {
+    #[builder(private)]
+    ipsum: String,
+}
+
+fn main() {}
@@ -0,0 +1,79 @@
+#![allow(deprecated)]
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    #[builder(setter(name = "ipsum", alias = "dolor"))]
+    foo: u32,
+    #[builder(setter(into, alias = "old_sit", alias = "older_sit"))]
+    sit: String,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Dolor {
+    #[builder(setter(strip_option, alias("sit", "s", deprecated = false)))]
+    sit_amet: Option<u32>,
+    #[builder(setter(into, alias("old_consectetur", "older_consectetur")))]
+    consectetur: String,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned")]
+struct Amet {
+    #[builder(setter(alias = "old_foo"))]
+    foo: u32,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Consectetur {
+    #[deprecated(note = "use `adipiscing` instead")]
+    #[builder(setter(alias = "old_elit"))]
+    elit: u32,
+    adipiscing: u32,
+}
+
+#[test]
+fn primary() {
+    let x = LoremBuilder::default().ipsum(1).sit("sit").build().unwrap();
+
+    assert_eq!(x, Lorem { foo: 1, sit: "sit".to_string() });
+}
+
+#[test]
+fn alias() {
+    let x = LoremBuilder::default().dolor(2).old_sit("old").build().unwrap();
+
+    assert_eq!(x, Lorem { foo: 2, sit: "old".to_string() });
+
+    let x = LoremBuilder::default().dolor(3).older_sit("older").build().unwrap();
+
+    assert_eq!(x, Lorem { foo: 3, sit: "older".to_string() });
+}
+
+#[test]
+fn alias_owned() {
+    let x = AmetBuilder::default().old_foo(4).build().unwrap();
+
+    assert_eq!(x, Amet { foo: 4 });
+}
+
+#[test]
+fn alias_list() {
+    let x = DolorBuilder::default().sit(1).old_consectetur("old").build().unwrap();
+
+    assert_eq!(x, Dolor { sit_amet: Some(1), consectetur: "old".to_string() });
+
+    let x = DolorBuilder::default().s(2).older_consectetur("older").build().unwrap();
+
+    assert_eq!(x, Dolor { sit_amet: Some(2), consectetur: "older".to_string() });
+}
+
+#[test]
+fn deprecated_field() {
+    let x = ConsecteturBuilder::default().old_elit(5).adipiscing(6).build().unwrap();
+
+    assert_eq!(x, Consectetur { elit: 5, adipiscing: 6 });
+}
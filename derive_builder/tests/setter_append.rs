@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    #[builder(setter(append))]
+    ipsum: Vec<u32>,
+    #[builder(setter(append, into_iter))]
+    dolor: Vec<String>,
+    #[builder(setter(append))]
+    sit: BTreeMap<String, u32>,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned")]
+struct Amet {
+    #[builder(setter(append))]
+    consectetur: Vec<char>,
+}
+
+#[test]
+fn accumulates() {
+    let mut base = BTreeMap::new();
+    base.insert("a".to_string(), 1);
+    base.insert("b".to_string(), 2);
+    let mut overrides = BTreeMap::new();
+    overrides.insert("b".to_string(), 3);
+
+    let x = LoremBuilder::default()
+        .ipsum(vec![1, 2])
+        .ipsum(vec![3])
+        .dolor(vec!["dolor"])
+        .dolor(Some("sit"))
+        .sit(base)
+        .sit(overrides)
+        .build()
+        .unwrap();
+
+    let mut sit = BTreeMap::new();
+    sit.insert("a".to_string(), 1);
+    sit.insert("b".to_string(), 3);
+    assert_eq!(x, Lorem {
+        ipsum: vec![1, 2, 3],
+        dolor: vec!["dolor".to_string(), "sit".to_string()],
+        sit: sit,
+    });
+}
+
+#[test]
+fn defaults_to_empty() {
+    let x = LoremBuilder::default().build().unwrap();
+
+    assert_eq!(x, Lorem::default());
+}
+
+#[test]
+fn owned() {
+    let x = AmetBuilder::default()
+        .consectetur(vec!['a'])
+        .consectetur(vec!['b', 'c'])
+        .build()
+        .unwrap();
+
+    assert_eq!(x.consectetur, vec!['a', 'b', 'c']);
+}
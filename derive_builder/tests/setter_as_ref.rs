@@ -0,0 +1,49 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    #[builder(setter(as_ref))]
+    ipsum: String,
+    #[builder(setter(as_ref), default)]
+    dolor: PathBuf,
+    #[builder(setter(as_ref), default)]
+    sit: OsString,
+}
+
+#[test]
+fn borrowed_values() {
+    let ipsum = String::from("ipsum");
+    let x = LoremBuilder::default()
+        .ipsum(&ipsum)
+        .dolor(Path::new("/dolor"))
+        .sit(OsStr::new("sit"))
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: ipsum,
+        dolor: PathBuf::from("/dolor"),
+        sit: OsString::from("sit"),
+    });
+}
+
+#[test]
+fn owned_values() {
+    let x = LoremBuilder::default()
+        .ipsum(Box::<str>::from("ipsum"))
+        .dolor("dolor")
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: "ipsum".to_string(),
+        dolor: PathBuf::from("dolor"),
+        sit: OsString::new(),
+    });
+}
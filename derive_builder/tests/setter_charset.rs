@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    #[builder(setter(ascii))]
+    ipsum: String,
+    #[builder(setter(charset = "alphanumeric"), default = "String::from(\"dolor sit\")")]
+    dolor: String,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(setter(ascii), build_fn(collect_errors))]
+struct Sit<'a> {
+    amet: &'a str,
+    consectetur: String,
+}
+
+#[test]
+fn valid() {
+    let x = LoremBuilder::default()
+        .ipsum("ipsum!".to_string())
+        .dolor("Dolor42".to_string())
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Lorem { ipsum: "ipsum!".to_string(), dolor: "Dolor42".to_string() });
+}
+
+#[test]
+fn default_not_checked() {
+    let x = LoremBuilder::default().ipsum("ipsum".to_string()).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: "ipsum".to_string(), dolor: "dolor sit".to_string() });
+}
+
+#[test]
+fn non_ascii() {
+    let err = LoremBuilder::default().ipsum("ipsüm".to_string()).build().unwrap_err();
+
+    assert_eq!(&err, "`ipsum` must only contain ASCII characters");
+}
+
+#[test]
+fn non_alphanumeric() {
+    let err = LoremBuilder::default()
+        .ipsum("ipsum".to_string())
+        .dolor("dolor sit".to_string())
+        .build()
+        .unwrap_err();
+
+    assert_eq!(&err, "`dolor` must only contain alphanumeric ASCII characters");
+}
+
+#[test]
+fn collect_errors() {
+    let err = SitBuilder::default()
+        .amet("ämet")
+        .consectetur("cönsectetur".to_string())
+        .build()
+        .unwrap_err();
+
+    assert_eq!(err, vec!["`amet` must only contain ASCII characters".to_string(),
+                         "`consectetur` must only contain ASCII characters".to_string()]);
+}
@@ -0,0 +1,88 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    #[builder(setter(collection))]
+    ipsum: Vec<String>,
+    #[builder(setter(collection), default)]
+    dolor: HashSet<u64>,
+    #[builder(setter(collection), default)]
+    sit: HashMap<String, u32>,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned")]
+struct Amet {
+    #[builder(setter(collection, each = "item"))]
+    consectetur: Vec<u32>,
+}
+
+#[test]
+fn set_vec() {
+    let x = LoremBuilder::default()
+        .ipsum(vec!["replaced".to_string()])
+        .set_ipsum(vec!["ipsum", "dolor"])
+        .build()
+        .unwrap();
+
+    assert_eq!(x.ipsum, vec!["ipsum".to_string(), "dolor".to_string()]);
+}
+
+#[test]
+fn set_hash_set() {
+    let x = LoremBuilder::default()
+        .set_ipsum(Vec::<String>::new())
+        .set_dolor([1u32, 2, 2].iter().cloned())
+        .build()
+        .unwrap();
+
+    assert_eq!(x.dolor, [1, 2].iter().cloned().collect());
+}
+
+#[test]
+fn set_hash_map() {
+    let x = LoremBuilder::default()
+        .set_ipsum(Vec::<String>::new())
+        .set_sit(vec![("amet".to_string(), 42)])
+        .build()
+        .unwrap();
+
+    let mut sit = HashMap::new();
+    sit.insert("amet".to_string(), 42);
+    assert_eq!(x.sit, sit);
+}
+
+#[test]
+fn clear() {
+    let mut builder = LoremBuilder::default();
+    builder.set_ipsum(vec!["ipsum"]).set_dolor(vec![1u64]).set_sit(vec![("a".to_string(), 1)]);
+    builder.clear_ipsum().clear_dolor().clear_sit();
+
+    // cleared collections are still set, just empty
+    assert_eq!(builder.build().unwrap(), Lorem::default());
+}
+
+#[test]
+fn clear_unset() {
+    let mut builder = LoremBuilder::default();
+    builder.clear_ipsum();
+
+    assert!(builder.build().is_err());
+}
+
+#[test]
+fn owned() {
+    let x = AmetBuilder::default()
+        .set_consectetur(vec![1u32, 2])
+        .clear_consectetur()
+        .item(3)
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Amet { consectetur: vec![3] });
+}
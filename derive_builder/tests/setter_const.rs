@@ -0,0 +1,87 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned", setter(const_fn))]
+struct Lorem {
+    ipsum: u32,
+    #[builder(setter(prefix = "with"))]
+    dolor: bool,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned")]
+struct Sit {
+    #[builder(setter(const_fn))]
+    amet: u32,
+    #[builder(setter(into))]
+    consectetur: String,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned", const_setters)]
+struct Adipiscing {
+    elit: u16,
+    #[builder(setter(strip_option))]
+    sed: Option<char>,
+    do_: (&'static str, [i8; 2]),
+    // not eligible, so these setters are silently left non-const
+    #[builder(setter(into))]
+    eiusmod: u64,
+    tempor: String,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned", const_setters)]
+struct Consectetur {
+    // emits a deprecation note, so this setter is silently left non-const
+    #[builder(public)]
+    adipiscing: u32,
+}
+
+const LOREM: LoremBuilder = LoremBuilder {
+    ipsum: None,
+    dolor: None,
+}.ipsum(42).with_dolor(true);
+
+const ADIPISCING: AdipiscingBuilder = AdipiscingBuilder {
+    elit: None,
+    sed: None,
+    do_: None,
+    eiusmod: None,
+    tempor: None,
+}.elit(1).sed('a').do_(("b", [2, 3]));
+
+#[test]
+fn const_setters() {
+    let x = ADIPISCING.eiusmod(4u8).tempor("tempor".to_string()).build().unwrap();
+
+    assert_eq!(x, Adipiscing {
+        elit: 1,
+        sed: Some('a'),
+        do_: ("b", [2, 3]),
+        eiusmod: 4,
+        tempor: "tempor".to_string(),
+    });
+}
+
+#[test]
+fn const_context() {
+    assert_eq!(LOREM.build().unwrap(), Lorem { ipsum: 42, dolor: true });
+}
+
+#[test]
+fn deprecated_option() {
+    let x = ConsecteturBuilder::default().adipiscing(1).build().unwrap();
+
+    assert_eq!(x, Consectetur { adipiscing: 1 });
+}
+
+#[test]
+fn runtime() {
+    let x = SitBuilder::default().amet(1).consectetur("consectetur").build().unwrap();
+
+    assert_eq!(x, Sit { amet: 1, consectetur: "consectetur".to_string() });
+}
@@ -0,0 +1,211 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    #[builder(setter(each(name = "arg", into)))]
+    args: Vec<String>,
+    #[builder(setter(each = "tag"))]
+    tags: HashSet<u32>,
+    #[builder(setter(each = "env"))]
+    envs: HashMap<String, String>,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned")]
+struct Ipsum {
+    #[builder(setter(each = "dolor"))]
+    dolores: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Sit {
+    #[builder(setter(each = "line"))]
+    text: String,
+    #[builder(setter(each(name = "word", into)))]
+    words: String,
+}
+
+type Words = Vec<String>;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Amet {
+    #[builder(setter(each(name = "word", ty = "String", into)))]
+    words: Words,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Adipiscing {
+    #[builder(setter(each(name = "entry", into)))]
+    hash: HashMap<String, u32>,
+    #[builder(setter(each(name = "sorted_entry", into)))]
+    sorted: BTreeMap<String, u32>,
+    #[builder(setter(each(name = "pair", ty = "(String, u32)")))]
+    pairs: Vec<(String, u32)>,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Consectetur {
+    #[builder(setter(strip_option, each = "arg"))]
+    args: Option<Vec<u32>>,
+    #[builder(setter(into, strip_option, each(name = "tag", into)))]
+    tags: Option<Vec<String>>,
+    #[builder(setter(strip_option, each = "line"))]
+    text: Option<String>,
+    #[builder(setter(strip_option(depth = 2), each(name = "env", into)))]
+    envs: Option<Option<Vec<String>>>,
+}
+
+#[test]
+fn each() {
+    let x = LoremBuilder::default()
+        .arg("sit")
+        .arg(String::from("amet"))
+        .tag(1)
+        .tag(1)
+        .env("consectetur".to_string(), "adipiscing".to_string())
+        .build()
+        .unwrap();
+
+    let mut envs = HashMap::new();
+    envs.insert("consectetur".to_string(), "adipiscing".to_string());
+
+    assert_eq!(x, Lorem {
+        args: vec!["sit".to_string(), "amet".to_string()],
+        tags: vec![1].into_iter().collect(),
+        envs: envs,
+    });
+}
+
+#[test]
+fn each_extends_whole_value() {
+    let x = LoremBuilder::default()
+        .args(vec!["sit".to_string()])
+        .arg("amet")
+        .tags(HashSet::new())
+        .envs(HashMap::new())
+        .build()
+        .unwrap();
+
+    assert_eq!(x.args, vec!["sit".to_string(), "amet".to_string()]);
+}
+
+#[test]
+fn each_owned() {
+    let x = IpsumBuilder::default().dolor(1).dolor(2).build().unwrap();
+
+    assert_eq!(x, Ipsum { dolores: vec![1, 2] });
+}
+
+#[test]
+fn each_string() {
+    let x = SitBuilder::default()
+        .line("amet,")
+        .line(" consectetur")
+        .word(String::from("adipiscing"))
+        .word("elit")
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Sit {
+        text: "amet, consectetur".to_string(),
+        words: "adipiscingelit".to_string(),
+    });
+}
+
+#[test]
+fn each_type_alias() {
+    let x = AmetBuilder::default().word("consectetur").word("adipiscing").build().unwrap();
+
+    assert_eq!(x.words, vec!["consectetur".to_string(), "adipiscing".to_string()]);
+}
+
+#[test]
+fn each_only() {
+    let x = LoremBuilder::default()
+        .arg("sit")
+        .tag(1)
+        .env("amet".to_string(), "1".to_string())
+        .build()
+        .unwrap();
+
+    assert_eq!(x.args, vec!["sit".to_string()]);
+    assert_eq!(x.tags, vec![1].into_iter().collect());
+    assert_eq!(x.envs.len(), 1);
+}
+
+#[test]
+fn each_defaults_to_empty() {
+    let x = LoremBuilder::default().build().unwrap();
+
+    assert_eq!(x, Lorem::default());
+}
+
+#[test]
+fn each_strip_option() {
+    let x = ConsecteturBuilder::default()
+        .arg(1)
+        .arg(2)
+        .tag("adipiscing")
+        .tag(String::from("elit"))
+        .line("sed ")
+        .line("do")
+        .env("eiusmod")
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Consectetur {
+        args: Some(vec![1, 2]),
+        tags: Some(vec!["adipiscing".to_string(), "elit".to_string()]),
+        text: Some("sed do".to_string()),
+        envs: Some(Some(vec!["eiusmod".to_string()])),
+    });
+}
+
+#[test]
+fn each_strip_option_extends_whole_value() {
+    let x = ConsecteturBuilder::default()
+        .args(vec![1])
+        .arg(2)
+        .tags(vec!["adipiscing".to_string()])
+        .build()
+        .unwrap();
+
+    assert_eq!(x.args, Some(vec![1, 2]));
+    assert_eq!(x.tags, Some(vec!["adipiscing".to_string()]));
+}
+
+#[test]
+fn each_strip_option_defaults_to_none() {
+    let x = ConsecteturBuilder::default().build().unwrap();
+
+    assert_eq!(x, Consectetur::default());
+}
+
+#[test]
+fn each_key_value() {
+    let x = AdipiscingBuilder::default()
+        .entry("elit", 1u32)
+        .entry(String::from("sed"), 2u32)
+        .entry("elit", 3u32)
+        .sorted_entry("do", 4u32)
+        .pair(("eiusmod".to_string(), 5))
+        .build()
+        .unwrap();
+
+    let mut hash = HashMap::new();
+    hash.insert("elit".to_string(), 3);
+    hash.insert("sed".to_string(), 2);
+    let mut sorted = BTreeMap::new();
+    sorted.insert("do".to_string(), 4);
+
+    assert_eq!(x, Adipiscing {
+        hash: hash,
+        sorted: sorted,
+        pairs: vec![("eiusmod".to_string(), 5)],
+    });
+}
@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(inline)]
+struct Lorem {
+    ipsum: u32,
+    #[builder(setter(into, alias("sit", deprecated = false)))]
+    dolor: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned")]
+struct Amet {
+    #[builder(setter(inline, each = "push_consectetur"))]
+    consectetur: Vec<u32>,
+    adipiscing: bool,
+}
+
+#[test]
+fn struct_level() {
+    let x = LoremBuilder::default().ipsum(1).sit("dolor").build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: 1, dolor: "dolor".to_string() });
+}
+
+#[test]
+fn field_level() {
+    let x = AmetBuilder::default()
+        .push_consectetur(1)
+        .push_consectetur(2)
+        .adipiscing(true)
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Amet { consectetur: vec![1, 2], adipiscing: true });
+}
@@ -15,6 +15,54 @@ struct Ipsum {
     foo: u32,
 }
 
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Dolor {
+    #[builder(setter(into, impl_trait))]
+    foo: String,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(setter(into, impl_trait))]
+struct Sit {
+    foo: u32,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(setter(auto_into))]
+struct Amet {
+    foo: String,
+    bar: ::std::path::PathBuf,
+    baz: u32,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(setter(into(only("String", "PathBuf"))))]
+struct Elit {
+    foo: String,
+    bar: ::std::path::PathBuf,
+    baz: u32,
+    qux: ::std::ffi::OsString,
+    #[builder(setter(into))]
+    quux: u64,
+}
+
+#[derive(Debug, PartialEq, Builder, Clone)]
+struct Consectetur {
+    // `&str` doesn't convert into `Box<Path>` directly, but via `PathBuf`
+    #[builder(setter(into = "::std::path::PathBuf"))]
+    foo: Box<::std::path::Path>,
+    #[builder(setter(into = "String", impl_trait))]
+    bar: ::std::rc::Rc<str>,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned", setter(into, with_plain))]
+struct Adipiscing {
+    foo: u32,
+    #[builder(setter(with_plain, strip_option))]
+    bar: Option<String>,
+}
+
 #[test]
 fn generic_field() {
     let x = LoremBuilder::default().foo("foo").build().unwrap();
@@ -28,3 +76,75 @@ fn generic_struct() {
 
     assert_eq!(x, Ipsum { foo: 42u32 });
 }
+
+#[test]
+fn impl_trait_field() {
+    let x = DolorBuilder::default().foo("foo").build().unwrap();
+
+    assert_eq!(x, Dolor { foo: "foo".to_string() });
+}
+
+#[test]
+fn impl_trait_struct() {
+    let x = SitBuilder::default().foo(42u8).build().unwrap();
+
+    assert_eq!(x, Sit { foo: 42u32 });
+}
+
+#[test]
+fn auto_into_struct() {
+    // `baz` takes a plain `u32`, so the integer literal is inferred without a suffix.
+    let x = AmetBuilder::default().foo("foo").bar("/bar").baz(42).build().unwrap();
+
+    assert_eq!(x, Amet {
+        foo: "foo".to_string(),
+        bar: "/bar".into(),
+        baz: 42,
+    });
+}
+
+#[test]
+fn into_only_listed_types() {
+    // `baz` and `qux` are not listed, so they take plain values.
+    let x = ElitBuilder::default()
+        .foo("foo")
+        .bar("/bar")
+        .baz(42)
+        .qux(::std::ffi::OsString::from("qux"))
+        .quux(7u8)
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Elit {
+        foo: "foo".to_string(),
+        bar: "/bar".into(),
+        baz: 42,
+        qux: "qux".into(),
+        quux: 7,
+    });
+}
+
+#[test]
+fn explicit_into_type() {
+    let x = ConsecteturBuilder::default().foo("foo").bar('b').build().unwrap();
+
+    assert_eq!(x, Consectetur {
+        foo: ::std::path::PathBuf::from("foo").into_boxed_path(),
+        bar: "b".into(),
+    });
+}
+
+#[test]
+fn with_plain() {
+    // the plain setter takes a `u32`, so the integer literal is inferred without a suffix.
+    let x = AdipiscingBuilder::default().foo(42).bar("bar".to_string()).build().unwrap();
+
+    assert_eq!(x, Adipiscing { foo: 42, bar: Some("bar".to_string()) });
+}
+
+#[test]
+fn with_plain_into() {
+    let x = AdipiscingBuilder::default().foo_into(42u8).bar_into("bar").build().unwrap();
+
+    assert_eq!(x, Adipiscing { foo: 42, bar: Some("bar".to_string()) });
+}
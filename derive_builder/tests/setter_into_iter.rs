@@ -0,0 +1,45 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+use std::collections::{BTreeMap, HashSet};
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    #[builder(setter(into_iter))]
+    ipsum: Vec<String>,
+    #[builder(setter(into_iter), default)]
+    dolor: HashSet<u64>,
+    #[builder(setter(into_iter), default)]
+    sit: BTreeMap<String, u32>,
+}
+
+#[test]
+fn from_iterators() {
+    let x = LoremBuilder::default()
+        .ipsum(vec!["ipsum", "dolor"])
+        .dolor([1u32, 2, 2].iter().cloned())
+        .sit(Some(("amet".to_string(), 42)))
+        .build()
+        .unwrap();
+
+    let mut sit = BTreeMap::new();
+    sit.insert("amet".to_string(), 42);
+    assert_eq!(x, Lorem {
+        ipsum: vec!["ipsum".to_string(), "dolor".to_string()],
+        dolor: [1, 2].iter().cloned().collect(),
+        sit: sit,
+    });
+}
+
+#[test]
+fn replaces_collection() {
+    let x = LoremBuilder::default()
+        .ipsum(vec!["ipsum"])
+        .ipsum(Vec::<String>::new())
+        .build()
+        .unwrap();
+
+    assert!(x.ipsum.is_empty());
+}
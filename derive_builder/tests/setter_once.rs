@@ -0,0 +1,58 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    #[builder(setter(once))]
+    ipsum: u32,
+    dolor: u32,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned", setter(once = "error"), derive(Debug))]
+struct Sit {
+    amet: u32,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(try_setter, setter(once))]
+struct Consectetur {
+    adipiscing: u8,
+}
+
+#[test]
+fn once() {
+    let x = LoremBuilder::default().ipsum(1).dolor(2).dolor(3).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: 1, dolor: 3 });
+}
+
+#[test]
+#[should_panic(expected = "`ipsum` must not be set more than once")]
+fn once_panic() {
+    LoremBuilder::default().ipsum(1).ipsum(2);
+}
+
+#[test]
+fn once_error() {
+    let x = SitBuilder::default().amet(1).unwrap().build().unwrap();
+    assert_eq!(x, Sit { amet: 1 });
+
+    let err = SitBuilder::default().amet(1).unwrap().amet(2).unwrap_err();
+    assert_eq!(&err, "`amet` must not be set more than once");
+}
+
+#[test]
+fn once_try_setter() {
+    let x = ConsecteturBuilder::default().try_adipiscing(1u32).unwrap().build().unwrap();
+
+    assert_eq!(x, Consectetur { adipiscing: 1 });
+}
+
+#[test]
+#[should_panic(expected = "`adipiscing` must not be set more than once")]
+fn once_try_setter_panic() {
+    let _ = ConsecteturBuilder::default().adipiscing(1).try_adipiscing(2u32);
+}
@@ -32,6 +32,17 @@ struct Sit {
     default: u32,
 }
 
+/// Not `Clone`, so `Amet` can only be built by moving its fields.
+#[derive(Debug, PartialEq, Default)]
+struct Consectetur(u32);
+
+#[derive(Debug, PartialEq, Default, Builder)]
+#[builder(pattern="move")]
+struct Amet {
+    consectetur: Consectetur,
+    adipiscing: u32,
+}
+
 type ImmutableSetter<T, U> = fn(&T, U) -> T;
 type OwnedSetter<T, U> = fn(T, U) -> T;
 type MutableSetter<T, U> = fn(&mut T, U) -> &mut T;
@@ -105,3 +116,19 @@ fn owned_override() {
     let new = owned_setter(old, 42);
     assert_eq!(new.owned_override, Some(42));
 }
+
+#[test]
+fn move_alias() {
+    // the setter must have the correct signature
+    let move_setter: OwnedSetter<AmetBuilder, u32> = AmetBuilder::adipiscing;
+
+    let x = move_setter(AmetBuilder::default(), 42)
+        .consectetur(Consectetur(1))
+        .adipiscing(43)
+        .build()
+        .unwrap();
+    assert_eq!(x, Amet {
+        consectetur: Consectetur(1),
+        adipiscing: 43,
+    });
+}
@@ -11,6 +11,19 @@ struct Lorem {
     pub dolor: &'static str,
 }
 
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(setter(prefix="with"))]
+struct Ipsum {
+    foo: u32,
+    bar: u32,
+    #[builder(setter(name="with_qux"))]
+    baz: u32,
+    #[builder(setter(prefix="set", name="quux"))]
+    corge: u32,
+    #[builder(setter(prefix=""))]
+    grault: u32,
+}
+
 #[test]
 fn prefixed_setters() {
     let x = LoremBuilder::default()
@@ -25,3 +38,24 @@ fn prefixed_setters() {
                    dolor: "dolor",
                });
 }
+
+#[test]
+fn explicit_names_are_not_prefixed() {
+    let x = IpsumBuilder::default()
+        .with_foo(1)
+        .with_bar(2)
+        .with_qux(3)
+        .quux(4)
+        .grault(5)
+        .build()
+        .unwrap();
+
+    assert_eq!(x,
+               Ipsum {
+                   foo: 1,
+                   bar: 2,
+                   baz: 3,
+                   corge: 4,
+                   grault: 5,
+               });
+}
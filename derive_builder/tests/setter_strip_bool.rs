@@ -0,0 +1,36 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    #[builder(setter(strip_bool))]
+    ipsum: bool,
+    #[builder(setter(strip_bool, name = "enable_dolor"))]
+    dolor: bool,
+    #[builder(setter(strip_bool), default = "true")]
+    sit: bool,
+}
+
+#[test]
+fn defaults_to_false() {
+    let x = LoremBuilder::default().build().unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: false,
+        dolor: false,
+        sit: true,
+    });
+}
+
+#[test]
+fn flags() {
+    let x = LoremBuilder::default().ipsum().enable_dolor().build().unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: true,
+        dolor: true,
+        sit: true,
+    });
+}
@@ -0,0 +1,97 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Lorem {
+    #[builder(setter(strip_option, into))]
+    ipsum: Option<u32>,
+    #[builder(setter(strip_option(depth = 1)))]
+    dolor: Option<Option<String>>,
+    #[builder(setter(strip_option(depth = 2)))]
+    sit: Option<Option<String>>,
+    #[builder(default)]
+    amet: Option<u32>,
+}
+
+type MaybeName = Option<String>;
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+struct Ipsum {
+    #[builder(setter(strip_option(ty = "String"), into))]
+    name: MaybeName,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(pattern = "owned")]
+struct Dolor {
+    #[builder(setter(option))]
+    sit: Option<u32>,
+    #[builder(setter(option, strip_option(depth = 2)))]
+    amet: Option<Option<u32>>,
+}
+
+#[test]
+fn strip_option() {
+    let x = LoremBuilder::default()
+        .ipsum(1u8)
+        .dolor(None)
+        .sit("sit".to_string())
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: Some(1),
+        dolor: Some(None),
+        sit: Some(Some("sit".to_string())),
+        amet: None,
+    });
+}
+
+#[test]
+fn depth_1() {
+    let x = LoremBuilder::default()
+        .ipsum(1u8)
+        .dolor(Some("dolor".to_string()))
+        .sit("sit".to_string())
+        .amet(Some(2))
+        .build()
+        .unwrap();
+
+    assert_eq!(x.dolor, Some(Some("dolor".to_string())));
+    assert_eq!(x.amet, Some(2));
+}
+
+#[test]
+fn type_alias() {
+    let x = IpsumBuilder::default().name("dolor").build().unwrap();
+
+    assert_eq!(x.name, Some("dolor".to_string()));
+}
+
+#[test]
+fn option_setter() {
+    let x = DolorBuilder::default().sit(1).maybe_amet(Some(2)).build().unwrap();
+
+    assert_eq!(x, Dolor {
+        sit: Some(1),
+        amet: Some(Some(2)),
+    });
+}
+
+#[test]
+fn option_setter_clears() {
+    let x = DolorBuilder::default()
+        .sit(1)
+        .maybe_sit(None)
+        .amet(2)
+        .maybe_amet(None)
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Dolor {
+        sit: None,
+        amet: Some(None),
+    });
+}
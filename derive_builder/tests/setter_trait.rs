@@ -0,0 +1,40 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+/// Setters shared by `LoremBuilder` and `DolorBuilder`.
+pub trait NameSetters {
+    fn name<VALUE: Into<String>>(&mut self, value: VALUE) -> &mut Self;
+    fn age(&mut self, value: u32) -> &mut Self;
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(impl_trait = "NameSetters", setter(auto_into))]
+struct Lorem {
+    name: String,
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Default, Builder, Clone)]
+#[builder(impl_trait = "NameSetters")]
+struct Dolor {
+    #[builder(setter(into))]
+    name: String,
+    age: u32,
+}
+
+fn fill<T: NameSetters>(builder: &mut T) {
+    builder.name("sit").age(42);
+}
+
+#[test]
+fn setters_via_trait() {
+    let mut lorem = LoremBuilder::default();
+    let mut dolor = DolorBuilder::default();
+    fill(&mut lorem);
+    fill(&mut dolor);
+
+    assert_eq!(lorem.build().unwrap(), Lorem { name: "sit".to_string(), age: 42 });
+    assert_eq!(dolor.build().unwrap(), Dolor { name: "sit".to_string(), age: 42 });
+}
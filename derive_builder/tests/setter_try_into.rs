@@ -0,0 +1,87 @@
+#![cfg(feature = "nightlytests")]
+#![feature(try_from)]
+
+#[macro_use]
+extern crate derive_builder;
+
+use std::num::TryFromIntError;
+use std::string::ToString;
+
+#[derive(Debug, PartialEq)]
+pub struct ConversionError(String);
+
+impl From<TryFromIntError> for ConversionError {
+    fn from(e: TryFromIntError) -> Self {
+        ConversionError(e.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(derive(Debug))]
+struct Lorem {
+    #[builder(setter(try_into = "ConversionError"))]
+    ipsum: u8,
+    dolor: String,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", setter(try_into = "ConversionError"), derive(Debug))]
+struct Ipsum {
+    dolor: u8,
+    #[builder(setter(strip_option))]
+    sit: Option<u16>,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(derive(Debug))]
+struct Sit {
+    #[builder(setter(try_into))]
+    amet: u8,
+}
+
+#[test]
+fn mutable() {
+    let mut builder = LoremBuilder::default();
+    let x: Result<&mut LoremBuilder, ConversionError> = builder.ipsum(42u64);
+    x.unwrap().dolor("sit".to_string());
+
+    assert_eq!(builder.build().unwrap(), Lorem { ipsum: 42, dolor: "sit".to_string() });
+}
+
+#[test]
+fn mutable_error() {
+    let mut builder = LoremBuilder::default();
+    let err = builder.ipsum(256u64).unwrap_err();
+
+    assert_eq!(err, ConversionError("out of range integral type conversion attempted".into()));
+    assert_eq!(builder.dolor("sit".to_string()).build().unwrap_err(),
+               "`ipsum` must be initialized");
+}
+
+#[test]
+fn owned() {
+    let x = IpsumBuilder::default()
+        .dolor(1u32).unwrap()
+        .sit(2u32).unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Ipsum { dolor: 1, sit: Some(2) });
+}
+
+#[test]
+fn owned_error() {
+    let err = IpsumBuilder::default().sit(-1i32).unwrap_err();
+
+    assert_eq!(err, ConversionError("out of range integral type conversion attempted".into()));
+}
+
+
+#[test]
+fn inferred_error() {
+    let mut builder = SitBuilder::default();
+    let err: TryFromIntError = builder.amet(256u64).unwrap_err();
+
+    assert_eq!(err.to_string(), "out of range integral type conversion attempted");
+    assert_eq!(builder.amet(1u64).unwrap().build().unwrap(), Sit { amet: 1 });
+}
@@ -78,3 +78,33 @@ fn public_setters_foreign_module() {
 //
 //     assert_eq!(x, foo::Lorem { ipsum: "Hello world!".into() });
 // }
+
+pub mod restricted {
+    pub mod inner {
+        #[derive(Debug, PartialEq, Default, Builder, Clone)]
+        #[builder(vis = "pub(crate)", setter(vis = "pub(super)"), field(vis = "pub(crate)"))]
+        pub struct Dolor {
+            pub sit: u32,
+            #[builder(setter(vis = "pub(crate)"))]
+            pub amet: u32,
+        }
+    }
+
+    #[test]
+    fn restricted_visibility() {
+        let mut builder = inner::DolorBuilder::default();
+        builder.sit(1).amet(2);
+        assert_eq!(builder.sit, Some(1));
+
+        assert_eq!(builder.build().unwrap(), inner::Dolor { sit: 1, amet: 2 });
+    }
+}
+
+#[test]
+fn restricted_visibility_crate() {
+    let mut builder = restricted::inner::DolorBuilder::default();
+    builder.sit = Some(1);
+    builder.amet(2);
+
+    assert_eq!(builder.build().unwrap(), restricted::inner::Dolor { sit: 1, amet: 2 });
+}
@@ -21,6 +21,8 @@ struct SetterOptOut {
     setter_skipped_by_shorthand_opt_out: u32,
     #[builder(setter(skip), default = "4")]
     setter_skipped_with_explicit_default: u32,
+    #[builder(setter(skip = "5"))]
+    setter_skipped_with_skip_expression: u32,
 }
 
 #[derive(Debug, PartialEq, Default, Builder, Clone)]
@@ -43,16 +45,35 @@ struct SetterOptInStructDefault {
     setter_skipped_with_type_default: u32,
 }
 
+#[cfg(feature = "struct_default")]
+#[derive(Debug, PartialEq, Builder, Clone)]
+#[builder(default = "new_setter_opt_in_struct_default_expression()")]
+struct SetterOptInStructDefaultExpression {
+    #[builder(setter(skip))]
+    setter_skipped_with_struct_default: NotDefaultable,
+    setter_present_with_struct_default: u32,
+}
+
 #[derive(Debug, PartialEq, Builder, Clone)]
 #[builder(setter(into))]
 struct SetterOptInFieldDefault {
     #[builder(setter(skip), default = "new_notdefaultable()")]
     setter_skipped_with_field_default: NotDefaultable,
 
+    #[builder(setter(skip = "new_notdefaultable()"))]
+    setter_skipped_with_skip_expression: NotDefaultable,
+
     #[builder(default)]
     setter_present_by_default: u32,
 }
 
+#[derive(Debug, PartialEq, Builder, Clone)]
+struct SetterSkippedPrivate {
+    setter_present: u32,
+    #[builder(setter(skip(private)))]
+    setter_skipped_but_crate_private: u32,
+}
+
 // compile test
 #[allow(dead_code)]
 impl SetterOptOut {
@@ -79,6 +100,14 @@ impl Default for SetterOptInStructDefault {
     }
 }
 
+#[cfg(feature = "struct_default")]
+fn new_setter_opt_in_struct_default_expression() -> SetterOptInStructDefaultExpression {
+    SetterOptInStructDefaultExpression {
+        setter_skipped_with_struct_default: new_notdefaultable(),
+        setter_present_with_struct_default: 42,
+    }
+}
+
 #[test]
 fn setter_opt_out() {
     let x: SetterOptOut = SetterOptOutBuilder::default()
@@ -92,6 +121,7 @@ fn setter_opt_out() {
                    setter_skipped_by_explicit_opt_out: 0,
                    setter_skipped_by_shorthand_opt_out: 0,
                    setter_skipped_with_explicit_default: 4,
+                   setter_skipped_with_skip_expression: 5,
                });
 }
 
@@ -122,6 +152,20 @@ fn setter_skipped_with_struct_default() {
     assert_eq!(x, SetterOptInStructDefault::default());
 }
 
+#[test]
+#[cfg(feature = "struct_default")]
+fn setter_skipped_with_struct_default_expression() {
+    let x = SetterOptInStructDefaultExpressionBuilder::default()
+        .setter_present_with_struct_default(7u32)
+        .build()
+        .unwrap();
+    assert_eq!(x,
+               SetterOptInStructDefaultExpression {
+                   setter_skipped_with_struct_default: new_notdefaultable(),
+                   setter_present_with_struct_default: 7,
+               });
+}
+
 #[test]
 fn setter_skipped_with_field_default() {
     let x = SetterOptInFieldDefaultBuilder::default()
@@ -130,6 +174,30 @@ fn setter_skipped_with_field_default() {
     assert_eq!(x,
                SetterOptInFieldDefault {
                    setter_skipped_with_field_default: new_notdefaultable(),
+                   setter_skipped_with_skip_expression: new_notdefaultable(),
                    setter_present_by_default: Default::default(),
                });
 }
+
+#[test]
+fn setter_skipped_private() {
+    let x = SetterSkippedPrivateBuilder::default()
+        .setter_present(1u32)
+        .setter_skipped_but_crate_private(2u32)
+        .build()
+        .unwrap();
+    assert_eq!(x,
+               SetterSkippedPrivate {
+                   setter_present: 1,
+                   setter_skipped_but_crate_private: 2,
+               });
+}
+
+#[test]
+fn setter_skipped_private_defaults() {
+    let x = SetterSkippedPrivateBuilder::default()
+        .setter_present(1u32)
+        .build()
+        .unwrap();
+    assert_eq!(x.setter_skipped_but_crate_private, 0);
+}
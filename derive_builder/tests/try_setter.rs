@@ -40,6 +40,21 @@ struct Ipsum {
     pub source: MyAddr,
 }
 
+#[derive(Debug, PartialEq)]
+pub struct MyError(String);
+
+impl From<AddrParseError> for MyError {
+    fn from(e: AddrParseError) -> Self {
+        MyError(e.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(try_setter(error = "MyError"), setter(into), derive(Debug))]
+struct Dolor {
+    pub source: MyAddr,
+}
+
 fn exact_helper() -> Result<Lorem, String> {
     LoremBuilder::default()
         .source(IpAddr::from_str("1.2.3.4").unwrap())
@@ -91,3 +106,14 @@ fn renamed() {
         .build()
         .expect("All fields were provided");
 }
+
+#[test]
+#[cfg(feature = "nightlytests")]
+fn custom_error() {
+    let mut builder = DolorBuilder::default();
+    let x: Result<&mut DolorBuilder, MyError> = builder.try_source("0.0.0.0");
+    assert!(x.is_ok());
+
+    let err = DolorBuilder::default().try_source("sit").unwrap_err();
+    assert_eq!(err, MyError("invalid IP address syntax".to_string()));
+}
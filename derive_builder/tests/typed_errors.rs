@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(build_fn(validate = "LoremBuilder::validate", typed_error))]
+struct Lorem {
+    #[builder(getter(try))]
+    ipsum: u32,
+    #[builder(default = "42")]
+    dolor: u32,
+}
+
+impl LoremBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.ipsum {
+            Some(0) => Err("ipsum must not be zero".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Builder)]
+struct Sit {
+    amet: u32,
+}
+
+#[test]
+fn success() {
+    let x = LoremBuilder::default().ipsum(1).build().unwrap();
+
+    assert_eq!(x, Lorem { ipsum: 1, dolor: 42 });
+}
+
+#[test]
+fn uninitialized_field() {
+    let error = LoremBuilder::default().build().unwrap_err();
+
+    assert_eq!(error, LoremBuilderError::UninitializedField("ipsum"));
+    assert_eq!(error.to_string(), "`ipsum` must be initialized");
+}
+
+#[test]
+fn validation_error() {
+    let error = LoremBuilder::default().ipsum(0).build().unwrap_err();
+
+    assert_eq!(error, LoremBuilderError::ValidationError("ipsum must not be zero".to_string()));
+    assert_eq!(error.to_string(), "ipsum must not be zero");
+}
+
+#[test]
+fn std_error() {
+    let error: Box<std::error::Error> = LoremBuilder::default().build().unwrap_err().into();
+
+    assert_eq!(error.to_string(), "`ipsum` must be initialized");
+}
+
+#[test]
+fn getter_uninitialized_field() {
+    let error = LoremBuilder::default().get_ipsum().unwrap_err();
+
+    assert_eq!(error, LoremBuilderError::UninitializedField("ipsum"));
+}
+
+#[test]
+fn string_by_default() {
+    let error: String = SitBuilder::default().build().unwrap_err();
+
+    assert_eq!(error, "`amet` must be initialized");
+}
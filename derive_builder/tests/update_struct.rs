@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate pretty_assertions;
+#[macro_use]
+extern crate derive_builder;
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(update_struct = "LoremUpdate")]
+struct Lorem {
+    ipsum: u32,
+    dolor: String,
+    #[builder(setter(skip))]
+    sit: bool,
+}
+
+#[derive(Debug, PartialEq, Builder)]
+#[builder(pattern = "owned", update_struct = "AmetUpdate")]
+struct Amet<T> {
+    consectetur: T,
+    adipiscing: Option<T>,
+}
+
+#[test]
+fn partial_update() {
+    let update = LoremUpdate {
+        ipsum: Some(42),
+        ..Default::default()
+    };
+
+    let x = LoremBuilder::default()
+        .ipsum(1)
+        .dolor("elit".to_string())
+        .apply_update(update)
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Lorem {
+        ipsum: 42,
+        dolor: "elit".to_string(),
+        sit: false,
+    });
+}
+
+#[test]
+fn empty_update() {
+    let x = LoremBuilder::default()
+        .apply_update(LoremUpdate::default())
+        .build();
+
+    assert!(x.is_err());
+}
+
+#[test]
+fn generic_owned() {
+    let update = AmetUpdate {
+        adipiscing: Some(Some("sed")),
+        ..Default::default()
+    };
+
+    let x = AmetBuilder::default()
+        .consectetur("do")
+        .apply_update(update)
+        .build()
+        .unwrap();
+
+    assert_eq!(x, Amet {
+        consectetur: "do",
+        adipiscing: Some("sed"),
+    });
+}
@@ -40,6 +40,70 @@ impl LoremBuilder {
     }
 }
 
+#[derive(Debug, Clone, Builder, PartialEq, Eq)]
+#[builder(build_fn(prepare="IpsumBuilder::prepare", validate="IpsumBuilder::validate"))]
+pub struct Ipsum {
+    /// A percentile. Must be between 0 and 100.
+    my_effort: u8,
+
+    /// Derived from `Ipsum::my_effort` unless set explicitly.
+    their_effort: u8,
+}
+
+impl IpsumBuilder {
+    /// Derives missing fields, before they are validated.
+    fn prepare(&mut self) {
+        if self.their_effort.is_none() {
+            self.their_effort = self.my_effort.map(|x| x.saturating_sub(10));
+        }
+    }
+
+    /// Performs bound checks, including derived fields.
+    fn validate(&self) -> Result<(), String> {
+        match self.their_effort {
+            Some(x) if x > 100 => Err("The game has changed".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Builder, PartialEq, Eq)]
+#[builder(build_fn(validate="DolorBuilder::validate"))]
+pub struct Dolor {
+    /// A percentile. Must be between 0 and 100.
+    #[builder(validate="check_percentile")]
+    my_effort: u8,
+
+    /// A percentile. Must be between 0 and 100.
+    #[builder(default="120", validate="check_percentile")]
+    their_effort: u8,
+}
+
+impl DolorBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.my_effort {
+            Some(0) => Err("Do something".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Builder, PartialEq, Eq)]
+#[builder(build_fn(collect_errors))]
+pub struct Sit {
+    #[builder(validate="check_percentile")]
+    my_effort: u8,
+    #[builder(validate="check_percentile")]
+    their_effort: u8,
+}
+
+fn check_percentile(value: &u8) -> Result<(), String> {
+    if *value > 100 {
+        return Err(format!("{} is not a percentile", value));
+    }
+    Ok(())
+}
+
 #[test]
 fn out_of_bounds() {
     assert_eq!(&LoremBuilder::default()
@@ -69,3 +133,66 @@ fn validation_pass() {
                    their_effort: 40,
                });
 }
+
+#[test]
+fn prepare_fills_derived_fields() {
+    let ipsum = IpsumBuilder::default()
+        .my_effort(90)
+        .build()
+        .expect("Derived fields should be filled in");
+
+    assert_eq!(ipsum,
+               Ipsum {
+                   my_effort: 90,
+                   their_effort: 80,
+               });
+}
+
+#[test]
+fn prepare_runs_before_validation() {
+    assert_eq!(&IpsumBuilder::default()
+                    .my_effort(120)
+                    .build()
+                    .unwrap_err(),
+               "The game has changed");
+}
+
+#[test]
+fn field_validation() {
+    assert_eq!(&DolorBuilder::default()
+                    .my_effort(120)
+                    .build()
+                    .unwrap_err(),
+               "120 is not a percentile");
+    assert_eq!(&DolorBuilder::default()
+                    .my_effort(50)
+                    .their_effort(101)
+                    .build()
+                    .unwrap_err(),
+               "101 is not a percentile");
+
+    // defaults are not validated
+    assert_eq!(DolorBuilder::default().my_effort(50).build(),
+               Ok(Dolor { my_effort: 50, their_effort: 120 }));
+}
+
+#[test]
+fn field_validation_after_struct_validation() {
+    assert_eq!(&DolorBuilder::default()
+                    .my_effort(0)
+                    .their_effort(101)
+                    .build()
+                    .unwrap_err(),
+               "Do something");
+}
+
+#[test]
+fn field_validation_collect_errors() {
+    assert_eq!(SitBuilder::default()
+                   .my_effort(120)
+                   .their_effort(130)
+                   .build()
+                   .unwrap_err(),
+               vec!["120 is not a percentile".to_string(),
+                    "130 is not a percentile".to_string()]);
+}
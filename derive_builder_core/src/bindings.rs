@@ -5,18 +5,34 @@ use RawTokens;
 pub struct Bindings {
     /// Whether the generated code should comply with `#![no_std]`.
     pub no_std: bool,
+    /// Whether `String` and `Vec` are taken from the `alloc` crate instead of
+    /// `collections`, if `no_std` is set.
+    pub alloc: bool,
 }
 
 impl Bindings {
     /// String type.
     pub fn string_ty(&self) -> RawTokens<&'static str> {
-        RawTokens(if self.no_std {
+        RawTokens(if self.no_std && self.alloc {
+                      ":: alloc :: string :: String"
+                  } else if self.no_std {
                       ":: collections :: string :: String"
                   } else {
                       ":: std :: string :: String"
                   })
     }
 
+    /// Vec type.
+    pub fn vec_ty(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std && self.alloc {
+                      ":: alloc :: vec :: Vec"
+                  } else if self.no_std {
+                      ":: collections :: vec :: Vec"
+                  } else {
+                      ":: std :: vec :: Vec"
+                  })
+    }
+
     /// Result type.
     pub fn result_ty(&self) -> RawTokens<&'static str> {
         RawTokens(if self.no_std {
@@ -71,6 +87,42 @@ impl Bindings {
                   })
     }
 
+    /// From trait.
+    pub fn from_trait(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: convert :: From"
+                  } else {
+                      ":: std :: convert :: From"
+                  })
+    }
+
+    /// Display trait.
+    pub fn display_trait(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: fmt :: Display"
+                  } else {
+                      ":: std :: fmt :: Display"
+                  })
+    }
+
+    /// Formatter type.
+    pub fn formatter_ty(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: fmt :: Formatter"
+                  } else {
+                      ":: std :: fmt :: Formatter"
+                  })
+    }
+
+    /// Result type of formatting traits.
+    pub fn fmt_result_ty(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: fmt :: Result"
+                  } else {
+                      ":: std :: fmt :: Result"
+                  })
+    }
+
     /// TryInto trait.
     pub fn try_into_trait(&self) -> RawTokens<&'static str> {
         RawTokens(if self.no_std {
@@ -79,14 +131,106 @@ impl Bindings {
                       ":: std :: convert :: TryInto"
                   })
     }
+
+    /// AsRef trait.
+    pub fn as_ref_trait(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: convert :: AsRef"
+                  } else {
+                      ":: std :: convert :: AsRef"
+                  })
+    }
+
+    /// AsMut trait.
+    pub fn as_mut_trait(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: convert :: AsMut"
+                  } else {
+                      ":: std :: convert :: AsMut"
+                  })
+    }
+
+    /// FromStr trait.
+    pub fn from_str_trait(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: str :: FromStr"
+                  } else {
+                      ":: std :: str :: FromStr"
+                  })
+    }
+
+    /// IntoIterator trait.
+    pub fn into_iterator_trait(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: iter :: IntoIterator"
+                  } else {
+                      ":: std :: iter :: IntoIterator"
+                  })
+    }
+
+    /// FromIterator trait.
+    pub fn from_iterator_trait(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: iter :: FromIterator"
+                  } else {
+                      ":: std :: iter :: FromIterator"
+                  })
+    }
+
+    /// Extend trait.
+    pub fn extend_trait(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: iter :: Extend"
+                  } else {
+                      ":: std :: iter :: Extend"
+                  })
+    }
+
+    /// Box type.
+    pub fn box_ty(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: alloc :: boxed :: Box"
+                  } else {
+                      ":: std :: boxed :: Box"
+                  })
+    }
+
+    /// Rc type.
+    pub fn rc_ty(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: alloc :: rc :: Rc"
+                  } else {
+                      ":: std :: rc :: Rc"
+                  })
+    }
+
+    /// Arc type.
+    pub fn arc_ty(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: alloc :: sync :: Arc"
+                  } else {
+                      ":: std :: sync :: Arc"
+                  })
+    }
+
+    /// PartialEq trait.
+    pub fn partial_eq_trait(&self) -> RawTokens<&'static str> {
+        RawTokens(if self.no_std {
+                      ":: core :: cmp :: PartialEq"
+                  } else {
+                      ":: std :: cmp :: PartialEq"
+                  })
+    }
 }
 
 #[test]
 fn std() {
-    let b = Bindings { no_std: false };
+    let b = Bindings { no_std: false, alloc: false };
 
     assert_eq!(b.string_ty().to_tokens(), quote!(::std::string::String));
 
+    assert_eq!(b.vec_ty().to_tokens(), quote!(::std::vec::Vec));
+
     assert_eq!(b.result_ty().to_tokens(), quote!(::std::result::Result));
 
     assert_eq!(b.option_ty().to_tokens(), quote!(::std::option::Option));
@@ -100,15 +244,42 @@ fn std() {
     assert_eq!(b.clone_trait().to_tokens(), quote!(::std::clone::Clone));
 
     assert_eq!(b.into_trait().to_tokens(), quote!(::std::convert::Into));
+
+    assert_eq!(b.from_trait().to_tokens(), quote!(::std::convert::From));
+
+    assert_eq!(b.display_trait().to_tokens(), quote!(::std::fmt::Display));
+
+    assert_eq!(b.formatter_ty().to_tokens(), quote!(::std::fmt::Formatter));
+
+    assert_eq!(b.fmt_result_ty().to_tokens(), quote!(::std::fmt::Result));
+
+    assert_eq!(b.as_mut_trait().to_tokens(), quote!(::std::convert::AsMut));
+
+    assert_eq!(b.from_str_trait().to_tokens(), quote!(::std::str::FromStr));
+
+    assert_eq!(b.from_iterator_trait().to_tokens(),
+               quote!(::std::iter::FromIterator));
+
+    assert_eq!(b.extend_trait().to_tokens(), quote!(::std::iter::Extend));
+
+    assert_eq!(b.partial_eq_trait().to_tokens(), quote!(::std::cmp::PartialEq));
+
+    assert_eq!(b.box_ty().to_tokens(), quote!(::std::boxed::Box));
+
+    assert_eq!(b.rc_ty().to_tokens(), quote!(::std::rc::Rc));
+
+    assert_eq!(b.arc_ty().to_tokens(), quote!(::std::sync::Arc));
 }
 
 #[test]
 fn no_std() {
-    let b = Bindings { no_std: true };
+    let b = Bindings { no_std: true, alloc: false };
 
     assert_eq!(b.string_ty().to_tokens(),
                quote!(::collections::string::String));
 
+    assert_eq!(b.vec_ty().to_tokens(), quote!(::collections::vec::Vec));
+
     assert_eq!(b.result_ty().to_tokens(), quote!(::core::result::Result));
 
     assert_eq!(b.option_ty().to_tokens(), quote!(::core::option::Option));
@@ -122,4 +293,43 @@ fn no_std() {
     assert_eq!(b.clone_trait().to_tokens(), quote!(::core::clone::Clone));
 
     assert_eq!(b.into_trait().to_tokens(), quote!(::core::convert::Into));
+
+    assert_eq!(b.from_trait().to_tokens(), quote!(::core::convert::From));
+
+    assert_eq!(b.display_trait().to_tokens(), quote!(::core::fmt::Display));
+
+    assert_eq!(b.formatter_ty().to_tokens(), quote!(::core::fmt::Formatter));
+
+    assert_eq!(b.fmt_result_ty().to_tokens(), quote!(::core::fmt::Result));
+
+    assert_eq!(b.as_mut_trait().to_tokens(), quote!(::core::convert::AsMut));
+
+    assert_eq!(b.from_str_trait().to_tokens(), quote!(::core::str::FromStr));
+
+    assert_eq!(b.from_iterator_trait().to_tokens(),
+               quote!(::core::iter::FromIterator));
+
+    assert_eq!(b.extend_trait().to_tokens(), quote!(::core::iter::Extend));
+
+    assert_eq!(b.partial_eq_trait().to_tokens(), quote!(::core::cmp::PartialEq));
+
+    assert_eq!(b.box_ty().to_tokens(), quote!(::alloc::boxed::Box));
+
+    assert_eq!(b.rc_ty().to_tokens(), quote!(::alloc::rc::Rc));
+
+    assert_eq!(b.arc_ty().to_tokens(), quote!(::alloc::sync::Arc));
+}
+
+#[test]
+fn no_std_alloc() {
+    let b = Bindings { no_std: true, alloc: true };
+
+    assert_eq!(b.string_ty().to_tokens(), quote!(::alloc::string::String));
+
+    assert_eq!(b.vec_ty().to_tokens(), quote!(::alloc::vec::Vec));
+
+    assert_eq!(b.result_ty().to_tokens(), quote!(::core::result::Result));
+
+    assert_eq!(b.default_trait().to_tokens(),
+               quote!(::core::default::Default));
 }
@@ -36,6 +36,16 @@ impl ToTokens for Block {
     }
 }
 
+impl Block {
+    /// Whether the block ends with the `?` operator, e.g. `compute()?`.
+    pub fn is_fallible(&self) -> bool {
+        match self.0.last() {
+            Some(&TokenTree::Token(syn::Token::Question)) => true,
+            _ => false,
+        }
+    }
+}
+
 impl FromStr for Block {
     type Err = String;
 
@@ -71,6 +81,13 @@ mod test {
         ));
     }
 
+    #[test]
+    fn block_fallible() {
+        assert!(Block::from_str("compute()?").unwrap().is_fallible());
+        assert!(!Block::from_str("compute()?.len()").unwrap().is_fallible());
+        assert!(!Block::from_str("\"?\"").unwrap().is_fallible());
+    }
+
     #[test]
     fn block_single_token_tree() {
         let expr = Block::from_str("42").unwrap();
@@ -43,14 +43,39 @@ pub struct BuildMethod<'a> {
     pub visibility: &'a syn::Visibility,
     /// How the build method takes and returns `self` (e.g. mutably).
     pub pattern: BuilderPattern,
+    /// Take `&mut self` regardless of the pattern and move values out of the
+    /// builder, leaving it empty for reuse.
+    pub reusable: bool,
     /// Type of the target field.
     ///
     /// The corresonding builder field will be `Option<field_type>`.
     pub target_ty: &'a syn::Ident,
+    /// Variant to build, if the target type is an enum, e.g. `Bar` for `Foo::Bar`.
+    pub target_variant: Option<&'a syn::Ident>,
     /// Type parameters and lifetimes attached to this builder struct.
     pub target_ty_generics: Option<syn::TyGenerics<'a>>,
+    /// Type to convert the target struct into via `Into`, which is then
+    /// returned instead of the target struct itself.
+    pub into_ty: Option<&'a syn::Ty>,
+    /// Function to post-process the target struct with, whose result is
+    /// returned instead of the target struct itself.
+    ///
+    /// The result must be of type `into_ty`, or of the target type if `into_ty`
+    /// is `None`. Takes precedence over the `Into` conversion.
+    pub map_fn: Option<&'a syn::Path>,
+    /// Pointer type to wrap the returned value in via its `new` function, e.g.
+    /// `Arc` to return `Arc<Foo>`.
+    ///
+    /// `Box`, `Rc` and `Arc` are resolved via `bindings`, any other path is
+    /// used as is.
+    pub wrap: Option<&'a syn::Path>,
     /// Field initializers for the target type.
     pub initializers: Vec<Tokens>,
+    /// Field names and their dependencies, one entry per pushed initializer.
+    ///
+    /// Initializers are evaluated after the initializers they depend on,
+    /// otherwise in declaration order.
+    pub initializer_deps: Vec<(syn::Ident, Vec<syn::Ident>)>,
     /// Doc-comment of the builder struct.
     pub doc_comment: Option<syn::Attribute>,
     /// Bindings to libstd or libcore.
@@ -59,9 +84,46 @@ pub struct BuildMethod<'a> {
     ///
     /// This will be in scope for all initializers as `__default`.
     pub default_struct: Option<Block>,
+    /// Take the default value for the whole struct as a parameter `base` of
+    /// the target type, instead of evaluating `default_struct`.
+    pub base_param: bool,
+    /// Emit an additional `try_build_all` fn, which builds a `Vec` of builders
+    /// and collects the errors of all failed builders with their indices.
+    pub build_all: bool,
+    /// Doc-comment of the `try_build_all` fn.
+    pub build_all_doc_comment: Option<syn::Attribute>,
     /// Validation function with signature `&FooBuilder -> Result<(), String>`
     /// to call before the macro-provided struct buildout.
     pub validate_fn: Option<&'a syn::Path>,
+    /// Preparation function with signature `&mut FooBuilder -> ()` to call
+    /// before `validate_fn`, e.g. to fill in derived fields.
+    ///
+    /// Requires the build method to take `&mut self`, or `mut self` for the
+    /// owned pattern.
+    pub prepare_fn: Option<&'a syn::Path>,
+    /// Check all required fields up front and return every error at once,
+    /// i.e. `Result<Foo, Vec<String>>`.
+    pub collect_errors: bool,
+    /// Typed error to return instead of a `String`, e.g. `FooBuilderError`,
+    /// see `BuilderError`.
+    ///
+    /// Ignored if `collect_errors` is enabled.
+    pub error_ty: Option<&'a syn::Ident>,
+    /// Statements pushing an error to `errors` for each uninitialized required
+    /// field, one entry per required field.
+    ///
+    /// Only used if `collect_errors` is enabled.
+    pub missing_field_checks: Vec<Tokens>,
+    /// Statements evaluating the values of unset fields before any value is
+    /// moved out of the builder, one entry per initializer, see
+    /// `Initializer::unset_value`.
+    pub unset_values: Vec<Tokens>,
+    /// Statements rejecting values with characters outside of their field's
+    /// charset, run after `validate_fn`.
+    pub charset_checks: Vec<Tokens>,
+    /// Statements passing set values to their field's validator, run after
+    /// `charset_checks`.
+    pub validation_checks: Vec<Tokens>,
 }
 
 impl<'a> ToTokens for BuildMethod<'a> {
@@ -70,39 +132,159 @@ impl<'a> ToTokens for BuildMethod<'a> {
         let vis = self.visibility;
         let target_ty = &self.target_ty;
         let target_ty_generics = &self.target_ty_generics;
-        let initializers = &self.initializers;
+        let evaluation_order = self.evaluation_order();
+        let initializers = evaluation_order.iter()
+            .map(|&i| &self.initializers[i])
+            .collect::<Vec<_>>();
+        let unset_values = evaluation_order.iter()
+            .filter_map(|&i| self.unset_values.get(i))
+            .collect::<Vec<_>>();
         let self_param = match self.pattern {
+            _ if self.reusable => quote!(&mut self),
+            BuilderPattern::Owned if self.prepare_fn.is_some() => quote!(mut self),
             BuilderPattern::Owned => quote!(self),
+            _ if self.prepare_fn.is_some() => quote!(&mut self),
             BuilderPattern::Mutable |
             BuilderPattern::Immutable => quote!(&self),
         };
+        let prepare_fn = self.prepare_fn.as_ref().map(|pfn| match self.pattern {
+            BuilderPattern::Owned if !self.reusable => quote!(#pfn(&mut self);),
+            _ => quote!(#pfn(&mut *self);),
+        });
         let doc_comment = &self.doc_comment;
-        let default_struct = self.default_struct
-            .as_ref()
-            .map(|default_expr| {
-                     let ident = syn::Ident::new(DEFAULT_STRUCT_NAME);
-                     quote!(let #ident: #target_ty = #default_expr;)
-                 });
-        let validate_fn = self.validate_fn
-            .as_ref()
-            .map(|vfn| quote!(#vfn(&self)?;));
+        let base_param = if self.base_param {
+            Some(quote!(, base: #target_ty #target_ty_generics))
+        } else {
+            None
+        };
+        let default_struct = if self.base_param {
+            let ident = syn::Ident::new(DEFAULT_STRUCT_NAME);
+            Some(quote!(let #ident = base;))
+        } else {
+            self.default_struct
+                .as_ref()
+                .map(|default_expr| {
+                         let ident = syn::Ident::new(DEFAULT_STRUCT_NAME);
+                         quote!(let #ident: #target_ty = #default_expr;)
+                     })
+        };
         let result = self.bindings.result_ty();
         let string = self.bindings.string_ty();
+        let charset_checks = &self.charset_checks;
+        let validation_checks = &self.validation_checks;
+        // Assert the signature up front, so a mismatch is reported against it, and not
+        // somewhere inside the generated call.
+        let validate_fn_check = self.validate_fn
+            .as_ref()
+            .map(|vfn| quote!(let _: fn(&Self) -> #result<_, _> = #vfn;));
+        let (error_ty, validate_fn) = if self.collect_errors {
+            let vec = self.bindings.vec_ty();
+            let validate_fn = self.validate_fn
+                .as_ref()
+                .map(|vfn| quote!(
+                    #validate_fn_check
+                    if let #result::Err(err) = #vfn(&self) {
+                        errors.push(err);
+                    }
+                ));
+            let missing_field_checks = &self.missing_field_checks;
+            (quote!(#vec<#string>), Some(quote!(
+                let mut errors: #vec<#string> = #vec::new();
+                #validate_fn
+                #(#missing_field_checks)*
+                #(#charset_checks)*
+                #(#validation_checks)*
+                if !errors.is_empty() {
+                    return #result::Err(errors);
+                }
+            )))
+        } else {
+            let validate_fn = self.validate_fn.as_ref().map(|vfn| quote!(
+                #validate_fn_check
+                #vfn(&self)?;
+            ));
+            let error_ty = match self.error_ty {
+                Some(error_ty) => quote!(#error_ty),
+                None => quote!(#string),
+            };
+            (error_ty, Some(quote!(
+                #validate_fn
+                #(#charset_checks)*
+                #(#validation_checks)*
+            )))
+        };
+        let target_path = match self.target_variant {
+            Some(variant) => quote!(#target_ty::#variant),
+            None => quote!(#target_ty),
+        };
+        let target = quote!(#target_path {
+            #(#initializers)*
+        });
+        let (return_ty, value) = match (self.into_ty, self.map_fn) {
+            (Some(into_ty), Some(map_fn)) => (quote!(#into_ty), quote!(#map_fn(#target))),
+            (Some(into_ty), None) => {
+                let into = self.bindings.into_trait();
+                (quote!(#into_ty), quote!(#into::into(#target)))
+            },
+            (None, Some(map_fn)) => {
+                (quote!(#target_ty #target_ty_generics), quote!(#map_fn(#target)))
+            },
+            (None, None) => (quote!(#target_ty #target_ty_generics), target),
+        };
+        let (return_ty, value) = match self.wrap {
+            Some(wrap) => {
+                let wrapper = self.wrapper(wrap);
+                (quote!(#wrapper<#return_ty>), quote!(#wrapper::new(#value)))
+            },
+            None => (return_ty, value),
+        };
 
         if self.enabled {
             trace!("Deriving build method `{}`.", self.ident.as_ref());
             tokens.append(quote!(
                 #doc_comment
-                #vis fn #ident(#self_param)
-                    -> #result<#target_ty #target_ty_generics, #string>
+                #vis fn #ident(#self_param #base_param)
+                    -> #result<#return_ty, #error_ty>
                 {
+                    #prepare_fn
                     #validate_fn
                     #default_struct
-                    Ok(#target_ty {
-                        #(#initializers)*
-                    })
+                    #(#unset_values)*
+                    Ok(#value)
                 }
-            ))
+            ));
+
+            if self.build_all {
+                let vec = self.bindings.vec_ty();
+                let builder = match self.pattern {
+                    _ if self.reusable => quote!(mut builder),
+                    BuilderPattern::Owned => quote!(builder),
+                    _ if self.prepare_fn.is_some() => quote!(mut builder),
+                    BuilderPattern::Mutable |
+                    BuilderPattern::Immutable => quote!(builder),
+                };
+                let doc_comment = &self.build_all_doc_comment;
+                tokens.append(quote!(
+                    #doc_comment
+                    #vis fn try_build_all(builders: #vec<Self>)
+                        -> #result<#vec<#return_ty>, #vec<(usize, #error_ty)>>
+                    {
+                        let mut targets = #vec::with_capacity(builders.len());
+                        let mut errors = #vec::new();
+                        for (index, #builder) in builders.into_iter().enumerate() {
+                            match builder.#ident() {
+                                #result::Ok(target) => targets.push(target),
+                                #result::Err(err) => errors.push((index, err)),
+                            }
+                        }
+                        if errors.is_empty() {
+                            #result::Ok(targets)
+                        } else {
+                            #result::Err(errors)
+                        }
+                    }
+                ));
+            }
         } else {
             trace!("Skipping build method.");
         }
@@ -116,15 +298,101 @@ impl<'a> BuildMethod<'a> {
         self
     }
 
+    /// Set a doc-comment for the `try_build_all` fn.
+    pub fn build_all_doc_comment(&mut self, s: String) -> &mut Self {
+        self.build_all_doc_comment = Some(doc_comment_from(s));
+        self
+    }
+
     /// Populate the `BuildMethod` with appropriate initializers of the
     /// underlying struct.
     ///
     /// For each struct field this must be called with the appropriate
     /// initializer.
-    pub fn push_initializer(&mut self, init: Initializer) -> &mut Self {
+    pub fn push_initializer<'b>(&mut self, mut init: Initializer<'b>) -> &mut Self
+        where 'a: 'b
+    {
+        if self.collect_errors {
+            init.checked_by_build_fn = true;
+            self.missing_field_checks.extend(init.missing_field_check());
+        } else {
+            init.error_ty = self.error_ty;
+        }
+        self.charset_checks.extend(init.charset_check());
+        self.validation_checks.extend(init.validation_check());
+        let unset_value = init.unset_value();
+        self.unset_values.push(quote!(#unset_value));
         self.initializers.push(quote!(#init));
+        self.initializer_deps.push((init.field_ident.clone(), init.depends_on.to_vec()));
         self
     }
+
+    /// Path of the `wrap` pointer type, resolving `Box`, `Rc` and `Arc` via
+    /// `bindings`.
+    fn wrapper(&self, wrap: &syn::Path) -> Tokens {
+        let name = if !wrap.global && wrap.segments.len() == 1 {
+            Some(wrap.segments[0].ident.as_ref())
+        } else {
+            None
+        };
+
+        match name {
+            Some("Box") => self.bindings.box_ty().to_tokens(),
+            Some("Rc") => self.bindings.rc_ty().to_tokens(),
+            Some("Arc") => self.bindings.arc_ty().to_tokens(),
+            _ => quote!(#wrap),
+        }
+    }
+
+    /// Indices into `initializers`, sorted topologically by their dependencies,
+    /// see `evaluation_order`.
+    fn evaluation_order(&self) -> Vec<usize> {
+        evaluation_order(&self.initializer_deps, self.initializers.len())
+    }
+}
+
+/// Indices of `n` initializers, sorted topologically by their dependencies,
+/// which are given as `(field, dependencies)` per initializer.
+///
+/// Falls back to declaration order, if there are no dependencies.
+///
+/// # Panics
+///
+/// If a dependency is unknown or dependencies are cyclic.
+pub fn evaluation_order(deps: &[(syn::Ident, Vec<syn::Ident>)], n: usize) -> Vec<usize> {
+    if deps.len() != n || deps.iter().all(|&(_, ref d)| d.is_empty()) {
+        return (0..n).collect();
+    }
+
+    let position = |dep: &syn::Ident, of: &syn::Ident| {
+        deps.iter()
+            .position(|&(ref ident, _)| ident == dep)
+            .unwrap_or_else(|| panic!("Field `{}` depends on unknown field `{}`.", of, dep))
+    };
+
+    let mut done = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    while order.len() < n {
+        let next = (0..n).find(|&i| {
+            !done[i] && deps[i].1.iter().all(|dep| done[position(dep, &deps[i].0)])
+        });
+
+        match next {
+            Some(i) => {
+                done[i] = true;
+                order.push(i);
+            },
+            None => {
+                let cycle: Vec<_> = (0..n)
+                    .filter(|&i| !done[i])
+                    .map(|i| format!("`{}`", deps[i].0))
+                    .collect();
+                panic!("Cyclic field dependencies between {}.", cycle.join(", "));
+            },
+        }
+    }
+
+    order
 }
 
 /// Helper macro for unit tests. This is _only_ public in order to be accessible
@@ -138,13 +406,29 @@ macro_rules! default_build_method {
             ident: &syn::Ident::new("build"),
             visibility: &syn::Visibility::Public,
             pattern: BuilderPattern::Mutable,
+            reusable: false,
             target_ty: &syn::Ident::new("Foo"),
+            target_variant: None,
             target_ty_generics: None,
+            into_ty: None,
+            map_fn: None,
+            wrap: None,
             initializers: vec![quote!(foo: self.foo,)],
+            initializer_deps: vec![],
             doc_comment: None,
             bindings: Default::default(),
             default_struct: None,
+            base_param: false,
+            build_all: false,
+            build_all_doc_comment: None,
             validate_fn: None,
+            prepare_fn: None,
+            collect_errors: false,
+            error_ty: None,
+            missing_field_checks: vec![],
+            unset_values: vec![],
+            charset_checks: vec![],
+            validation_checks: vec![],
         }
     }
 }
@@ -167,6 +451,202 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn into() {
+        let into_ty = syn::parse_type("Bar").unwrap();
+        let mut build_method = default_build_method!();
+        build_method.into_ty = Some(&into_ty);
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self) -> ::std::result::Result<Bar, ::std::string::String> {
+                Ok(::std::convert::Into::into(Foo {
+                    foo: self.foo,
+                }))
+            }
+        ));
+    }
+
+    #[test]
+    fn variant() {
+        let variant = syn::Ident::new("Bar");
+        let mut build_method = default_build_method!();
+        build_method.target_variant = Some(&variant);
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self) -> ::std::result::Result<Foo, ::std::string::String> {
+                Ok(Foo::Bar {
+                    foo: self.foo,
+                })
+            }
+        ));
+    }
+
+    #[test]
+    fn wrap() {
+        let arc = syn::parse_path("Arc").unwrap();
+        let mut build_method = default_build_method!();
+        build_method.wrap = Some(&arc);
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self)
+                -> ::std::result::Result<::std::sync::Arc<Foo>, ::std::string::String>
+            {
+                Ok(::std::sync::Arc::new(Foo {
+                    foo: self.foo,
+                }))
+            }
+        ));
+
+        let rc = syn::parse_path("Rc").unwrap();
+        build_method.wrap = Some(&rc);
+        build_method.bindings.no_std = true;
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self)
+                -> ::core::result::Result<::alloc::rc::Rc<Foo>, ::collections::string::String>
+            {
+                Ok(::alloc::rc::Rc::new(Foo {
+                    foo: self.foo,
+                }))
+            }
+        ));
+
+        let custom = syn::parse_path("std::cell::RefCell").unwrap();
+        build_method.wrap = Some(&custom);
+        build_method.bindings.no_std = false;
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self)
+                -> ::std::result::Result<std::cell::RefCell<Foo>, ::std::string::String>
+            {
+                Ok(std::cell::RefCell::new(Foo {
+                    foo: self.foo,
+                }))
+            }
+        ));
+    }
+
+    #[test]
+    fn map() {
+        let into_ty = syn::parse_type("Arc<Foo>").unwrap();
+        let map_path = syn::parse_path("Arc::new")
+            .expect("Statically-entered path should be valid");
+        let mut build_method = default_build_method!();
+        build_method.map_fn = Some(&map_path);
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self) -> ::std::result::Result<Foo, ::std::string::String> {
+                Ok(Arc::new(Foo {
+                    foo: self.foo,
+                }))
+            }
+        ));
+
+        build_method.into_ty = Some(&into_ty);
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self) -> ::std::result::Result<Arc<Foo>, ::std::string::String> {
+                Ok(Arc::new(Foo {
+                    foo: self.foo,
+                }))
+            }
+        ));
+    }
+
+    #[test]
+    fn prepare() {
+        let prepare_path = syn::parse_path("IpsumBuilder::prepare")
+            .expect("Statically-entered path should be valid");
+        let validate_path = syn::parse_path("IpsumBuilder::validate")
+            .expect("Statically-entered path should be valid");
+        let mut build_method = default_build_method!();
+        build_method.prepare_fn = Some(&prepare_path);
+        build_method.validate_fn = Some(&validate_path);
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&mut self) -> ::std::result::Result<Foo, ::std::string::String> {
+                IpsumBuilder::prepare(&mut *self);
+                let _: fn(&Self) -> ::std::result::Result<_, _> = IpsumBuilder::validate;
+                IpsumBuilder::validate(&self)?;
+                Ok(Foo {
+                    foo: self.foo,
+                })
+            }
+        ));
+
+        build_method.pattern = BuilderPattern::Owned;
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(mut self) -> ::std::result::Result<Foo, ::std::string::String> {
+                IpsumBuilder::prepare(&mut self);
+                let _: fn(&Self) -> ::std::result::Result<_, _> = IpsumBuilder::validate;
+                IpsumBuilder::validate(&self)?;
+                Ok(Foo {
+                    foo: self.foo,
+                })
+            }
+        ));
+    }
+
+    #[test]
+    fn collect_errors() {
+        let validate_path = syn::parse_path("IpsumBuilder::validate")
+            .expect("Statically-entered path should be valid");
+        let mut build_method = default_build_method!();
+        build_method.collect_errors = true;
+        build_method.validate_fn = Some(&validate_path);
+        build_method.missing_field_checks = vec![quote!(
+            if self.foo.is_none() {
+                errors.push(::std::string::String::from("`foo` must be initialized"));
+            }
+        )];
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self)
+                -> ::std::result::Result<Foo, ::std::vec::Vec<::std::string::String> >
+            {
+                let mut errors: ::std::vec::Vec<::std::string::String> =
+                    ::std::vec::Vec::new();
+                let _: fn(&Self) -> ::std::result::Result<_, _> = IpsumBuilder::validate;
+                if let ::std::result::Result::Err(err) = IpsumBuilder::validate(&self) {
+                    errors.push(err);
+                }
+                if self.foo.is_none() {
+                    errors.push(::std::string::String::from("`foo` must be initialized"));
+                }
+                if !errors.is_empty() {
+                    return ::std::result::Result::Err(errors);
+                }
+                Ok(Foo {
+                    foo: self.foo,
+                })
+            }
+        ));
+    }
+
+    #[test]
+    fn charset_checks() {
+        let mut build_method = default_build_method!();
+        build_method.charset_checks = vec![quote!(
+            if let ::std::option::Option::Some(ref value) = self.foo {
+                if !value.chars().all(|c| c.is_ascii()) {
+                    return ::std::result::Result::Err(::std::string::String::from(
+                        "`foo` must only contain ASCII characters"));
+                }
+            }
+        )];
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self) -> ::std::result::Result<Foo, ::std::string::String> {
+                if let ::std::option::Option::Some(ref value) = self.foo {
+                    if !value.chars().all(|c| c.is_ascii()) {
+                        return ::std::result::Result::Err(::std::string::String::from(
+                            "`foo` must only contain ASCII characters"));
+                    }
+                }
+                Ok(Foo {
+                    foo: self.foo,
+                })
+            }
+        ));
+    }
+
     #[test]
     fn no_std() {
         let mut build_method = default_build_method!();
@@ -196,6 +676,55 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn base_param() {
+        let mut build_method = default_build_method!();
+        build_method.default_struct = Some("Default::default()".parse().unwrap());
+        build_method.base_param = true;
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self, base: Foo) -> ::std::result::Result<Foo, ::std::string::String> {
+                let __default = base;
+                Ok(Foo {
+                    foo: self.foo,
+                })
+            }
+        ));
+    }
+
+    #[test]
+    fn build_all() {
+        let mut build_method = default_build_method!();
+        build_method.build_all = true;
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self) -> ::std::result::Result<Foo, ::std::string::String> {
+                Ok(Foo {
+                    foo: self.foo,
+                })
+            }
+
+            pub fn try_build_all(builders: ::std::vec::Vec<Self>)
+                -> ::std::result::Result<::std::vec::Vec<Foo>,
+                                         ::std::vec::Vec<(usize, ::std::string::String)>>
+            {
+                let mut targets = ::std::vec::Vec::with_capacity(builders.len());
+                let mut errors = ::std::vec::Vec::new();
+                for (index, builder) in builders.into_iter().enumerate() {
+                    match builder.build() {
+                        ::std::result::Result::Ok(target) => targets.push(target),
+                        ::std::result::Result::Err(err) => errors.push((index, err)),
+                    }
+                }
+                if errors.is_empty() {
+                    ::std::result::Result::Ok(targets)
+                } else {
+                    ::std::result::Result::Err(errors)
+                }
+            }
+        ));
+    }
+
     #[test]
     fn skip() {
         let mut build_method = default_build_method!();
@@ -220,6 +749,64 @@ mod tests {
         ))
     }
 
+    #[test]
+    fn reusable() {
+        let mut build_method: BuildMethod = default_build_method!();
+        build_method.pattern = BuilderPattern::Owned;
+        build_method.reusable = true;
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&mut self) -> ::std::result::Result<Foo, ::std::string::String> {
+                Ok(Foo {
+                    foo: self.foo,
+                })
+            }
+        ));
+    }
+
+    #[test]
+    fn depends_on() {
+        let mut build_method: BuildMethod = default_build_method!();
+        build_method.initializers = vec![quote!(foo: self.foo,), quote!(bar: self.bar,)];
+        build_method.initializer_deps = vec![
+            (syn::Ident::new("foo"), vec![syn::Ident::new("bar")]),
+            (syn::Ident::new("bar"), vec![]),
+        ];
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self) -> ::std::result::Result<Foo, ::std::string::String> {
+                Ok(Foo {
+                    bar: self.bar,
+                    foo: self.foo,
+                })
+            }
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cyclic field dependencies between `foo`, `bar`.")]
+    fn depends_on_cycle() {
+        let mut build_method: BuildMethod = default_build_method!();
+        build_method.initializers = vec![quote!(foo: self.foo,), quote!(bar: self.bar,)];
+        build_method.initializer_deps = vec![
+            (syn::Ident::new("foo"), vec![syn::Ident::new("bar")]),
+            (syn::Ident::new("bar"), vec![syn::Ident::new("foo")]),
+        ];
+
+        quote!(#build_method);
+    }
+
+    #[test]
+    #[should_panic(expected = "Field `foo` depends on unknown field `baz`.")]
+    fn depends_on_unknown() {
+        let mut build_method: BuildMethod = default_build_method!();
+        build_method.initializer_deps = vec![
+            (syn::Ident::new("foo"), vec![syn::Ident::new("baz")]),
+        ];
+
+        quote!(#build_method);
+    }
+
     #[test]
     fn validation() {
         let validate_path = syn::parse_path("IpsumBuilder::validate")
@@ -230,6 +817,29 @@ mod tests {
 
         assert_eq!(quote!(#build_method), quote!(
             pub fn build(&self) -> ::std::result::Result<Foo, ::std::string::String> {
+                let _: fn(&Self) -> ::std::result::Result<_, _> = IpsumBuilder::validate;
+                IpsumBuilder::validate(&self)?;
+
+                Ok(Foo {
+                    foo: self.foo,
+                })
+            }
+        ));
+    }
+
+    #[test]
+    fn typed_error() {
+        let validate_path = syn::parse_path("IpsumBuilder::validate")
+            .expect("Statically-entered path should be valid");
+        let error_ty = syn::Ident::new("FooBuilderError");
+
+        let mut build_method: BuildMethod = default_build_method!();
+        build_method.validate_fn = Some(&validate_path);
+        build_method.error_ty = Some(&error_ty);
+
+        assert_eq!(quote!(#build_method), quote!(
+            pub fn build(&self) -> ::std::result::Result<Foo, FooBuilderError> {
+                let _: fn(&Self) -> ::std::result::Result<_, _> = IpsumBuilder::validate;
                 IpsumBuilder::validate(&self)?;
 
                 Ok(Foo {
@@ -6,6 +6,10 @@ use BuildMethod;
 use BuilderField;
 use BuilderPattern;
 use Setter;
+use Getter;
+use FieldsFn;
+use FieldsFnKind;
+use ident_name;
 use doc_comment::doc_comment_from;
 use DeprecationNotes;
 
@@ -48,8 +52,20 @@ pub struct Builder<'a> {
     pub ident: &'a syn::Ident,
     /// Pattern of this builder struct.
     pub pattern: BuilderPattern,
-    /// Traits to automatically derive on the builder type.
+    /// Additional traits to derive on the builder type.
+    ///
+    /// `Default` is derived if `derive_default` is set, `Clone` only if
+    /// `must_derive_clone` is set.
     pub derives: &'a [syn::Ident],
+    /// Whether to derive `Default` on the builder.
+    ///
+    /// Otherwise `Default` is implemented manually via `default_fields`,
+    /// without requiring any type parameter to implement `Default`.
+    pub derive_default: bool,
+    /// Whether the builder must derive `Clone`, e.g. because a setter clones `self`.
+    ///
+    /// Owned builders move their fields instead, so their fields need not be `Clone`.
+    pub must_derive_clone: bool,
     /// Type parameters and lifetimes attached to this builder's struct definition.
     pub generics: Option<&'a syn::Generics>,
     /// Visibility of the builder struct, e.g. `syn::Visibility::Public`.
@@ -58,10 +74,43 @@ pub struct Builder<'a> {
     ///
     /// Expects each entry to be terminated by a comma.
     pub fields: Vec<Tokens>,
+    /// Initializers of the builder fields in the manual `Default` impl,
+    /// e.g. `foo: ::std::option::Option::None,`
+    ///
+    /// Only used if `derive_default` is disabled.
+    pub default_fields: Vec<Tokens>,
     /// Functions of the builder struct, e.g. `fn bar() -> { unimplemented!() }`
     pub functions: Vec<Tokens>,
+    /// Trait declaring the setters, e.g. `path::FooSetters`.
+    ///
+    /// If set, setters are emitted in an `impl FooSetters for FooBuilder`
+    /// instead of the inherent impl.
+    pub setter_trait: Option<&'a syn::Path>,
+    /// Functions of the `setter_trait` impl, i.e. all setters.
+    pub trait_functions: Vec<Tokens>,
     /// Doc-comment of the builder struct.
     pub doc_comment: Option<syn::Attribute>,
+    /// Emit an inherent `new` constructor, delegating to `Default`.
+    pub new_fn: bool,
+    /// Mark the builder struct `#[non_exhaustive]`, so other crates can't
+    /// construct or exhaustively destructure it.
+    pub non_exhaustive: bool,
+    /// Struct with the same `Option`-wrapped fields as the builder, e.g.
+    /// `PartialFoo`, to implement `From` conversions in both directions for.
+    pub partial: Option<&'a syn::Ty>,
+    /// Initializers of the builder fields from a `partial` value,
+    /// e.g. `foo: partial.foo,`
+    pub from_partial_fields: Vec<Tokens>,
+    /// Initializers of the `partial` fields from a builder,
+    /// e.g. `foo: builder.foo,`
+    ///
+    /// Fields without a setter are not part of the `partial` struct.
+    pub into_partial_fields: Vec<Tokens>,
+    /// `fmt` function of a `Display` impl for the builder, if any.
+    pub display_fn: Option<Tokens>,
+    /// Bounds of the `Display` impl on the types of the printed fields,
+    /// e.g. `u32: Display`.
+    pub display_bounds: Vec<Tokens>,
     /// Emit deprecation notes to the user.
     pub deprecation_notes: DeprecationNotes,
     /// Library bindings to use in emitted builder.
@@ -74,7 +123,14 @@ impl<'a> ToTokens for Builder<'a> {
             trace!("Deriving builder `{}`.", self.ident);
             let builder_vis = self.visibility;
             let builder_ident = self.ident;
-            let derives = self.derives;
+            let mut derives = vec![];
+            if self.derive_default {
+                derives.push(quote!(Default));
+            }
+            if self.must_derive_clone && !self.derives.iter().any(|x| x == "Clone") {
+                derives.push(quote!(Clone));
+            }
+            derives.extend(self.derives.iter().map(|x| quote!(#x)));
             let bounded_generics = self.compute_impl_bounds();
             let (impl_generics, _, _) = bounded_generics.split_for_impl();
             let (struct_generics, ty_generics, where_clause) = self.generics
@@ -83,8 +139,106 @@ impl<'a> ToTokens for Builder<'a> {
                 .unwrap_or((None, None, None));
             let builder_fields = &self.fields;
             let functions = &self.functions;
+            let trait_impl = self.setter_trait.map(|setter_trait| {
+                let trait_functions = &self.trait_functions;
+                quote!(
+                    impl #impl_generics #setter_trait for #builder_ident #ty_generics #where_clause {
+                        #(#trait_functions)*
+                    }
+                )
+            });
+            let default_impl = if self.derive_default {
+                None
+            } else {
+                let default = self.bindings.default_trait();
+                let default_fields = &self.default_fields;
+                Some(quote!(
+                    impl #struct_generics #default for #builder_ident #ty_generics #where_clause {
+                        fn default() -> Self {
+                            #builder_ident {
+                                #(#default_fields)*
+                            }
+                        }
+                    }
+                ))
+            };
             let builder_doc_comment = &self.doc_comment;
+            let non_exhaustive = if self.non_exhaustive {
+                Some(quote!(#[non_exhaustive]))
+            } else {
+                None
+            };
             let deprecation_notes = &self.deprecation_notes.as_item();
+            let new_fn = if self.new_fn {
+                let default = self.bindings.default_trait();
+                Some(quote!(
+                    /// Create an empty builder, with all fields set to `None`.
+                    #builder_vis fn new() -> Self where Self: #default {
+                        #default::default()
+                    }
+                ))
+            } else {
+                None
+            };
+
+            let partial_impls = self.partial.map(|partial| {
+                let from = self.bindings.from_trait();
+                let from_partial_fields = &self.from_partial_fields;
+                let into_partial_fields = &self.into_partial_fields;
+                // struct expressions can't name type parameters, they are inferred instead
+                let partial_ctor = match *partial {
+                    syn::Ty::Path(None, ref path) => {
+                        let mut path = path.clone();
+                        if let Some(segment) = path.segments.last_mut() {
+                            segment.parameters = syn::PathParameters::none();
+                        }
+                        path
+                    },
+                    _ => panic!("Unable to construct the partial struct `{}`, expected a path.",
+                                quote!(#partial)),
+                };
+                quote!(
+                    impl #struct_generics #from<#partial> for #builder_ident #ty_generics
+                        #where_clause
+                    {
+                        fn from(partial: #partial) -> Self {
+                            #builder_ident {
+                                #(#from_partial_fields)*
+                            }
+                        }
+                    }
+
+                    impl #struct_generics #from<#builder_ident #ty_generics> for #partial
+                        #where_clause
+                    {
+                        fn from(builder: #builder_ident #ty_generics) -> Self {
+                            #partial_ctor {
+                                #(#into_partial_fields)*
+                            }
+                        }
+                    }
+                )
+            });
+
+            let display_impl = self.display_fn.as_ref().map(|display_fn| {
+                let display = self.bindings.display_trait();
+                let predicates = self.generics
+                    .map(|g| g.where_clause.predicates.as_slice())
+                    .unwrap_or(&[]);
+                let display_bounds = &self.display_bounds;
+                let display_where_clause = if predicates.is_empty() && display_bounds.is_empty() {
+                    None
+                } else {
+                    Some(quote!(where #(#predicates,)* #(#display_bounds),*))
+                };
+                quote!(
+                    impl #struct_generics #display for #builder_ident #ty_generics
+                        #display_where_clause
+                    {
+                        #display_fn
+                    }
+                )
+            });
 
             debug!("ty_generics={:?}, where_clause={:?}, struct_generics={:?}",
                    ty_generics,
@@ -92,17 +246,27 @@ impl<'a> ToTokens for Builder<'a> {
                    struct_generics);
 
             tokens.append(quote!(
-                #[derive(Default, Clone #( , #derives)* )]
+                #[derive(#(#derives),*)]
                 #builder_doc_comment
+                #non_exhaustive
                 #builder_vis struct #builder_ident #struct_generics #where_clause {
                     #(#builder_fields)*
                 }
 
+                #default_impl
+
                 #[allow(dead_code)]
                 impl #impl_generics #builder_ident #ty_generics #where_clause {
+                    #new_fn
                     #(#functions)*
                     #deprecation_notes
                 }
+
+                #trait_impl
+
+                #partial_impls
+
+                #display_impl
             ));
         } else {
             trace!("Skipping builder `{}`.", self.ident);
@@ -118,13 +282,55 @@ impl<'a> Builder<'a> {
     }
 
     /// Add a field to the builder
+    ///
+    /// A field with an `initial_value` disables the derived `Default`, since
+    /// the manual impl is needed to start with that value.
     pub fn push_field(&mut self, f: BuilderField) -> &mut Self {
+        let ident = f.field_ident;
+        let cfg_attrs = f.attrs.iter().filter(|a| a.value.name() == "cfg");
+        let cfg_attrs = &cfg_attrs.collect::<Vec<_>>();
+        let empty = if f.setter_enabled {
+            let option = self.bindings.option_ty();
+            self.from_partial_fields.push(quote!(#(#cfg_attrs)* #ident: partial.#ident,));
+            self.into_partial_fields.push(quote!(#(#cfg_attrs)* #ident: builder.#ident,));
+            match f.initial_value {
+                Some(value) => {
+                    self.derive_default = false;
+                    quote!(#option::Some(#value))
+                },
+                None => quote!(#option::None),
+            }
+        } else {
+            let phantom_data = self.bindings.phantom_data_ty();
+            self.from_partial_fields.push(quote!(#(#cfg_attrs)* #ident: #phantom_data,));
+            quote!(#phantom_data)
+        };
+        self.default_fields.push(quote!(#(#cfg_attrs)* #ident: #empty,));
         self.fields.push(quote!(#f));
         self
     }
 
     /// Add a setter function to the builder
-    pub fn push_setter_fn(&mut self, f: Setter) -> &mut Self {
+    ///
+    /// If the builder implements a `setter_trait`, the setter becomes part of
+    /// that impl, where it must not have a visibility.
+    pub fn push_setter_fn(&mut self, mut f: Setter) -> &mut Self {
+        if f.enabled && f.pattern == BuilderPattern::Immutable {
+            self.must_derive_clone = true;
+        }
+        if self.setter_trait.is_some() {
+            f.visibility = &syn::Visibility::Inherited;
+            self.trait_functions.push(quote!(#f));
+        } else {
+            self.functions.push(quote!(#f));
+        }
+        self
+    }
+
+    /// Add a getter function to the builder
+    ///
+    /// Getters are always inherent, even if the builder implements a `setter_trait`.
+    pub fn push_getter_fn(&mut self, f: Getter) -> &mut Self {
         self.functions.push(quote!(#f));
         self
     }
@@ -135,6 +341,46 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Add a function visiting all fields to the builder, e.g. `merge`.
+    ///
+    /// `Display::fmt` goes into a separate `Display` impl instead, which is
+    /// bounded by the field bounds of `f`.
+    pub fn push_fields_fn(&mut self, f: FieldsFn) -> &mut Self {
+        match f.kind {
+            FieldsFnKind::Display if f.enabled => {
+                self.display_fn = Some(quote!(#f));
+                self.display_bounds = f.field_bounds;
+            },
+            FieldsFnKind::Display => {},
+            _ => self.functions.push(quote!(#f)),
+        }
+        self
+    }
+
+    /// Add a `take_*` function to the builder, moving the value of a field out
+    /// of the builder via `Option::take`.
+    ///
+    /// The function has the same visibility as the builder field. Fields
+    /// without a setter are skipped.
+    pub fn push_take_fn(&mut self, f: BuilderField) -> &mut Self {
+        if f.setter_enabled {
+            let ident = f.field_ident;
+            let ty = f.field_type;
+            let vis = f.field_visibility;
+            let take_ident = syn::Ident::new(format!("take_{}", ident_name(ident)));
+            let attrs = f.attrs;
+            let option = self.bindings.option_ty();
+
+            self.functions.push(quote!(
+                #(#attrs)*
+                #vis fn #take_ident(&mut self) -> #option<#ty> {
+                    #option::take(&mut self.#ident)
+                }
+            ));
+        }
+        self
+    }
+
     /// Add `Clone` trait bound to generic types for non-owned builders.
     /// This enables target types to declare generics without requiring a `Clone`
     /// impl. This is the same as how the built-in derives for `Clone`, `Default`,
@@ -177,11 +423,23 @@ macro_rules! default_builder {
             ident: &syn::Ident::new("FooBuilder"),
             pattern: Default::default(),
             derives: &vec![],
+            derive_default: true,
+            must_derive_clone: true,
             generics: None,
             visibility: &syn::Visibility::Public,
             fields: vec![quote!(foo: u32,)],
+            default_fields: vec![],
             functions: vec![quote!(fn bar() -> { unimplemented!() })],
+            setter_trait: None,
+            trait_functions: vec![],
             doc_comment: None,
+            new_fn: false,
+            non_exhaustive: false,
+            partial: None,
+            from_partial_fields: vec![],
+            into_partial_fields: vec![],
+            display_fn: None,
+            display_bounds: vec![],
             deprecation_notes: DeprecationNotes::default(),
             bindings: Default::default(),
         }
@@ -192,6 +450,7 @@ macro_rules! default_builder {
 mod tests {
     #[allow(unused_imports)]
     use super::*;
+    use Block;
     
     #[test]
     fn simple() {
@@ -212,6 +471,192 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn non_exhaustive() {
+        let mut builder = default_builder!();
+        builder.non_exhaustive = true;
+
+        assert_eq!(quote!(#builder), quote!(
+            #[derive(Default, Clone)]
+            #[non_exhaustive]
+            pub struct FooBuilder {
+                foo: u32,
+            }
+
+            #[allow(dead_code)]
+            impl FooBuilder {
+                fn bar () -> {
+                    unimplemented!()
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn manual_default() {
+        let ast = syn::parse_macro_input(stringify!(
+            struct Lorem<T> where T: PartialEq { }
+        )).expect("Couldn't parse item");
+        let generics = ast.generics;
+        let foo = syn::Ident::new("foo");
+        let bar = syn::Ident::new("bar");
+        let ty = syn::parse_type("T").unwrap();
+        let vis = syn::Visibility::Inherited;
+        let attrs = vec![syn::parse_outer_attr("#[cfg(feature = \"bar\")]").unwrap()];
+        let mut builder = default_builder!();
+        builder.generics = Some(&generics);
+        builder.pattern = BuilderPattern::Owned;
+        builder.derive_default = false;
+        builder.must_derive_clone = false;
+        builder.fields.clear();
+        builder.functions.clear();
+        builder.push_field(BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+        builder.push_field(BuilderField {
+            field_ident: &bar,
+            field_type: &ty,
+            setter_enabled: false,
+            field_visibility: &vis,
+            attrs: &attrs,
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+
+        assert_eq!(quote!(#builder), quote!(
+            #[derive()]
+            pub struct FooBuilder<T> where T: PartialEq {
+                foo: ::std::option::Option<T>,
+                #[cfg(feature = "bar")]
+                bar: ::std::marker::PhantomData<T>,
+            }
+
+            impl<T> ::std::default::Default for FooBuilder<T> where T: PartialEq {
+                fn default() -> Self {
+                    FooBuilder {
+                        foo: ::std::option::Option::None,
+                        #[cfg(feature = "bar")]
+                        bar: ::std::marker::PhantomData,
+                    }
+                }
+            }
+
+            #[allow(dead_code)]
+            impl<T> FooBuilder<T> where T: PartialEq {
+            }
+        ));
+    }
+
+    #[test]
+    fn initial_value() {
+        let foo = syn::Ident::new("foo");
+        let ty = syn::parse_type("Vec<u32>").unwrap();
+        let vis = syn::Visibility::Inherited;
+        let value: Block = "Vec::new()".parse().unwrap();
+        let mut builder = default_builder!();
+        builder.fields.clear();
+        builder.functions.clear();
+        builder.push_field(BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: Some(&value),
+            bindings: Default::default(),
+        });
+
+        assert_eq!(quote!(#builder), quote!(
+            #[derive(Clone)]
+            pub struct FooBuilder {
+                foo: ::std::option::Option<Vec<u32> >,
+            }
+
+            impl ::std::default::Default for FooBuilder {
+                fn default() -> Self {
+                    FooBuilder {
+                        foo: ::std::option::Option::Some({ Vec::new() }),
+                    }
+                }
+            }
+
+            #[allow(dead_code)]
+            impl FooBuilder {
+            }
+        ));
+    }
+
+    #[test]
+    fn partial() {
+        let foo = syn::Ident::new("foo");
+        let bar = syn::Ident::new("bar");
+        let ty = syn::parse_type("u32").unwrap();
+        let partial = syn::parse_type("PartialFoo").unwrap();
+        let vis = syn::Visibility::Inherited;
+        let mut builder = default_builder!();
+        builder.partial = Some(&partial);
+        builder.fields.clear();
+        builder.functions.clear();
+        builder.push_field(BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+        builder.push_field(BuilderField {
+            field_ident: &bar,
+            field_type: &ty,
+            setter_enabled: false,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+
+        assert_eq!(quote!(#builder), quote!(
+            #[derive(Default, Clone)]
+            pub struct FooBuilder {
+                foo: ::std::option::Option<u32>,
+                bar: ::std::marker::PhantomData<u32>,
+            }
+
+            #[allow(dead_code)]
+            impl FooBuilder {
+            }
+
+            impl ::std::convert::From<PartialFoo> for FooBuilder {
+                fn from(partial: PartialFoo) -> Self {
+                    FooBuilder {
+                        foo: partial.foo,
+                        bar: ::std::marker::PhantomData,
+                    }
+                }
+            }
+
+            impl ::std::convert::From<FooBuilder> for PartialFoo {
+                fn from(builder: FooBuilder) -> Self {
+                    PartialFoo {
+                        foo: builder.foo,
+                    }
+                }
+            }
+        ));
+    }
+
     #[test]
     fn generic() {
         let ast = syn::parse_macro_input(stringify!(
@@ -270,9 +715,10 @@ mod tests {
         let mut builder = default_builder!();
         builder.generics = Some(&generics);
         builder.pattern = BuilderPattern::Owned;
+        builder.must_derive_clone = false;
 
         assert_eq!(quote!(#builder), quote!(
-            #[derive(Default, Clone)]
+            #[derive(Default)]
             pub struct FooBuilder<'a, T: Debug> where T: PartialEq {
                 foo: u32,
             }
@@ -294,6 +740,31 @@ mod tests {
         assert_eq!(quote!(#builder), quote!());
     }
 
+    #[test]
+    fn new_fn() {
+        let mut builder = default_builder!();
+        builder.new_fn = true;
+
+        assert_eq!(quote!(#builder), quote!(
+            #[derive(Default, Clone)]
+            pub struct FooBuilder {
+                foo: u32,
+            }
+
+            #[allow(dead_code)]
+            impl FooBuilder {
+                /// Create an empty builder, with all fields set to `None`.
+                pub fn new() -> Self where Self: ::std::default::Default {
+                    ::std::default::Default::default()
+                }
+
+                fn bar () -> {
+                    unimplemented!()
+                }
+            }
+        ));
+    }
+
     #[test]
     fn add_derives() {
         let derives = vec![syn::Ident::new("Serialize")];
@@ -314,4 +785,189 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn setter_trait() {
+        let setter_trait = syn::parse_path("path::FooSetters").unwrap();
+        let field_ident = syn::Ident::new("foo");
+        let field_type = syn::parse_type("u32").unwrap();
+        let mut builder = default_builder!();
+        builder.setter_trait = Some(&setter_trait);
+        builder.push_setter_fn(Setter {
+            enabled: true,
+            try_setter: false,
+            try_setter_error: None,
+            try_into: false,
+            try_into_error: None,
+            visibility: &syn::Visibility::Public,
+            pattern: BuilderPattern::Mutable,
+            attrs: &[],
+            doc_comment: None,
+            ident: &field_ident,
+            aliases: &[],
+            field_ident: &field_ident,
+            field_type: &field_type,
+            generic_into: false,
+            with_plain: false,
+            into_ty: None,
+            impl_trait: false,
+            each: None,
+            into_iter: None,
+            as_ref: false,
+            strip_option: None,
+            option: None,
+            strip_bool: false,
+            once: None,
+            const_fn: false,
+            inline: false,
+            collection: None,
+            append: false,
+            deprecation_notes: &Default::default(),
+            deprecated: None,
+            bindings: Default::default(),
+        });
+
+        assert_eq!(quote!(#builder), quote!(
+            #[derive(Default, Clone)]
+            pub struct FooBuilder {
+                foo: u32,
+            }
+
+            #[allow(dead_code)]
+            impl FooBuilder {
+                fn bar () -> {
+                    unimplemented!()
+                }
+            }
+
+            impl path::FooSetters for FooBuilder {
+                fn foo(&mut self, value: u32) -> &mut Self {
+                    let mut new = self;
+                    new.foo = ::std::option::Option::Some(value);
+                    new
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn take_fn() {
+        let mut builder = default_builder!();
+        builder.functions.clear();
+        let foo = syn::Ident::new("foo");
+        let bar = syn::Ident::new("bar");
+        let ty = syn::parse_type("String").unwrap();
+        let vis = syn::Visibility::Public;
+        let attrs = vec![syn::parse_outer_attr("#[some_attr]").unwrap()];
+        builder.push_take_fn(BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &attrs,
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+        builder.push_take_fn(BuilderField {
+            field_ident: &bar,
+            field_type: &ty,
+            setter_enabled: false,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+
+        assert_eq!(quote!(#builder), quote!(
+            #[derive(Default, Clone)]
+            pub struct FooBuilder {
+                foo: u32,
+            }
+
+            #[allow(dead_code)]
+            impl FooBuilder {
+                #[some_attr]
+                pub fn take_foo(&mut self) -> ::std::option::Option<String> {
+                    ::std::option::Option::take(&mut self.foo)
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn fields_fn() {
+        let mut builder = default_builder!();
+        builder.functions.clear();
+        builder.push_fields_fn(FieldsFn {
+            enabled: true,
+            kind: FieldsFnKind::Merge,
+            visibility: &syn::Visibility::Inherited,
+            statements: vec![],
+            field_bounds: vec![],
+            bindings: Default::default(),
+        });
+        builder.push_fields_fn(FieldsFn {
+            enabled: false,
+            kind: FieldsFnKind::ToJson,
+            visibility: &syn::Visibility::Public,
+            statements: vec![],
+            field_bounds: vec![],
+            bindings: Default::default(),
+        });
+
+        assert_eq!(quote!(#builder), quote!(
+            #[derive(Default, Clone)]
+            pub struct FooBuilder {
+                foo: u32,
+            }
+
+            #[allow(dead_code)]
+            impl FooBuilder {
+                fn merge(&mut self, other: &Self) -> &mut Self {
+                    self
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn display_fn() {
+        let ast = syn::parse_macro_input(stringify!(
+            struct Lorem<T> where T: PartialEq { }
+        )).expect("Couldn't parse item");
+        let generics = ast.generics;
+        let mut builder = default_builder!();
+        builder.generics = Some(&generics);
+        builder.functions.clear();
+        builder.push_fields_fn(FieldsFn {
+            enabled: true,
+            kind: FieldsFnKind::Display,
+            visibility: &syn::Visibility::Public,
+            statements: vec![],
+            field_bounds: vec![],
+            bindings: Default::default(),
+        });
+        builder.display_bounds.push(quote!(T: ::std::fmt::Display));
+
+        assert_eq!(quote!(#builder), quote!(
+            #[derive(Default, Clone)]
+            pub struct FooBuilder<T> where T: PartialEq {
+                foo: u32,
+            }
+
+            #[allow(dead_code)]
+            impl<T: ::std::clone::Clone> FooBuilder<T> where T: PartialEq {
+            }
+
+            impl<T> ::std::fmt::Display for FooBuilder<T>
+                where T: PartialEq, T: ::std::fmt::Display
+            {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    ::std::result::Result::Ok(())
+                }
+            }
+        ));
+    }
 }
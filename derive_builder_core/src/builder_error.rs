@@ -0,0 +1,206 @@
+use quote::{Tokens, ToTokens};
+use syn;
+use Bindings;
+use doc_comment::doc_comment_from;
+
+/// Typed error of the build method, implementing `quote::ToTokens`.
+///
+/// Distinguishes uninitialized fields from failed validations. Validators
+/// returning `Result<_, String>` are converted via `From<String>`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::BuilderError;
+/// # fn main() {
+/// #    let error = default_builder_error!();
+/// #
+/// #    assert_eq!(quote!(#error), quote!(
+/// #[derive(Debug, Clone, PartialEq)]
+/// pub enum FooBuilderError {
+///     /// A required field has not been set.
+///     UninitializedField(&'static str),
+///     /// A validator rejected the value of the builder.
+///     ValidationError(::std::string::String),
+/// }
+///
+/// impl ::std::convert::From<::std::string::String> for FooBuilderError {
+///     fn from(error: ::std::string::String) -> Self {
+///         FooBuilderError::ValidationError(error)
+///     }
+/// }
+///
+/// impl ::std::fmt::Display for FooBuilderError {
+///     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+///         match *self {
+///             FooBuilderError::UninitializedField(field) => {
+///                 write!(f, "`{}` must be initialized", field)
+///             },
+///             FooBuilderError::ValidationError(ref error) => write!(f, "{}", error),
+///         }
+///     }
+/// }
+///
+/// impl ::std::error::Error for FooBuilderError {
+///     fn description(&self) -> &str {
+///         match *self {
+///             FooBuilderError::UninitializedField(_) => "uninitialized field",
+///             FooBuilderError::ValidationError(ref error) => error,
+///         }
+///     }
+/// }
+/// #    ));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderError<'a> {
+    /// Name of the error enum, e.g. `FooBuilderError`.
+    ///
+    /// No code is generated if this is `None`.
+    pub ident: Option<&'a syn::Ident>,
+    /// Visibility of the error enum, e.g. `syn::Visibility::Public`.
+    pub visibility: &'a syn::Visibility,
+    /// Doc-comment of the error enum.
+    pub doc_comment: Option<syn::Attribute>,
+    /// Bindings to libstd or libcore.
+    pub bindings: Bindings,
+}
+
+impl<'a> ToTokens for BuilderError<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        if let Some(ident) = self.ident {
+            trace!("Deriving builder error `{}`.", ident);
+            let vis = self.visibility;
+            let doc_comment = &self.doc_comment;
+            let string = self.bindings.string_ty();
+            let from = self.bindings.from_trait();
+            let display = self.bindings.display_trait();
+            let formatter = self.bindings.formatter_ty();
+            let fmt_result = self.bindings.fmt_result_ty();
+            // `std::error::Error` is not available in `core`
+            let error_impl = if self.bindings.no_std {
+                None
+            } else {
+                Some(quote!(
+                    impl ::std::error::Error for #ident {
+                        fn description(&self) -> &str {
+                            match *self {
+                                #ident::UninitializedField(_) => "uninitialized field",
+                                #ident::ValidationError(ref error) => error,
+                            }
+                        }
+                    }
+                ))
+            };
+
+            tokens.append(quote!(
+                #[derive(Debug, Clone, PartialEq)]
+                #doc_comment
+                #vis enum #ident {
+                    /// A required field has not been set.
+                    UninitializedField(&'static str),
+                    /// A validator rejected the value of the builder.
+                    ValidationError(#string),
+                }
+
+                impl #from<#string> for #ident {
+                    fn from(error: #string) -> Self {
+                        #ident::ValidationError(error)
+                    }
+                }
+
+                impl #display for #ident {
+                    fn fmt(&self, f: &mut #formatter) -> #fmt_result {
+                        match *self {
+                            #ident::UninitializedField(field) => {
+                                write!(f, "`{}` must be initialized", field)
+                            },
+                            #ident::ValidationError(ref error) => write!(f, "{}", error),
+                        }
+                    }
+                }
+
+                #error_impl
+            ));
+        } else {
+            trace!("Skipping builder error.");
+        }
+    }
+}
+
+impl<'a> BuilderError<'a> {
+    /// Set a doc-comment for this item.
+    pub fn doc_comment(&mut self, s: String) -> &mut Self {
+        self.doc_comment = Some(doc_comment_from(s));
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_error {
+    () => {
+        BuilderError {
+            ident: Some(&syn::Ident::new("FooBuilderError")),
+            visibility: &syn::Visibility::Public,
+            doc_comment: None,
+            bindings: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut error = default_builder_error!();
+        error.ident = None;
+
+        assert_eq!(quote!(#error), quote!());
+    }
+
+    #[test]
+    fn no_std() {
+        let mut error = default_builder_error!();
+        error.bindings.no_std = true;
+
+        assert_eq!(quote!(#error), quote!(
+            #[derive(Debug, Clone, PartialEq)]
+            pub enum FooBuilderError {
+                /// A required field has not been set.
+                UninitializedField(&'static str),
+                /// A validator rejected the value of the builder.
+                ValidationError(::collections::string::String),
+            }
+
+            impl ::core::convert::From<::collections::string::String> for FooBuilderError {
+                fn from(error: ::collections::string::String) -> Self {
+                    FooBuilderError::ValidationError(error)
+                }
+            }
+
+            impl ::core::fmt::Display for FooBuilderError {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    match *self {
+                        FooBuilderError::UninitializedField(field) => {
+                            write!(f, "`{}` must be initialized", field)
+                        },
+                        FooBuilderError::ValidationError(ref error) => write!(f, "{}", error),
+                    }
+                }
+            }
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 use quote::{Tokens, ToTokens};
 use syn;
 use Bindings;
+use Block;
 
 /// Field for the builder struct, implementing `quote::ToTokens`.
 ///
@@ -43,6 +44,13 @@ pub struct BuilderField<'a> {
     pub field_visibility: &'a syn::Visibility,
     /// Attributes which will be attached to this builder field.
     pub attrs: &'a [syn::Attribute],
+    /// Whether to attach `serde` attributes, so a serialized builder omits unset fields.
+    ///
+    /// Note: The builder must derive `Serialize` itself, e.g. via `#[builder(derive(..))]`.
+    pub serde: bool,
+    /// Initial value of the builder field in the manual `Default` impl of the
+    /// builder, instead of `None`, e.g. an empty collection.
+    pub initial_value: Option<&'a Block>,
     /// Bindings to libstd or libcore.
     pub bindings: Bindings,
 }
@@ -56,9 +64,14 @@ impl<'a> ToTokens for BuilderField<'a> {
             let ty = self.field_type;
             let attrs = self.attrs;
             let option = self.bindings.option_ty();
+            let serde_attr = if self.serde {
+                Some(quote!(#[serde(skip_serializing_if = "Option::is_none")]))
+            } else {
+                None
+            };
 
             tokens.append(quote!(
-                #(#attrs)* #vis #ident: #option<#ty>,
+                #(#attrs)* #serde_attr #vis #ident: #option<#ty>,
             ));
         } else {
             trace!("Skipping builder field for `{}`, fallback to PhantomData.",
@@ -67,9 +80,14 @@ impl<'a> ToTokens for BuilderField<'a> {
             let ty = self.field_type;
             let attrs = self.attrs;
             let phantom_data = self.bindings.phantom_data_ty();
+            let serde_attr = if self.serde {
+                Some(quote!(#[serde(skip)]))
+            } else {
+                None
+            };
 
             tokens.append(quote!(
-                #(#attrs)* #ident: #phantom_data<#ty>,
+                #(#attrs)* #serde_attr #ident: #phantom_data<#ty>,
             ));
         }
     }
@@ -87,6 +105,8 @@ macro_rules! default_builder_field {
             setter_enabled: true,
             field_visibility: &syn::Visibility::Public,
             attrs: &vec![syn::parse_outer_attr("#[some_attr]").unwrap()],
+            serde: false,
+            initial_value: None,
             bindings: Default::default(),
         }
     }
@@ -137,6 +157,24 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn serde() {
+        let mut field = default_builder_field!();
+        field.serde = true;
+
+        assert_eq!(quote!(#field), quote!(
+            #[some_attr]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub foo: ::std::option::Option<String>,
+        ));
+
+        field.setter_enabled = false;
+
+        assert_eq!(quote!(#field), quote!(
+            #[some_attr] #[serde(skip)] foo: ::std::marker::PhantomData<String>,
+        ));
+    }
+
     #[test]
     fn private_field() {
         let private = syn::Visibility::Inherited;
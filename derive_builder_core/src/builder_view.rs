@@ -0,0 +1,251 @@
+use quote::{Tokens, ToTokens};
+use syn;
+use Bindings;
+use BuilderField;
+use doc_comment::doc_comment_from;
+
+/// Name of the lifetime of the borrowed builder.
+const VIEW_LIFETIME: &'static str = "'view";
+
+/// Read-only view of a builder with borrowed fields and a method to create it,
+/// implementing `quote::ToTokens`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::BuilderView;
+/// # fn main() {
+/// #    let view = default_builder_view!();
+/// #
+/// #    assert_eq!(quote!(#view), quote!(
+/// #[derive(Clone, Copy)]
+/// pub struct FooBuilderView<'view> {
+///     pub foo: ::std::option::Option<&'view u32>,
+/// }
+///
+/// #[allow(dead_code)]
+/// impl FooBuilder {
+///     pub fn view<'view>(&'view self) -> FooBuilderView<'view> {
+///         FooBuilderView {
+///             foo: self.foo.as_ref(),
+///         }
+///     }
+/// }
+/// #    ));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BuilderView<'a> {
+    /// Enables code generation for the view struct and the `view` method.
+    pub enabled: bool,
+    /// Name of the view struct, e.g. `FooBuilderView`.
+    pub ident: &'a syn::Ident,
+    /// Name of the builder struct, e.g. `FooBuilder`.
+    pub builder_ident: &'a syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Visibility of the view struct, its fields and the `view` method.
+    pub visibility: &'a syn::Visibility,
+    /// Fields of the view struct, e.g. `pub foo: Option<&'view u32>,`
+    pub fields: Vec<Tokens>,
+    /// Initializers of the view fields, e.g. `foo: self.foo.as_ref(),`
+    pub initializers: Vec<Tokens>,
+    /// Doc-comment of the view struct.
+    pub doc_comment: Option<syn::Attribute>,
+    /// Bindings to libstd or libcore.
+    pub bindings: Bindings,
+}
+
+impl<'a> ToTokens for BuilderView<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        if self.enabled {
+            trace!("Deriving builder view `{}`.", self.ident);
+            let vis = self.visibility;
+            let ident = self.ident;
+            let builder_ident = self.builder_ident;
+            let lifetime = syn::Lifetime::new(VIEW_LIFETIME);
+            let mut view_generics = self.generics.cloned().unwrap_or_default();
+            view_generics.lifetimes.insert(0, syn::LifetimeDef::new(VIEW_LIFETIME));
+            let (view_struct_generics, view_ty_generics, _) = view_generics.split_for_impl();
+            let (impl_generics, ty_generics, where_clause) = self.generics
+                .map(syn::Generics::split_for_impl)
+                .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+                .unwrap_or((None, None, None));
+            let fields = &self.fields;
+            let initializers = &self.initializers;
+            let doc_comment = &self.doc_comment;
+
+            tokens.append(quote!(
+                #[derive(Clone, Copy)]
+                #doc_comment
+                #vis struct #ident #view_struct_generics #where_clause {
+                    #(#fields)*
+                }
+
+                #[allow(dead_code)]
+                impl #impl_generics #builder_ident #ty_generics #where_clause {
+                    #vis fn view<#lifetime>(&#lifetime self) -> #ident #view_ty_generics {
+                        #ident {
+                            #(#initializers)*
+                        }
+                    }
+                }
+            ));
+        } else {
+            trace!("Skipping builder view `{}`.", self.ident);
+        }
+    }
+}
+
+impl<'a> BuilderView<'a> {
+    /// Set a doc-comment for this item.
+    pub fn doc_comment(&mut self, s: String) -> &mut Self {
+        self.doc_comment = Some(doc_comment_from(s));
+        self
+    }
+
+    /// Add a borrowed counterpart of a builder field to the view.
+    ///
+    /// Fields without a setter fall back to `PhantomData`, just like on the
+    /// builder.
+    pub fn push_field(&mut self, f: BuilderField) -> &mut Self {
+        let vis = self.visibility;
+        let ident = f.field_ident;
+        let ty = f.field_type;
+        let attrs = f.attrs;
+        let lifetime = syn::Lifetime::new(VIEW_LIFETIME);
+
+        if f.setter_enabled {
+            let option = self.bindings.option_ty();
+            self.fields.push(quote!(
+                #(#attrs)* #vis #ident: #option<&#lifetime #ty>,
+            ));
+            self.initializers.push(quote!(
+                #ident: self.#ident.as_ref(),
+            ));
+        } else {
+            let phantom_data = self.bindings.phantom_data_ty();
+            self.fields.push(quote!(
+                #(#attrs)* #ident: #phantom_data<&#lifetime #ty>,
+            ));
+            self.initializers.push(quote!(
+                #ident: #phantom_data,
+            ));
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_builder_view {
+    () => {
+        BuilderView {
+            enabled: true,
+            ident: &syn::Ident::new("FooBuilderView"),
+            builder_ident: &syn::Ident::new("FooBuilder"),
+            generics: None,
+            visibility: &syn::Visibility::Public,
+            fields: vec![quote!(pub foo: ::std::option::Option<&'view u32>,)],
+            initializers: vec![quote!(foo: self.foo.as_ref(),)],
+            doc_comment: None,
+            bindings: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut view = default_builder_view!();
+        view.enabled = false;
+
+        assert_eq!(quote!(#view), quote!());
+    }
+
+    #[test]
+    fn generic() {
+        let ast = syn::parse_macro_input(stringify!(
+            struct Lorem<'a, T: Debug> where T: PartialEq { }
+        )).expect("Couldn't parse item");
+        let generics = ast.generics;
+        let mut view = default_builder_view!();
+        view.generics = Some(&generics);
+        view.fields.clear();
+        view.initializers.clear();
+
+        assert_eq!(quote!(#view), quote!(
+            #[derive(Clone, Copy)]
+            pub struct FooBuilderView<'view, 'a, T: Debug> where T: PartialEq {}
+
+            #[allow(dead_code)]
+            impl<'a, T: Debug> FooBuilder<'a, T> where T: PartialEq {
+                pub fn view<'view>(&'view self) -> FooBuilderView<'view, 'a, T> {
+                    FooBuilderView {}
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn push_field() {
+        let foo = syn::Ident::new("foo");
+        let bar = syn::Ident::new("bar");
+        let ty = syn::parse_type("u32").unwrap();
+        let vis = syn::Visibility::Public;
+        let mut view = default_builder_view!();
+        view.fields.clear();
+        view.initializers.clear();
+        view.push_field(BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+        view.push_field(BuilderField {
+            field_ident: &bar,
+            field_type: &ty,
+            setter_enabled: false,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+
+        assert_eq!(quote!(#view), quote!(
+            #[derive(Clone, Copy)]
+            pub struct FooBuilderView<'view> {
+                pub foo: ::std::option::Option<&'view u32>,
+                bar: ::std::marker::PhantomData<&'view u32>,
+            }
+
+            #[allow(dead_code)]
+            impl FooBuilder {
+                pub fn view<'view>(&'view self) -> FooBuilderView<'view> {
+                    FooBuilderView {
+                        foo: self.foo.as_ref(),
+                        bar: ::std::marker::PhantomData,
+                    }
+                }
+            }
+        ));
+    }
+}
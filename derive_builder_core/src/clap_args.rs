@@ -0,0 +1,227 @@
+use quote::{Tokens, ToTokens};
+use syn;
+use Bindings;
+use ident_name;
+use BuilderField;
+
+/// Interop with `clap`, implementing `quote::ToTokens`.
+///
+/// Generates `augment_args` to add one command-line argument per field to a
+/// `clap::Command` and `from_matches` to populate the builder from the parsed
+/// `clap::ArgMatches`. Values are converted via `FromStr`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::ClapArgs;
+/// # fn main() {
+/// #    let clap_args = default_clap_args!();
+/// #
+/// #    assert_eq!(quote!(#clap_args), quote!(
+/// #[allow(dead_code)]
+/// impl FooBuilder {
+///     pub fn augment_args(cmd: ::clap::Command) -> ::clap::Command {
+///         cmd
+///     }
+///
+///     pub fn from_matches(matches: &::clap::ArgMatches)
+///         -> ::std::result::Result<Self, ::std::string::String>
+///         where Self: ::std::default::Default
+///     {
+///         let mut builder: Self = ::std::default::Default::default();
+///         ::std::result::Result::Ok(builder)
+///     }
+/// }
+/// #    ));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ClapArgs<'a> {
+    /// Enables code generation for `augment_args` and `from_matches`.
+    pub enabled: bool,
+    /// Name of the builder struct.
+    pub builder_ident: &'a syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Visibility of the generated methods, e.g. `syn::Visibility::Public`.
+    pub visibility: &'a syn::Visibility,
+    /// Argument definitions, e.g. `.arg(::clap::Arg::new("foo").long("foo"))`.
+    pub args: Vec<Tokens>,
+    /// Statements copying a parsed argument into its builder field.
+    pub assignments: Vec<Tokens>,
+    /// `FromStr` bounds on the types of all fields, e.g. `u32: FromStr`.
+    pub field_bounds: Vec<Tokens>,
+    /// Bindings to libstd or libcore.
+    pub bindings: Bindings,
+}
+
+impl<'a> ToTokens for ClapArgs<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        if self.enabled {
+            trace!("Deriving `clap` interop for `{}`.", self.builder_ident);
+            let builder_ident = self.builder_ident;
+            let vis = self.visibility;
+            let (impl_generics, ty_generics, where_clause) = self.generics
+                .map(syn::Generics::split_for_impl)
+                .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+                .unwrap_or((None, None, None));
+            let args = &self.args;
+            let assignments = &self.assignments;
+            let field_bounds = &self.field_bounds;
+            let result = self.bindings.result_ty();
+            let string = self.bindings.string_ty();
+            let default = self.bindings.default_trait();
+
+            tokens.append(quote!(
+                #[allow(dead_code)]
+                impl #impl_generics #builder_ident #ty_generics #where_clause {
+                    #vis fn augment_args(cmd: ::clap::Command) -> ::clap::Command {
+                        cmd #(#args)*
+                    }
+
+                    #vis fn from_matches(matches: &::clap::ArgMatches) -> #result<Self, #string>
+                        where #(#field_bounds,)* Self: #default
+                    {
+                        let mut builder: Self = #default::default();
+                        #(#assignments)*
+                        #result::Ok(builder)
+                    }
+                }
+            ));
+        } else {
+            trace!("Skipping `clap` interop for `{}`.", self.builder_ident);
+        }
+    }
+}
+
+impl<'a> ClapArgs<'a> {
+    /// Add a command-line argument for a builder field.
+    ///
+    /// The argument is named after the field, with underscores replaced by
+    /// dashes, e.g. `--foo-bar` for `foo_bar`. Fields without a setter are
+    /// ignored.
+    pub fn push_field(&mut self, f: BuilderField<'a>) -> &mut Self {
+        if f.setter_enabled {
+            let ident = f.field_ident;
+            let ty = f.field_type;
+            let id = ident_name(ident);
+            let long = id.replace('_', "-");
+            let err = format!("Unable to parse value for `{}`", id);
+            let option = self.bindings.option_ty();
+            let result = self.bindings.result_ty();
+            let string = self.bindings.string_ty();
+            let from_str = self.bindings.from_str_trait();
+
+            self.args.push(quote!(
+                .arg(::clap::Arg::new(#id).long(#long))
+            ));
+            self.assignments.push(quote!(
+                if let #option::Some(value) = matches.get_one::<#string>(#id) {
+                    let parsed: #ty = match value.parse() {
+                        #result::Ok(parsed) => parsed,
+                        #result::Err(_) => return #result::Err(#string::from(#err)),
+                    };
+                    builder.#ident = #option::Some(parsed);
+                }
+            ));
+            self.field_bounds.push(quote!(#ty: #from_str));
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_clap_args {
+    () => {
+        ClapArgs {
+            enabled: true,
+            builder_ident: &syn::Ident::new("FooBuilder"),
+            generics: None,
+            visibility: &syn::Visibility::Public,
+            args: vec![],
+            assignments: vec![],
+            field_bounds: vec![],
+            bindings: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut clap_args = default_clap_args!();
+        clap_args.enabled = false;
+
+        assert_eq!(quote!(#clap_args), quote!());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo_bar");
+        let bar = syn::Ident::new("bar");
+        let ty = syn::parse_type("u32").unwrap();
+        let vis = syn::Visibility::Public;
+        let mut clap_args = default_clap_args!();
+        clap_args.push_field(BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+        clap_args.push_field(BuilderField {
+            field_ident: &bar,
+            field_type: &ty,
+            setter_enabled: false,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+
+        assert_eq!(quote!(#clap_args), quote!(
+            #[allow(dead_code)]
+            impl FooBuilder {
+                pub fn augment_args(cmd: ::clap::Command) -> ::clap::Command {
+                    cmd .arg(::clap::Arg::new("foo_bar").long("foo-bar"))
+                }
+
+                pub fn from_matches(matches: &::clap::ArgMatches)
+                    -> ::std::result::Result<Self, ::std::string::String>
+                    where u32: ::std::str::FromStr, Self: ::std::default::Default
+                {
+                    let mut builder: Self = ::std::default::Default::default();
+                    if let ::std::option::Option::Some(value) =
+                        matches.get_one::<::std::string::String>("foo_bar")
+                    {
+                        let parsed: u32 = match value.parse() {
+                            ::std::result::Result::Ok(parsed) => parsed,
+                            ::std::result::Result::Err(_) => return ::std::result::Result::Err(
+                                ::std::string::String::from("Unable to parse value for `foo_bar`")),
+                        };
+                        builder.foo_bar = ::std::option::Option::Some(parsed);
+                    }
+                    ::std::result::Result::Ok(builder)
+                }
+            }
+        ));
+    }
+}
@@ -57,6 +57,11 @@ impl DeprecationNotes {
         self.0.push(note)
     }
 
+    /// Whether there are no notes at all.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Extend this collection with all values from another collection.
     pub fn extend(&mut self, other: &DeprecationNotes) {
         for x in &other.0 {
@@ -68,6 +73,12 @@ impl DeprecationNotes {
     pub fn as_item(&self) -> DeprecationNotesAsItem {
         DeprecationNotesAsItem(self)
     }
+
+    /// Create a view of these deprecation notes that can annotate a fn, so
+    /// they are emitted to its callers.
+    pub fn as_attribute<'a>(&'a self) -> DeprecationNotesAsAttribute<'a> {
+        DeprecationNotesAsAttribute(self)
+    }
 }
 
 /// A view of `DeprecationNotes` that can be used in any context that accept
@@ -92,6 +103,26 @@ impl<'a> ToTokens for DeprecationNotesAsItem<'a> {
     }
 }
 
+/// A view of `DeprecationNotes` that can be used as an attribute of an item.
+///
+/// Expands to a single `#[deprecated]` attribute joining all notes, because
+/// the attribute may only appear once.
+#[derive(Debug)]
+pub struct DeprecationNotesAsAttribute<'a>(&'a DeprecationNotes);
+
+impl<'a> ToTokens for DeprecationNotesAsAttribute<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        let deprecation_notes = self.0;
+
+        if !deprecation_notes.0.is_empty() {
+            let note = deprecation_notes.0.join("; ");
+            tokens.append(quote!(
+                #[deprecated(note=#note)]
+            ))
+        }
+    }
+}
+
 #[test]
 fn deprecation_note() {
     let mut note = DeprecationNotes::default();
@@ -104,3 +135,14 @@ fn deprecation_note() {
         }
     ));
 }
+
+#[test]
+fn deprecation_note_as_attribute() {
+    let mut note = DeprecationNotes::default();
+    note.push("Some Warning".to_string());
+    note.push("Another Warning".to_string());
+    let attribute = note.as_attribute();
+    assert_eq!(quote!(#attribute), quote!(
+        #[deprecated(note="Some Warning; Another Warning")]
+    ));
+}
@@ -0,0 +1,189 @@
+use quote::{Tokens, ToTokens};
+use syn;
+use Bindings;
+use doc_comment::doc_comment_from;
+
+/// Sealed extension trait for custom setters, implementing `quote::ToTokens`.
+///
+/// The trait has no items of its own. It is only implemented by the builder
+/// and grants mutable access to it via its `AsMut` supertrait, so custom setters
+/// can be added with blanket impls of other traits, e.g.
+/// `impl<T: FooBuilderExt> MySetters for T`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::ExtTrait;
+/// # fn main() {
+/// #    let ext_trait = default_ext_trait!();
+/// #
+/// #    assert_eq!(quote!(#ext_trait), quote!(
+/// #[doc(hidden)]
+/// #[allow(non_snake_case)]
+/// mod __FooBuilderExt {
+///     pub trait Sealed {}
+/// }
+///
+/// impl __FooBuilderExt::Sealed for FooBuilder {}
+///
+/// impl ::std::convert::AsMut<FooBuilder> for FooBuilder {
+///     fn as_mut(&mut self) -> &mut FooBuilder {
+///         self
+///     }
+/// }
+///
+/// pub trait FooBuilderExt: __FooBuilderExt::Sealed + ::std::convert::AsMut<FooBuilder> {}
+///
+/// impl FooBuilderExt for FooBuilder {}
+/// #    ));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ExtTrait<'a> {
+    /// Enables code generation for the extension trait.
+    pub enabled: bool,
+    /// Name of the extension trait, e.g. `FooBuilderExt`.
+    pub ident: &'a syn::Ident,
+    /// Name of the builder struct, e.g. `FooBuilder`.
+    pub builder_ident: &'a syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Visibility of the extension trait.
+    pub visibility: &'a syn::Visibility,
+    /// Doc-comment of the extension trait.
+    pub doc_comment: Option<syn::Attribute>,
+    /// Bindings to libstd or libcore.
+    pub bindings: Bindings,
+}
+
+impl<'a> ToTokens for ExtTrait<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        if self.enabled {
+            trace!("Deriving extension trait `{}`.", self.ident);
+            let vis = self.visibility;
+            let ident = self.ident;
+            let sealed_mod = syn::Ident::new(format!("__{}", ident));
+            let builder_ident = self.builder_ident;
+            let (impl_generics, ty_generics, where_clause) = self.generics
+                .map(syn::Generics::split_for_impl)
+                .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+                .unwrap_or((None, None, None));
+            let doc_comment = &self.doc_comment;
+            let as_mut = self.bindings.as_mut_trait();
+
+            tokens.append(quote!(
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                mod #sealed_mod {
+                    pub trait Sealed {}
+                }
+
+                impl #impl_generics #sealed_mod::Sealed for #builder_ident #ty_generics
+                    #where_clause {}
+
+                impl #impl_generics #as_mut<#builder_ident #ty_generics>
+                    for #builder_ident #ty_generics #where_clause
+                {
+                    fn as_mut(&mut self) -> &mut #builder_ident #ty_generics {
+                        self
+                    }
+                }
+
+                #doc_comment
+                #vis trait #ident #impl_generics
+                    : #sealed_mod::Sealed + #as_mut<#builder_ident #ty_generics>
+                    #where_clause {}
+
+                impl #impl_generics #ident #ty_generics for #builder_ident #ty_generics
+                    #where_clause {}
+            ));
+        } else {
+            trace!("Skipping extension trait `{}`.", self.ident);
+        }
+    }
+}
+
+impl<'a> ExtTrait<'a> {
+    /// Set a doc-comment for this item.
+    pub fn doc_comment(&mut self, s: String) -> &mut Self {
+        self.doc_comment = Some(doc_comment_from(s));
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_ext_trait {
+    () => {
+        ExtTrait {
+            enabled: true,
+            ident: &syn::Ident::new("FooBuilderExt"),
+            builder_ident: &syn::Ident::new("FooBuilder"),
+            generics: None,
+            visibility: &syn::Visibility::Public,
+            doc_comment: None,
+            bindings: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut ext_trait = default_ext_trait!();
+        ext_trait.enabled = false;
+
+        assert_eq!(quote!(#ext_trait), quote!());
+    }
+
+    #[test]
+    fn generic() {
+        let ast = syn::parse_macro_input(stringify!(
+            struct Lorem<'a, T: Debug> where T: PartialEq { }
+        )).expect("Couldn't parse item");
+        let generics = ast.generics;
+        let mut ext_trait = default_ext_trait!();
+        ext_trait.generics = Some(&generics);
+        ext_trait.doc_comment("Lorem ipsum".to_string());
+
+        assert_eq!(quote!(#ext_trait), quote!(
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            mod __FooBuilderExt {
+                pub trait Sealed {}
+            }
+
+            impl<'a, T: Debug> __FooBuilderExt::Sealed for FooBuilder<'a, T>
+                where T: PartialEq {}
+
+            impl<'a, T: Debug> ::std::convert::AsMut<FooBuilder<'a, T> >
+                for FooBuilder<'a, T> where T: PartialEq
+            {
+                fn as_mut(&mut self) -> &mut FooBuilder<'a, T> {
+                    self
+                }
+            }
+
+            #[doc = r##"Lorem ipsum"##]
+            pub trait FooBuilderExt<'a, T: Debug>
+                : __FooBuilderExt::Sealed + ::std::convert::AsMut<FooBuilder<'a, T> >
+                where T: PartialEq {}
+
+            impl<'a, T: Debug> FooBuilderExt<'a, T> for FooBuilder<'a, T>
+                where T: PartialEq {}
+        ));
+    }
+}
@@ -0,0 +1,405 @@
+use quote::{Tokens, ToTokens};
+use syn;
+use Bindings;
+use ident_name;
+use BuilderField;
+
+/// Function of the builder visiting all fields with a setter, implementing
+/// `quote::ToTokens`.
+///
+/// Each field contributes one statement to the body and one bound on its type,
+/// e.g. `u32: Clone`. Add it to the builder via `Builder::push_fields_fn`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{BuilderField, FieldsFn, FieldsFnKind};
+/// # fn main() {
+/// #    let mut merge = default_fields_fn!();
+/// #    merge.push_field(default_builder_field!(), false);
+/// #
+/// #    assert_eq!(quote!(#merge), quote!(
+/// pub fn merge(&mut self, other: &Self) -> &mut Self where String: ::std::clone::Clone {
+///     #[some_attr]
+///     if let ::std::option::Option::Some(ref value) = other.foo {
+///         self.foo = ::std::option::Option::Some(::std::clone::Clone::clone(value));
+///     }
+///     self
+/// }
+/// #    ));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FieldsFn<'a> {
+    /// Enables code generation for this function.
+    pub enabled: bool,
+    /// Which function to generate.
+    pub kind: FieldsFnKind<'a>,
+    /// Visibility of the function, e.g. `syn::Visibility::Public`.
+    ///
+    /// Ignored by `FieldsFnKind::Display`, which implements a trait method.
+    pub visibility: &'a syn::Visibility,
+    /// One statement per field with a setter.
+    pub statements: Vec<Tokens>,
+    /// Bounds on the types of all visited fields, e.g. `u32: Clone`.
+    pub field_bounds: Vec<Tokens>,
+    /// Bindings to libstd or libcore.
+    pub bindings: Bindings,
+}
+
+/// Kind of a `FieldsFn`.
+#[derive(Debug, Clone, Copy)]
+pub enum FieldsFnKind<'a> {
+    /// `merge(&mut self, other: &Self)`, copying all fields set on `other`.
+    Merge,
+    /// `apply(&self, target: T)`, assigning all fields set on the builder to
+    /// the target of the given type, e.g. `&mut Foo`.
+    Apply(&'a syn::Ty),
+    /// `changed_from(&self, base: &Foo)`, listing the names of all fields set
+    /// to a value different from the given type, e.g. `Foo<T>`.
+    ChangedFrom(&'a syn::Ty),
+    /// `to_json(&self)`, serializing all fields which have been set, failing if
+    /// any of them fails to serialize.
+    ToJson,
+    /// `Display::fmt`, printing one line per field.
+    Display,
+}
+
+impl<'a> ToTokens for FieldsFn<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        if !self.enabled {
+            trace!("Skipping fields fn `{:?}`.", self.kind);
+            return;
+        }
+
+        trace!("Deriving fields fn `{:?}`.", self.kind);
+        let vis = self.visibility;
+        let statements = &self.statements;
+        let field_bounds = if self.field_bounds.is_empty() {
+            None
+        } else {
+            let field_bounds = &self.field_bounds;
+            Some(quote!(where #(#field_bounds),*))
+        };
+
+        tokens.append(match self.kind {
+            FieldsFnKind::Merge => quote!(
+                #vis fn merge(&mut self, other: &Self) -> &mut Self #field_bounds {
+                    #(#statements)*
+                    self
+                }
+            ),
+            FieldsFnKind::Apply(target_ty) => quote!(
+                #vis fn apply(&self, target: #target_ty) #field_bounds {
+                    #(#statements)*
+                }
+            ),
+            FieldsFnKind::ChangedFrom(base_ty) => {
+                let vec = self.bindings.vec_ty();
+                quote!(
+                    #[allow(unused_mut)]
+                    #vis fn changed_from(&self, base: &#base_ty) -> #vec<&'static str>
+                        #field_bounds
+                    {
+                        let mut changed = #vec::new();
+                        #(#statements)*
+                        changed
+                    }
+                )
+            },
+            FieldsFnKind::ToJson => {
+                let result = self.bindings.result_ty();
+                quote!(
+                    #[allow(unused_mut)]
+                    #vis fn to_json(&self)
+                        -> #result<::serde_json::Value, ::serde_json::Error> #field_bounds
+                    {
+                        let mut map = ::serde_json::Map::new();
+                        #(#statements)*
+                        #result::Ok(::serde_json::Value::Object(map))
+                    }
+                )
+            },
+            FieldsFnKind::Display => {
+                // the bounds go into the where clause of the impl instead
+                let formatter = self.bindings.formatter_ty();
+                let fmt_result = self.bindings.fmt_result_ty();
+                let result = self.bindings.result_ty();
+                quote!(
+                    fn fmt(&self, f: &mut #formatter) -> #fmt_result {
+                        #(#statements)*
+                        #result::Ok(())
+                    }
+                )
+            },
+        });
+    }
+}
+
+impl<'a> FieldsFn<'a> {
+    /// Visit a builder field.
+    ///
+    /// `has_default` marks fields which fall back to a default value, which
+    /// `Display` prints as `<default>` instead of `<unset>`. Fields without a
+    /// setter are skipped.
+    pub fn push_field(&mut self, f: BuilderField, has_default: bool) -> &mut Self {
+        if !f.setter_enabled {
+            return self;
+        }
+
+        let ident = f.field_ident;
+        let ty = f.field_type;
+        let name = ident_name(ident);
+        // doc comments are not allowed on statements
+        let attrs = f.attrs.iter().filter(|a| a.value.name() != "doc");
+        let option = self.bindings.option_ty();
+
+        let (statement, bound) = match self.kind {
+            FieldsFnKind::Merge => {
+                let clone = self.bindings.clone_trait();
+                (quote!(
+                    if let #option::Some(ref value) = other.#ident {
+                        self.#ident = #option::Some(#clone::clone(value));
+                    }
+                ), quote!(#ty: #clone))
+            },
+            FieldsFnKind::Apply(_) => {
+                let clone = self.bindings.clone_trait();
+                (quote!(
+                    if let #option::Some(ref value) = self.#ident {
+                        target.#ident = #clone::clone(value);
+                    }
+                ), quote!(#ty: #clone))
+            },
+            FieldsFnKind::ChangedFrom(_) => {
+                let partial_eq = self.bindings.partial_eq_trait();
+                (quote!(
+                    if let #option::Some(ref value) = self.#ident {
+                        if value != &base.#ident {
+                            changed.push(#name);
+                        }
+                    }
+                ), quote!(#ty: #partial_eq))
+            },
+            FieldsFnKind::ToJson => {
+                let string = self.bindings.string_ty();
+                (quote!(
+                    if let #option::Some(ref value) = self.#ident {
+                        map.insert(#string::from(#name), ::serde_json::to_value(value)?);
+                    }
+                ), quote!(#ty: ::serde::Serialize))
+            },
+            FieldsFnKind::Display => {
+                let display = self.bindings.display_trait();
+                let set = format!("{}: {{}}", name);
+                let unset = format!("{}: {}",
+                                    name,
+                                    if has_default { "<default>" } else { "<unset>" });
+                (quote!(
+                    match self.#ident {
+                        #option::Some(ref value) => writeln!(f, #set, value)?,
+                        #option::None => writeln!(f, #unset)?,
+                    }
+                ), quote!(#ty: #display))
+            },
+        };
+
+        self.statements.push(quote!(#(#attrs)* #statement));
+        self.field_bounds.push(bound);
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_fields_fn {
+    () => {
+        FieldsFn {
+            enabled: true,
+            kind: FieldsFnKind::Merge,
+            visibility: &syn::Visibility::Public,
+            statements: vec![],
+            field_bounds: vec![],
+            bindings: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut merge = default_fields_fn!();
+        merge.enabled = false;
+
+        assert_eq!(quote!(#merge), quote!());
+    }
+
+    #[test]
+    fn skipped_field() {
+        let mut merge = default_fields_fn!();
+        let foo = syn::Ident::new("foo");
+        let ty = syn::parse_type("String").unwrap();
+        let vis = syn::Visibility::Public;
+        let field = BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: false,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        };
+        merge.push_field(field, false);
+
+        assert_eq!(quote!(#merge), quote!(
+            pub fn merge(&mut self, other: &Self) -> &mut Self {
+                self
+            }
+        ));
+    }
+
+    #[test]
+    fn apply() {
+        let target_ty = syn::parse_type("&mut Foo").unwrap();
+        let mut apply = default_fields_fn!();
+        apply.kind = FieldsFnKind::Apply(&target_ty);
+        let foo = syn::Ident::new("foo");
+        let ty = syn::parse_type("String").unwrap();
+        let vis = syn::Visibility::Public;
+        let field = BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        };
+        apply.push_field(field, false);
+
+        assert_eq!(quote!(#apply), quote!(
+            pub fn apply(&self, target: &mut Foo) where String: ::std::clone::Clone {
+                if let ::std::option::Option::Some(ref value) = self.foo {
+                    target.foo = ::std::clone::Clone::clone(value);
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn changed_from() {
+        let base_ty = syn::parse_type("Foo").unwrap();
+        let mut diff = default_fields_fn!();
+        diff.kind = FieldsFnKind::ChangedFrom(&base_ty);
+        let foo = syn::Ident::new("foo");
+        let ty = syn::parse_type("String").unwrap();
+        let vis = syn::Visibility::Public;
+        let field = BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        };
+        diff.push_field(field, false);
+
+        assert_eq!(quote!(#diff), quote!(
+            #[allow(unused_mut)]
+            pub fn changed_from(&self, base: &Foo) -> ::std::vec::Vec<&'static str>
+                where String: ::std::cmp::PartialEq
+            {
+                let mut changed = ::std::vec::Vec::new();
+                if let ::std::option::Option::Some(ref value) = self.foo {
+                    if value != &base.foo {
+                        changed.push("foo");
+                    }
+                }
+                changed
+            }
+        ));
+    }
+
+    #[test]
+    fn to_json() {
+        let mut json = default_fields_fn!();
+        json.kind = FieldsFnKind::ToJson;
+        let foo = syn::Ident::new("foo");
+        let ty = syn::parse_type("String").unwrap();
+        let vis = syn::Visibility::Public;
+        let field = BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        };
+        json.push_field(field, false);
+
+        assert_eq!(quote!(#json), quote!(
+            #[allow(unused_mut)]
+            pub fn to_json(&self)
+                -> ::std::result::Result<::serde_json::Value, ::serde_json::Error>
+                where String: ::serde::Serialize
+            {
+                let mut map = ::serde_json::Map::new();
+                if let ::std::option::Option::Some(ref value) = self.foo {
+                    map.insert(::std::string::String::from("foo"),
+                               ::serde_json::to_value(value)?);
+                }
+                ::std::result::Result::Ok(::serde_json::Value::Object(map))
+            }
+        ));
+    }
+
+    #[test]
+    fn display() {
+        let mut display = default_fields_fn!();
+        display.kind = FieldsFnKind::Display;
+        let foo = syn::Ident::new("foo");
+        let ty = syn::parse_type("String").unwrap();
+        let vis = syn::Visibility::Public;
+        let field = BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        };
+        display.push_field(field, true);
+
+        assert_eq!(quote!(#display), quote!(
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                match self.foo {
+                    ::std::option::Option::Some(ref value) => writeln!(f, "foo: {}", value)?,
+                    ::std::option::Option::None => writeln!(f, "foo: <default>")?,
+                }
+                ::std::result::Result::Ok(())
+            }
+        ));
+        assert_eq!(display.field_bounds, vec![quote!(String: ::std::fmt::Display)]);
+    }
+}
@@ -0,0 +1,283 @@
+use quote::{Tokens, ToTokens};
+use syn;
+use Bindings;
+use ident_name;
+use BuilderField;
+
+/// Constructor from key-value pairs for the builder struct, implementing
+/// `quote::ToTokens`.
+///
+/// Each key is dispatched to the builder field of the same name; the value is
+/// converted via `FromStr`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::FromPairs;
+/// # fn main() {
+/// #    let from_pairs = default_from_pairs!();
+/// #
+/// #    assert_eq!(quote!(#from_pairs), quote!(
+/// #[allow(dead_code)]
+/// impl FooBuilder {
+///     pub fn from_pairs<I, K, V>(pairs: I)
+///         -> ::std::result::Result<Self, ::std::string::String>
+///         where I: ::std::iter::IntoIterator<Item = (K, V)>,
+///               K: ::std::convert::AsRef<str>,
+///               V: ::std::convert::AsRef<str>,
+///               Self: ::std::default::Default
+///     {
+///         let mut builder: Self = ::std::default::Default::default();
+///         for (key, value) in pairs {
+///             let value = value.as_ref();
+///             match key.as_ref() {
+///                 _ => {}
+///             }
+///         }
+///         ::std::result::Result::Ok(builder)
+///     }
+/// }
+///
+/// impl ::std::iter::FromIterator<(::std::string::String, ::std::string::String)> for FooBuilder
+///     where Self: ::std::default::Default
+/// {
+///     fn from_iter<I>(pairs: I) -> Self
+///         where I: ::std::iter::IntoIterator<
+///             Item = (::std::string::String, ::std::string::String)>
+///     {
+///         match Self::from_pairs(pairs) {
+///             ::std::result::Result::Ok(builder) => builder,
+///             ::std::result::Result::Err(err) => panic!("{}", err),
+///         }
+///     }
+/// }
+/// #    ));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FromPairs<'a> {
+    /// Enables code generation for `from_pairs` and the `FromIterator` impl.
+    pub enabled: bool,
+    /// Name of the builder struct.
+    pub builder_ident: &'a syn::Ident,
+    /// Type parameters and lifetimes attached to the builder struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Visibility of the `from_pairs` method, e.g. `syn::Visibility::Public`.
+    pub visibility: &'a syn::Visibility,
+    /// Whether keys without a matching field are an error or silently ignored.
+    pub deny_unknown: bool,
+    /// Match arms dispatching a key to its field, e.g. `"foo" => { .. }`.
+    pub arms: Vec<Tokens>,
+    /// `FromStr` bounds on the types of all dispatched fields, e.g. `u32: FromStr`.
+    pub field_bounds: Vec<Tokens>,
+    /// Bindings to libstd or libcore.
+    pub bindings: Bindings,
+}
+
+impl<'a> ToTokens for FromPairs<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        if self.enabled {
+            trace!("Deriving `from_pairs` for `{}`.", self.builder_ident);
+            let builder_ident = self.builder_ident;
+            let vis = self.visibility;
+            let (impl_generics, ty_generics, where_clause) = self.generics
+                .map(syn::Generics::split_for_impl)
+                .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+                .unwrap_or((None, None, None));
+            let predicates = self.generics
+                .map(|g| g.where_clause.predicates.as_slice())
+                .unwrap_or(&[]);
+            let arms = &self.arms;
+            let field_bounds = &self.field_bounds;
+            let result = self.bindings.result_ty();
+            let string = self.bindings.string_ty();
+            let default = self.bindings.default_trait();
+            let as_ref = self.bindings.as_ref_trait();
+            let into_iter = self.bindings.into_iterator_trait();
+            let from_iter = self.bindings.from_iterator_trait();
+
+            let unknown = if self.deny_unknown {
+                quote!(key => return #result::Err(
+                    #string::from("Unknown key `") + key + "`"))
+            } else {
+                quote!(_ => {})
+            };
+
+            tokens.append(quote!(
+                #[allow(dead_code)]
+                impl #impl_generics #builder_ident #ty_generics #where_clause {
+                    #vis fn from_pairs<I, K, V>(pairs: I) -> #result<Self, #string>
+                        where I: #into_iter<Item = (K, V)>,
+                              K: #as_ref<str>,
+                              V: #as_ref<str>,
+                              #(#field_bounds,)*
+                              Self: #default
+                    {
+                        let mut builder: Self = #default::default();
+                        for (key, value) in pairs {
+                            let value = value.as_ref();
+                            match key.as_ref() {
+                                #(#arms)*
+                                #unknown
+                            }
+                        }
+                        #result::Ok(builder)
+                    }
+                }
+
+                impl #impl_generics #from_iter<(#string, #string)> for #builder_ident #ty_generics
+                    where #(#predicates,)* #(#field_bounds,)* Self: #default
+                {
+                    fn from_iter<I>(pairs: I) -> Self
+                        where I: #into_iter<Item = (#string, #string)>
+                    {
+                        match Self::from_pairs(pairs) {
+                            #result::Ok(builder) => builder,
+                            #result::Err(err) => panic!("{}", err),
+                        }
+                    }
+                }
+            ));
+        } else {
+            trace!("Skipping `from_pairs` for `{}`.", self.builder_ident);
+        }
+    }
+}
+
+impl<'a> FromPairs<'a> {
+    /// Dispatch the key named after a builder field to that field.
+    ///
+    /// Fields without a setter are ignored, i.e. treated as unknown keys.
+    pub fn push_field(&mut self, f: BuilderField<'a>) -> &mut Self {
+        if f.setter_enabled {
+            let ident = f.field_ident;
+            let ty = f.field_type;
+            let key = ident_name(ident);
+            let err = format!("Unable to parse value for `{}`", key);
+            let option = self.bindings.option_ty();
+            let result = self.bindings.result_ty();
+            let string = self.bindings.string_ty();
+            let from_str = self.bindings.from_str_trait();
+
+            self.arms.push(quote!(
+                #key => {
+                    let parsed: #ty = match value.parse() {
+                        #result::Ok(parsed) => parsed,
+                        #result::Err(_) => return #result::Err(#string::from(#err)),
+                    };
+                    builder.#ident = #option::Some(parsed);
+                }
+            ));
+            self.field_bounds.push(quote!(#ty: #from_str));
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_from_pairs {
+    () => {
+        FromPairs {
+            enabled: true,
+            builder_ident: &syn::Ident::new("FooBuilder"),
+            generics: None,
+            visibility: &syn::Visibility::Public,
+            deny_unknown: false,
+            arms: vec![],
+            field_bounds: vec![],
+            bindings: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut from_pairs = default_from_pairs!();
+        from_pairs.enabled = false;
+
+        assert_eq!(quote!(#from_pairs), quote!());
+    }
+
+    #[test]
+    fn fields() {
+        let ident = syn::Ident::new("foo");
+        let ty = syn::parse_type("u32").unwrap();
+        let vis = syn::Visibility::Public;
+        let mut from_pairs = default_from_pairs!();
+        from_pairs.deny_unknown = true;
+        from_pairs.push_field(BuilderField {
+            field_ident: &ident,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+
+        assert_eq!(quote!(#from_pairs), quote!(
+            #[allow(dead_code)]
+            impl FooBuilder {
+                pub fn from_pairs<I, K, V>(pairs: I)
+                    -> ::std::result::Result<Self, ::std::string::String>
+                    where I: ::std::iter::IntoIterator<Item = (K, V)>,
+                          K: ::std::convert::AsRef<str>,
+                          V: ::std::convert::AsRef<str>,
+                          u32: ::std::str::FromStr,
+                          Self: ::std::default::Default
+                {
+                    let mut builder: Self = ::std::default::Default::default();
+                    for (key, value) in pairs {
+                        let value = value.as_ref();
+                        match key.as_ref() {
+                            "foo" => {
+                                let parsed: u32 = match value.parse() {
+                                    ::std::result::Result::Ok(parsed) => parsed,
+                                    ::std::result::Result::Err(_) => return
+                                        ::std::result::Result::Err(
+                                            ::std::string::String::from(
+                                                "Unable to parse value for `foo`")),
+                                };
+                                builder.foo = ::std::option::Option::Some(parsed);
+                            }
+                            key => return ::std::result::Result::Err(
+                                ::std::string::String::from("Unknown key `") + key + "`")
+                        }
+                    }
+                    ::std::result::Result::Ok(builder)
+                }
+            }
+
+            impl ::std::iter::FromIterator<(::std::string::String, ::std::string::String)>
+                for FooBuilder
+                where u32: ::std::str::FromStr, Self: ::std::default::Default
+            {
+                fn from_iter<I>(pairs: I) -> Self
+                    where I: ::std::iter::IntoIterator<
+                        Item = (::std::string::String, ::std::string::String)>
+                {
+                    match Self::from_pairs(pairs) {
+                        ::std::result::Result::Ok(builder) => builder,
+                        ::std::result::Result::Err(err) => panic!("{}", err),
+                    }
+                }
+            }
+        ));
+    }
+}
@@ -0,0 +1,178 @@
+use quote::{Tokens, ToTokens};
+use syn;
+use Bindings;
+use ident_name;
+use doc_comment::doc_comment_from;
+
+/// Fallible getter for a required field of the builder, implementing
+/// `quote::ToTokens`.
+///
+/// Fails with the same error as the build method if the field has not been set.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::Getter;
+/// # fn main() {
+/// #     let getter = default_getter!();
+/// #
+/// #     assert_eq!(quote!(#getter), quote!(
+/// pub fn get_foo(&self) -> ::std::result::Result<&Foo, ::std::string::String> {
+///     match self.foo {
+///         ::std::option::Option::Some(ref value) => ::std::result::Result::Ok(value),
+///         ::std::option::Option::None => ::std::result::Result::Err(
+///             ::std::string::String::from("`foo` must be initialized")),
+///     }
+/// }
+/// #     ));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Getter<'a> {
+    /// Enables code generation for this getter fn.
+    pub enabled: bool,
+    /// Visibility of the getter, e.g. `syn::Visibility::Public`.
+    pub visibility: &'a syn::Visibility,
+    /// Attributes which will be attached to this getter fn, except doc-comments.
+    pub attrs: &'a [syn::Attribute],
+    /// Doc-comment of this getter fn.
+    pub doc_comment: Option<syn::Attribute>,
+    /// Name of the target field.
+    pub field_ident: &'a syn::Ident,
+    /// Type of the target field.
+    pub field_type: &'a syn::Ty,
+    /// Typed error of the build method, e.g. `FooBuilderError`.
+    ///
+    /// The getter fails with a `String` if this is `None`.
+    pub error_ty: Option<&'a syn::Ident>,
+    /// Bindings to libstd or libcore.
+    pub bindings: Bindings,
+}
+
+impl<'a> ToTokens for Getter<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        if self.enabled {
+            trace!("Deriving getter for `{}`.", self.field_ident);
+            let vis = self.visibility;
+            let field_ident = self.field_ident;
+            let ty = self.field_type;
+            let name = ident_name(field_ident);
+            let ident = syn::Ident::new(format!("get_{}", name));
+            let attrs = self.attrs.iter().filter(|a| a.value.name() != "doc");
+            let doc_comment = &self.doc_comment;
+            let option = self.bindings.option_ty();
+            let result = self.bindings.result_ty();
+
+            let (error_ty, error) = match self.error_ty {
+                Some(error_ty) => (quote!(#error_ty), quote!(#error_ty::UninitializedField(#name))),
+                None => {
+                    let string = self.bindings.string_ty();
+                    let msg = format!("`{}` must be initialized", name);
+                    (quote!(#string), quote!(#string::from(#msg)))
+                },
+            };
+
+            tokens.append(quote!(
+                #(#attrs)*
+                #doc_comment
+                #vis fn #ident(&self) -> #result<&#ty, #error_ty> {
+                    match self.#field_ident {
+                        #option::Some(ref value) => #result::Ok(value),
+                        #option::None => #result::Err(#error),
+                    }
+                }
+            ));
+        } else {
+            trace!("Skipping getter for `{}`.", self.field_ident);
+        }
+    }
+}
+
+impl<'a> Getter<'a> {
+    /// Set a doc-comment for this item.
+    pub fn doc_comment(&mut self, s: String) -> &mut Self {
+        self.doc_comment = Some(doc_comment_from(s));
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_getter {
+    () => {
+        Getter {
+            enabled: true,
+            visibility: &syn::Visibility::Public,
+            attrs: &vec![],
+            doc_comment: None,
+            field_ident: &syn::Ident::new("foo"),
+            field_type: &syn::parse_type("Foo").unwrap(),
+            error_ty: None,
+            bindings: Default::default(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut getter = default_getter!();
+        getter.enabled = false;
+
+        assert_eq!(quote!(#getter), quote!());
+    }
+
+    #[test]
+    fn typed_error() {
+        let error_ty = syn::Ident::new("FooBuilderError");
+        let mut getter = default_getter!();
+        getter.error_ty = Some(&error_ty);
+
+        assert_eq!(quote!(#getter), quote!(
+            pub fn get_foo(&self) -> ::std::result::Result<&Foo, FooBuilderError> {
+                match self.foo {
+                    ::std::option::Option::Some(ref value) => ::std::result::Result::Ok(value),
+                    ::std::option::Option::None => ::std::result::Result::Err(
+                        FooBuilderError::UninitializedField("foo")),
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn doc_comment() {
+        let attrs = vec![
+            doc_comment_from("Field docs.".to_string()),
+            syn::parse_outer_attr("#[cfg(unix)]").unwrap(),
+        ];
+        let mut getter = default_getter!();
+        getter.attrs = &attrs;
+        getter.doc_comment("Returns `foo`.".to_string());
+        let doc = doc_comment_from("Returns `foo`.".to_string());
+
+        assert_eq!(quote!(#getter), quote!(
+            #[cfg(unix)]
+            #doc
+            pub fn get_foo(&self) -> ::std::result::Result<&Foo, ::std::string::String> {
+                match self.foo {
+                    ::std::option::Option::Some(ref value) => ::std::result::Result::Ok(value),
+                    ::std::option::Option::None => ::std::result::Result::Err(
+                        ::std::string::String::from("`foo` must be initialized")),
+                }
+            }
+        ));
+    }
+}
@@ -1,8 +1,11 @@
 use quote::{Tokens, ToTokens};
 use syn;
 use BuilderPattern;
+use Charset;
 use Block;
 use Bindings;
+use RawTokens;
+use ident_name;
 use DEFAULT_STRUCT_NAME;
 
 /// Initializer for the target struct fields, implementing `quote::ToTokens`.
@@ -37,16 +40,61 @@ use DEFAULT_STRUCT_NAME;
 pub struct Initializer<'a> {
     /// Name of the target field.
     pub field_ident: &'a syn::Ident,
+    /// Type of the target field.
+    pub field_type: &'a syn::Ty,
     /// Whether the builder implements a setter for this field.
     pub setter_enabled: bool,
     /// How the build method takes and returns `self` (e.g. mutably).
     pub builder_pattern: BuilderPattern,
     /// Default value for the target field.
     ///
-    /// This takes precedence over a default struct identifier.
+    /// This takes precedence over a default struct identifier. A block ending
+    /// with `?` is evaluated in a closure returning the error type of the
+    /// build method, see `fallible_default`.
     pub default_value: Option<Block>,
     /// Whether the build_method defines a default struct.
     pub use_default_struct: bool,
+    /// Fall back to `None` if the field type is an `Option` and neither a
+    /// default value nor a default struct applies.
+    pub option_as_optional: bool,
+    /// Fields whose initializers must be evaluated before this one.
+    pub depends_on: &'a [syn::Ident],
+    /// Move the value out of the builder via `Option::take`, instead of cloning it.
+    ///
+    /// Everything which may fail is evaluated up front by `unset_value`, so
+    /// a failed build leaves the builder untouched. Requires the build method
+    /// to take `&mut self`.
+    pub take_from_builder: bool,
+    /// Initial value of the builder field, which is restored after moving the
+    /// value out of the builder, see `take_from_builder`.
+    pub initial_value: Option<&'a Block>,
+    /// Whether the build method checks all required fields up front, see
+    /// `missing_field_check`.
+    ///
+    /// The initializer then doesn't return an error itself.
+    pub checked_by_build_fn: bool,
+    /// Characters the value must consist of, if it has been set, see
+    /// `charset_check`.
+    pub charset: Option<Charset>,
+    /// Validator with signature `&T -> Result<(), String>` for the value, if it
+    /// has been set, see `validation_check`.
+    pub validate_fn: Option<&'a syn::Path>,
+    /// Reject a value equal to `Default::default()` of the field type, e.g. an
+    /// empty `String` which must be provided.
+    ///
+    /// Requires the field type to implement `Default` and `PartialEq`.
+    pub forbid_default: bool,
+    /// Typed error of the build method, e.g. `FooBuilderError`, see
+    /// `BuilderError`.
+    ///
+    /// Uninitialized fields then return its `UninitializedField` variant,
+    /// other errors its `ValidationError` variant instead of a `String`.
+    pub error_ty: Option<&'a syn::Ident>,
+    /// Attributes of the field, e.g. `#[cfg(feature = "foo")]`.
+    ///
+    /// Only `cfg` attributes are forwarded, so the initializer is compiled if
+    /// and only if the field exists.
+    pub attrs: &'a [syn::Attribute],
     /// Bindings to libstd or libcore.
     pub bindings: Bindings,
 }
@@ -56,21 +104,42 @@ impl<'a> ToTokens for Initializer<'a> {
         trace!("Deriving initializer for `{}`.", self.field_ident);
 
         let struct_field = &self.field_ident;
+        let cfg_attrs = self.cfg_attrs();
 
-        if self.setter_enabled {
+        let value = if self.take_from_builder {
+            self.take_value()
+        } else if self.setter_enabled {
             let match_some = self.match_some();
             let match_none = self.match_none();
             let builder_field = &*struct_field;
-            tokens.append(quote!(
-                #struct_field: match self.#builder_field {
+            quote!(
+                match self.#builder_field {
                     #match_some,
                     #match_none,
+                }
+            )
+        } else {
+            self.default()
+        };
+
+        if self.forbid_default && !self.take_from_builder {
+            let ty = self.field_type;
+            let default = self.bindings.default_trait();
+            let reject = self.forbid_default_reject();
+            tokens.append(quote!(
+                #(#cfg_attrs)*
+                #struct_field: {
+                    let value: #ty = #value;
+                    if value == <#ty as #default>::default() {
+                        #reject
+                    }
+                    value
                 },
             ));
         } else {
-            let default = self.default();
             tokens.append(quote!(
-                #struct_field: #default,
+                #(#cfg_attrs)*
+                #struct_field: #value,
             ));
         }
     }
@@ -92,26 +161,330 @@ impl<'a> Initializer<'a> {
         }
     }
 
+    /// Value of the field, moved out of the builder, see `take_from_builder`.
+    ///
+    /// The builder field is reset to its initial value. Requires `unset_value`
+    /// to be in scope.
+    fn take_value(&self) -> Tokens {
+        let local = self.unset_value_ident();
+        if !self.setter_enabled {
+            return quote!(#local);
+        }
+
+        let builder_field = self.field_ident;
+        let option = self.bindings.option_ty();
+        let take = match self.initial_value {
+            Some(initial_value) => quote!(#option::replace(&mut self.#builder_field, #initial_value)),
+            None => quote!(#option::take(&mut self.#builder_field)),
+        };
+        let err = format!("`{}` has been checked before moving any value",
+                          ident_name(self.field_ident));
+        quote!(
+            match #option::or(#take, #local) {
+                #option::Some(value) => value,
+                #option::None => unreachable!(#err),
+            }
+        )
+    }
+
+    /// Statement evaluating everything which may fail to build this field,
+    /// before any value is moved out of the builder, see `take_from_builder`.
+    ///
+    /// That is the value of an unset field, e.g. its default value, or the
+    /// error if it is required, and `forbid_default`. Returns `None` unless
+    /// `take_from_builder` is enabled.
+    pub fn unset_value(&self) -> Option<Tokens> {
+        if !self.take_from_builder {
+            return None;
+        }
+
+        let local = self.unset_value_ident();
+        let ty = self.field_type;
+        let option = self.bindings.option_ty();
+        let cfg_attrs = &self.cfg_attrs();
+        let default = self.bindings.default_trait();
+        let reject = self.forbid_default_reject();
+
+        if !self.setter_enabled {
+            let default_value = self.default();
+            let forbid_default = if self.forbid_default {
+                Some(quote!(
+                    #(#cfg_attrs)*
+                    if #local == <#ty as #default>::default() {
+                        #reject
+                    }
+                ))
+            } else {
+                None
+            };
+            return Some(quote!(
+                #(#cfg_attrs)*
+                let #local: #ty = #default_value;
+                #forbid_default
+            ));
+        }
+
+        let builder_field = self.field_ident;
+        let match_none = match self.match_none() {
+            MatchNone::DefaultTo(_) |
+            MatchNone::UseDefaultStructField(_) |
+            MatchNone::NoneValue(_) => {
+                let default_value = self.default();
+                quote!(None => #option::Some(#default_value))
+            },
+            required => quote!(#required),
+        };
+        let forbid_default = if self.forbid_default {
+            Some(quote!(
+                #(#cfg_attrs)*
+                if let #option::Some(value) = #option::or(#option::as_ref(&#local),
+                                                           #option::as_ref(&self.#builder_field)) {
+                    if *value == <#ty as #default>::default() {
+                        #reject
+                    }
+                }
+            ))
+        } else {
+            None
+        };
+        Some(quote!(
+            #(#cfg_attrs)*
+            let #local: #option<#ty> = match self.#builder_field {
+                Some(_) => #option::None,
+                #match_none,
+            };
+            #forbid_default
+        ))
+    }
+
+    /// Name of the local variable holding the `unset_value` of this field.
+    fn unset_value_ident(&self) -> syn::Ident {
+        syn::Ident::new(format!("__unset_{}", ident_name(self.field_ident)))
+    }
+
+    /// Statements returning the error of `forbid_default`, or pushing it to
+    /// `errors` if the field is `checked_by_build_fn`.
+    fn forbid_default_reject(&self) -> Tokens {
+        let result = self.bindings.result_ty();
+        let string = self.bindings.string_ty();
+        let err = format!("`{}` must not be left at its default value",
+                          ident_name(self.field_ident));
+        if self.checked_by_build_fn {
+            quote!(
+                errors.push(#string::from(#err));
+                return #result::Err(errors);
+            )
+        } else {
+            let err = self.error_value(quote!(#string::from(#err)));
+            quote!(return #result::Err(#err);)
+        }
+    }
+
+    /// Statement pushing an error to `errors`, if this field is required but
+    /// uninitialized.
+    ///
+    /// Returns `None` if the field is not required.
+    pub fn missing_field_check(&self) -> Option<Tokens> {
+        if !self.setter_enabled || self.default_value.is_some() || self.use_default_struct ||
+           self.is_optional() {
+            return None;
+        }
+
+        let builder_field = self.field_ident;
+        let err = format!("`{}` must be initialized", ident_name(self.field_ident));
+        let string = self.bindings.string_ty();
+        let cfg_attrs = self.cfg_attrs();
+        Some(quote!(
+            #(#cfg_attrs)*
+            if self.#builder_field.is_none() {
+                errors.push(#string::from(#err));
+            }
+        ))
+    }
+
+    /// Statement rejecting a value with characters outside of `charset`.
+    ///
+    /// Returns an error, or pushes it to `errors` if the field is
+    /// `checked_by_build_fn`. Returns `None` if there is no charset.
+    pub fn charset_check(&self) -> Option<Tokens> {
+        let charset = match self.charset {
+            Some(charset) if self.setter_enabled => charset,
+            _ => return None,
+        };
+
+        let builder_field = self.field_ident;
+        let predicate = syn::Ident::new(charset.predicate());
+        let err = format!("`{}` must only contain {}",
+                          ident_name(self.field_ident),
+                          charset.description());
+        let option = self.bindings.option_ty();
+        let result = self.bindings.result_ty();
+        let string = self.bindings.string_ty();
+        let cfg_attrs = self.cfg_attrs();
+        let reject = if self.checked_by_build_fn {
+            quote!(errors.push(#string::from(#err));)
+        } else {
+            let err = self.error_value(quote!(#string::from(#err)));
+            quote!(return #result::Err(#err);)
+        };
+        Some(quote!(
+            #(#cfg_attrs)*
+            if let #option::Some(ref value) = self.#builder_field {
+                if !value.chars().all(|c| c.#predicate()) {
+                    #reject
+                }
+            }
+        ))
+    }
+
+    /// Statement passing the value to `validate_fn`.
+    ///
+    /// Returns the error, or pushes it to `errors` if the field is
+    /// `checked_by_build_fn`. Returns `None` if there is no validator.
+    pub fn validation_check(&self) -> Option<Tokens> {
+        let validate_fn = match self.validate_fn {
+            Some(validate_fn) if self.setter_enabled => validate_fn,
+            _ => return None,
+        };
+
+        let builder_field = self.field_ident;
+        let option = self.bindings.option_ty();
+        let result = self.bindings.result_ty();
+        let cfg_attrs = self.cfg_attrs();
+        let validate = if self.checked_by_build_fn {
+            quote!(
+                if let #result::Err(err) = #validate_fn(value) {
+                    errors.push(err);
+                }
+            )
+        } else {
+            quote!(#validate_fn(value)?;)
+        };
+        Some(quote!(
+            #(#cfg_attrs)*
+            if let #option::Some(ref value) = self.#builder_field {
+                #validate
+            }
+        ))
+    }
+
+    /// Value of this field in a `Default` impl of the target, which has
+    /// neither a builder nor a default struct at hand, see `TargetDefault`.
+    ///
+    /// Returns `None` if the field has no such default, e.g. if its default
+    /// value is fallible.
+    pub fn target_default_value(&self) -> Option<Tokens> {
+        match self.default_value {
+            Some(ref expr) if expr.is_fallible() => None,
+            Some(ref expr) => Some(quote!(#expr)),
+            None if self.is_optional() => {
+                let option = self.bindings.option_ty();
+                Some(quote!(#option::None))
+            },
+            None if !self.setter_enabled && !self.use_default_struct => {
+                let default = self.bindings.default_trait();
+                Some(quote!(#default::default()))
+            },
+            None => None,
+        }
+    }
+
+    /// Whether an unset field falls back to `None`, see `option_as_optional`.
+    fn is_optional(&self) -> bool {
+        if !self.option_as_optional {
+            return false;
+        }
+
+        match *self.field_type {
+            syn::Ty::Path(None, ref path) => {
+                path.segments.last().map_or(false, |s| s.ident == "Option")
+            },
+            _ => false,
+        }
+    }
+
+    /// The error returned for the message `err`, i.e. `err` itself or the
+    /// `ValidationError` variant of `error_ty`.
+    fn error_value(&self, err: Tokens) -> Tokens {
+        match self.error_ty {
+            Some(error_ty) => quote!(#error_ty::ValidationError(#err)),
+            None => err,
+        }
+    }
+
+    /// The `cfg` attributes of the field.
+    fn cfg_attrs(&'a self) -> Vec<&'a syn::Attribute> {
+        self.attrs.iter().filter(|a| a.value.name() == "cfg").collect()
+    }
+
     /// To be used inside of `#struct_field: match self.#builder_field { ... }`
     fn match_none(&'a self) -> MatchNone<'a> {
         match self.default_value {
-            Some(ref expr) => MatchNone::DefaultTo(expr),
+            Some(ref expr) => MatchNone::DefaultTo(self.default_expr(expr)),
             None => {
                 if self.use_default_struct {
                     MatchNone::UseDefaultStructField(self.field_ident)
-                } else if self.bindings.no_std {
-                    MatchNone::ReturnErrorNoStd(format!("`{}` must be initialized",
-                                                        self.field_ident))
+                } else if self.is_optional() {
+                    let option = self.bindings.option_ty();
+                    MatchNone::NoneValue(quote!(#option))
                 } else {
-                    MatchNone::ReturnError(format!("`{}` must be initialized", self.field_ident))
+                    let name = ident_name(self.field_ident);
+                    let err = format!("`{}` must be initialized", name);
+                    if self.checked_by_build_fn {
+                        MatchNone::Unreachable(err)
+                    } else if let Some(error_ty) = self.error_ty {
+                        let result = self.bindings.result_ty();
+                        MatchNone::ReturnTypedError(quote!(
+                            return #result::Err(#error_ty::UninitializedField(#name))
+                        ))
+                    } else if self.bindings.no_std {
+                        MatchNone::ReturnErrorNoStd(err, self.bindings.string_ty())
+                    } else {
+                        MatchNone::ReturnError(err)
+                    }
                 }
             },
         }
     }
 
+    /// The default value `expr`, see `fallible_default` if it ends with `?`.
+    fn default_expr(&self, expr: &Block) -> Tokens {
+        if expr.is_fallible() {
+            self.fallible_default(expr)
+        } else {
+            quote!(#expr)
+        }
+    }
+
+    /// Evaluates `expr` in a closure returning the error type of the build
+    /// method, such that `?` converts errors via `From` into a `String`.
+    ///
+    /// Returns the error, or pushes it to `errors` if the field is
+    /// `checked_by_build_fn`.
+    fn fallible_default(&self, expr: &Block) -> Tokens {
+        let ty = self.field_type;
+        let result = self.bindings.result_ty();
+        let string = self.bindings.string_ty();
+        let reject = if self.checked_by_build_fn {
+            quote!({
+                errors.push(err);
+                return #result::Err(errors);
+            })
+        } else {
+            let err = self.error_value(quote!(err));
+            quote!(return #result::Err(#err))
+        };
+        quote!(
+            match (|| -> #result<#ty, #string> { #result::Ok(#expr) })() {
+                #result::Ok(value) => value,
+                #result::Err(err) => #reject,
+            }
+        )
+    }
+
     fn default(&'a self) -> Tokens {
         match self.default_value {
-            Some(ref expr) => quote!(#expr),
+            Some(ref expr) => self.default_expr(expr),
             None if self.use_default_struct => {
                 let struct_ident = syn::Ident::new(DEFAULT_STRUCT_NAME);
                 let field_ident = self.field_ident;
@@ -128,21 +501,29 @@ impl<'a> Initializer<'a> {
 /// To be used inside of `#struct_field: match self.#builder_field { ... }`
 enum MatchNone<'a> {
     /// Inner value must be a valid Rust expression
-    DefaultTo(&'a Block),
+    DefaultTo(Tokens),
     /// Inner value must be the field identifier
     ///
     /// The default struct must be in scope in the build_method.
     UseDefaultStructField(&'a syn::Ident),
+    /// Inner value must be the path to `Option`
+    NoneValue(Tokens),
     /// Inner value must be the field name
-    ReturnError(String),
+    ///
+    /// The build method must have checked that the field is initialized.
+    Unreachable(String),
     /// Inner value must be the field name
-    ReturnErrorNoStd(String),
+    ReturnError(String),
+    /// Inner values must be the field name and the path to `String`
+    ReturnErrorNoStd(String, RawTokens<&'static str>),
+    /// Inner value must be a `return` expression
+    ReturnTypedError(Tokens),
 }
 
 impl<'a> ToTokens for MatchNone<'a> {
     fn to_tokens(&self, tokens: &mut Tokens) {
         match *self {
-            MatchNone::DefaultTo(expr) => tokens.append(quote!(
+            MatchNone::DefaultTo(ref expr) => tokens.append(quote!(
                 None => #expr
             )),
             MatchNone::UseDefaultStructField(field_ident) => {
@@ -151,12 +532,20 @@ impl<'a> ToTokens for MatchNone<'a> {
                     None => #struct_ident.#field_ident
                 ))
             },
+            MatchNone::NoneValue(ref option) => tokens.append(quote!(
+                None => #option::None
+            )),
+            MatchNone::Unreachable(ref err) => tokens.append(quote!(
+                None => unreachable!(#err)
+            )),
             MatchNone::ReturnError(ref err) => tokens.append(quote!(
                 None => return ::std::result::Result::Err(::std::string::String::from(#err))
             )),
-            MatchNone::ReturnErrorNoStd(ref err) => tokens.append(quote!(
-                None => return ::core::result::Result::Err(
-                    ::collections::string::String::from(#err))
+            MatchNone::ReturnErrorNoStd(ref err, ref string) => tokens.append(quote!(
+                None => return ::core::result::Result::Err(#string::from(#err))
+            )),
+            MatchNone::ReturnTypedError(ref ret) => tokens.append(quote!(
+                None => #ret
             )),
         }
     }
@@ -193,10 +582,21 @@ macro_rules! default_initializer {
     () => {
         Initializer {
             field_ident: &syn::Ident::new("foo"),
+            field_type: &syn::parse_type("u32").unwrap(),
             setter_enabled: true,
             builder_pattern: BuilderPattern::Mutable,
             default_value: None,
             use_default_struct: false,
+            option_as_optional: false,
+            depends_on: &[],
+            take_from_builder: false,
+            initial_value: None,
+            checked_by_build_fn: false,
+            charset: None,
+            validate_fn: None,
+            forbid_default: false,
+            error_ty: None,
+            attrs: &[],
             bindings: Default::default(),
         }
     }
@@ -252,6 +652,249 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn take_from_builder() {
+        let mut initializer = default_initializer!();
+        initializer.take_from_builder = true;
+
+        assert_eq!(quote!(#initializer), quote!(
+            foo: match ::std::option::Option::or(
+                ::std::option::Option::take(&mut self.foo),
+                __unset_foo
+            ) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => unreachable!(
+                    "`foo` has been checked before moving any value"
+                ),
+            },
+        ));
+        assert_eq!(initializer.unset_value().map(|x| x.to_string()),
+                   Some(quote!(
+                       let __unset_foo: ::std::option::Option<u32> = match self.foo {
+                           Some(_) => ::std::option::Option::None,
+                           None => return ::std::result::Result::Err(
+                               ::std::string::String::from("`foo` must be initialized")
+                           ),
+                       };
+                   ).to_string()));
+    }
+
+    #[test]
+    fn take_from_builder_initial_value() {
+        let initial_value: Block = "7".parse().unwrap();
+        let mut initializer = default_initializer!();
+        initializer.take_from_builder = true;
+        initializer.initial_value = Some(&initial_value);
+        initializer.default_value = Some("42".parse().unwrap());
+        initializer.forbid_default = true;
+
+        assert_eq!(quote!(#initializer), quote!(
+            foo: match ::std::option::Option::or(
+                ::std::option::Option::replace(&mut self.foo, { 7 }),
+                __unset_foo
+            ) {
+                ::std::option::Option::Some(value) => value,
+                ::std::option::Option::None => unreachable!(
+                    "`foo` has been checked before moving any value"
+                ),
+            },
+        ));
+        assert_eq!(initializer.unset_value().map(|x| x.to_string()),
+                   Some(quote!(
+                       let __unset_foo: ::std::option::Option<u32> = match self.foo {
+                           Some(_) => ::std::option::Option::None,
+                           None => ::std::option::Option::Some({ 42 }),
+                       };
+                       if let ::std::option::Option::Some(value) = ::std::option::Option::or(
+                           ::std::option::Option::as_ref(&__unset_foo),
+                           ::std::option::Option::as_ref(&self.foo)
+                       ) {
+                           if *value == <u32 as ::std::default::Default>::default() {
+                               return ::std::result::Result::Err(::std::string::String::from(
+                                   "`foo` must not be left at its default value"
+                               ));
+                           }
+                       }
+                   ).to_string()));
+    }
+
+    #[test]
+    fn checked_by_build_fn() {
+        let mut initializer = default_initializer!();
+        initializer.checked_by_build_fn = true;
+
+        assert_eq!(quote!(#initializer), quote!(
+            foo: match self.foo {
+                Some(ref value) => ::std::clone::Clone::clone(value),
+                None => unreachable!("`foo` must be initialized"),
+            },
+        ));
+        assert_eq!(initializer.missing_field_check().map(|x| x.to_string()),
+                   Some(quote!(
+                       if self.foo.is_none() {
+                           errors.push(::std::string::String::from("`foo` must be initialized"));
+                       }
+                   ).to_string()));
+
+        initializer.default_value = Some("42".parse().unwrap());
+        assert!(initializer.missing_field_check().is_none());
+    }
+
+    #[test]
+    fn charset_check() {
+        let mut initializer = default_initializer!();
+        assert!(initializer.charset_check().is_none());
+
+        initializer.charset = Some(Charset::Ascii);
+        assert_eq!(initializer.charset_check().map(|x| x.to_string()),
+                   Some(quote!(
+                       if let ::std::option::Option::Some(ref value) = self.foo {
+                           if !value.chars().all(|c| c.is_ascii()) {
+                               return ::std::result::Result::Err(::std::string::String::from(
+                                   "`foo` must only contain ASCII characters"));
+                           }
+                       }
+                   ).to_string()));
+
+        initializer.charset = Some(Charset::Alphanumeric);
+        initializer.checked_by_build_fn = true;
+        assert_eq!(initializer.charset_check().map(|x| x.to_string()),
+                   Some(quote!(
+                       if let ::std::option::Option::Some(ref value) = self.foo {
+                           if !value.chars().all(|c| c.is_ascii_alphanumeric()) {
+                               errors.push(::std::string::String::from(
+                                   "`foo` must only contain alphanumeric ASCII characters"));
+                           }
+                       }
+                   ).to_string()));
+
+        initializer.setter_enabled = false;
+        assert!(initializer.charset_check().is_none());
+    }
+
+    #[test]
+    fn validation_check() {
+        let mut initializer = default_initializer!();
+        assert!(initializer.validation_check().is_none());
+
+        let validate_fn = syn::parse_path("validate_foo").unwrap();
+        initializer.validate_fn = Some(&validate_fn);
+        assert_eq!(initializer.validation_check().map(|x| x.to_string()),
+                   Some(quote!(
+                       if let ::std::option::Option::Some(ref value) = self.foo {
+                           validate_foo(value)?;
+                       }
+                   ).to_string()));
+
+        initializer.checked_by_build_fn = true;
+        assert_eq!(initializer.validation_check().map(|x| x.to_string()),
+                   Some(quote!(
+                       if let ::std::option::Option::Some(ref value) = self.foo {
+                           if let ::std::result::Result::Err(err) = validate_foo(value) {
+                               errors.push(err);
+                           }
+                       }
+                   ).to_string()));
+
+        initializer.setter_enabled = false;
+        assert!(initializer.validation_check().is_none());
+    }
+
+    #[test]
+    fn option_as_optional() {
+        let ty = syn::parse_type("Option<u32>").unwrap();
+        let mut initializer = default_initializer!();
+        initializer.field_type = &ty;
+        initializer.option_as_optional = true;
+
+        assert_eq!(quote!(#initializer), quote!(
+            foo: match self.foo {
+                Some(ref value) => ::std::clone::Clone::clone(value),
+                None => ::std::option::Option::None,
+            },
+        ));
+        assert!(initializer.missing_field_check().is_none());
+    }
+
+    #[test]
+    fn option_as_optional_non_option() {
+        let mut initializer = default_initializer!();
+        initializer.option_as_optional = true;
+
+        assert_eq!(quote!(#initializer), quote!(
+            foo: match self.foo {
+                Some(ref value) => ::std::clone::Clone::clone(value),
+                None => return ::std::result::Result::Err(::std::string::String::from(
+                    "`foo` must be initialized"
+                )),
+            },
+        ));
+    }
+
+    #[test]
+    fn forbid_default() {
+        let mut initializer = default_initializer!();
+        initializer.forbid_default = true;
+
+        assert_eq!(quote!(#initializer), quote!(
+            foo: {
+                let value: u32 = match self.foo {
+                    Some(ref value) => ::std::clone::Clone::clone(value),
+                    None => return ::std::result::Result::Err(::std::string::String::from(
+                        "`foo` must be initialized"
+                    )),
+                };
+                if value == <u32 as ::std::default::Default>::default() {
+                    return ::std::result::Result::Err(::std::string::String::from(
+                        "`foo` must not be left at its default value"));
+                }
+                value
+            },
+        ));
+
+        initializer.checked_by_build_fn = true;
+        initializer.default_value = Some("42".parse().unwrap());
+        assert_eq!(quote!(#initializer), quote!(
+            foo: {
+                let value: u32 = match self.foo {
+                    Some(ref value) => ::std::clone::Clone::clone(value),
+                    None => { 42 },
+                };
+                if value == <u32 as ::std::default::Default>::default() {
+                    errors.push(::std::string::String::from(
+                        "`foo` must not be left at its default value"));
+                    return ::std::result::Result::Err(errors);
+                }
+                value
+            },
+        ));
+    }
+
+    #[test]
+    fn cfg_attrs() {
+        let attrs = vec![syn::parse_outer_attr("#[cfg(feature = \"bar\")]").unwrap(),
+                         syn::parse_outer_attr("#[doc = \"Doc comment\"]").unwrap(),
+                         syn::parse_outer_attr("#[allow(non_snake_case)]").unwrap()];
+        let mut initializer = default_initializer!();
+        initializer.attrs = attrs.as_slice();
+
+        assert_eq!(quote!(#initializer), quote!(
+            #[cfg(feature = "bar")]
+            foo: match self.foo {
+                Some(ref value) => ::std::clone::Clone::clone(value),
+                None => return ::std::result::Result::Err(::std::string::String::from(
+                    "`foo` must be initialized"
+                )),
+            },
+        ));
+
+        initializer.setter_enabled = false;
+        assert_eq!(quote!(#initializer), quote!(
+            #[cfg(feature = "bar")]
+            foo: ::std::default::Default::default(),
+        ));
+    }
+
     #[test]
     fn default_value() {
         let mut initializer = default_initializer!();
@@ -265,6 +908,69 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn fallible_default_value() {
+        let mut initializer = default_initializer!();
+        initializer.default_value = Some("compute()?".parse().unwrap());
+
+        assert_eq!(quote!(#initializer), quote!(
+            foo: match self.foo {
+                Some(ref value) => ::std::clone::Clone::clone(value),
+                None => match (|| -> ::std::result::Result<u32, ::std::string::String> {
+                    ::std::result::Result::Ok({ compute()? })
+                })() {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(err) => return ::std::result::Result::Err(err),
+                },
+            },
+        ));
+
+        initializer.checked_by_build_fn = true;
+        initializer.setter_enabled = false;
+        assert_eq!(quote!(#initializer), quote!(
+            foo: match (|| -> ::std::result::Result<u32, ::std::string::String> {
+                ::std::result::Result::Ok({ compute()? })
+            })() {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(err) => {
+                    errors.push(err);
+                    return ::std::result::Result::Err(errors);
+                },
+            },
+        ));
+    }
+
+    #[test]
+    fn typed_error() {
+        let error_ty = syn::Ident::new("FooBuilderError");
+        let mut initializer = default_initializer!();
+        initializer.error_ty = Some(&error_ty);
+
+        assert_eq!(quote!(#initializer), quote!(
+            foo: match self.foo {
+                Some(ref value) => ::std::clone::Clone::clone(value),
+                None => return ::std::result::Result::Err(
+                    FooBuilderError::UninitializedField("foo")),
+            },
+        ));
+
+        initializer.forbid_default = true;
+        assert_eq!(quote!(#initializer), quote!(
+            foo: {
+                let value: u32 = match self.foo {
+                    Some(ref value) => ::std::clone::Clone::clone(value),
+                    None => return ::std::result::Result::Err(
+                        FooBuilderError::UninitializedField("foo")),
+                };
+                if value == <u32 as ::std::default::Default>::default() {
+                    return ::std::result::Result::Err(FooBuilderError::ValidationError(
+                        ::std::string::String::from("`foo` must not be left at its default value")));
+                }
+                value
+            },
+        ));
+    }
+
     #[test]
     fn default_struct() {
         let mut initializer = default_initializer!();
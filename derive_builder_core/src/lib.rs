@@ -23,7 +23,7 @@
 //! [`derive_builder_core`]: https://!crates.io/crates/derive_builder_core
 
 #![deny(warnings, missing_docs)]
-#![cfg_attr(test, recursion_limit = "100")]
+#![cfg_attr(test, recursion_limit = "256")]
 
 extern crate proc_macro;
 extern crate syn;
@@ -40,27 +40,47 @@ extern crate pretty_assertions;
 #[macro_use]
 mod log_disabled;
 mod build_method;
+mod from_pairs;
 mod builder_field;
 mod builder;
+mod builder_view;
+mod builder_error;
+mod fields_fn;
+mod clap_args;
+mod ext_trait;
 mod deprecation_notes;
 mod doc_comment;
+mod raw_ident;
 mod initializer;
 mod setter;
+mod getter;
 mod options;
 mod block;
 mod bindings;
 mod tokens;
+mod update_struct;
+mod target_default;
 
 pub use build_method::BuildMethod;
+pub use from_pairs::FromPairs;
 pub use builder_field::BuilderField;
 pub use builder::Builder;
+pub use builder_view::BuilderView;
+pub use builder_error::BuilderError;
+pub use fields_fn::{FieldsFn, FieldsFnKind};
+pub use clap_args::ClapArgs;
+pub use ext_trait::ExtTrait;
 pub use deprecation_notes::DeprecationNotes;
 pub use initializer::Initializer;
-pub use setter::Setter;
+pub use setter::{Setter, SetterAlias, EachSetter, StripOption};
+pub use getter::Getter;
 pub use doc_comment::doc_comment_from;
-pub use options::BuilderPattern;
+pub use raw_ident::ident_name;
+pub use options::{BuilderPattern, SetterOnce, Charset};
 pub use block::Block;
 pub use bindings::Bindings;
 pub use tokens::RawTokens;
+pub use update_struct::UpdateStruct;
+pub use target_default::TargetDefault;
 
 const DEFAULT_STRUCT_NAME: &'static str = "__default";
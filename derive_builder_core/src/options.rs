@@ -33,3 +33,39 @@ impl Default for BuilderPattern {
         BuilderPattern::Mutable
     }
 }
+
+/// Controls how a setter reacts if its field has already been set, see `setter(once)`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SetterOnce {
+    /// Panic on the second call.
+    Panic,
+    /// Return an error on the second call, i.e. the setter returns a `Result`.
+    Error,
+}
+
+/// Characters a string field may contain, see `setter(charset = "...")`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Charset {
+    /// Only ASCII characters, i.e. `char::is_ascii`.
+    Ascii,
+    /// Only ASCII letters and digits, i.e. `char::is_ascii_alphanumeric`.
+    Alphanumeric,
+}
+
+impl Charset {
+    /// Name of the method on `char` checking membership in this charset.
+    pub fn predicate(&self) -> &'static str {
+        match *self {
+            Charset::Ascii => "is_ascii",
+            Charset::Alphanumeric => "is_ascii_alphanumeric",
+        }
+    }
+
+    /// Human-readable description for error messages.
+    pub fn description(&self) -> &'static str {
+        match *self {
+            Charset::Ascii => "ASCII characters",
+            Charset::Alphanumeric => "alphanumeric ASCII characters",
+        }
+    }
+}
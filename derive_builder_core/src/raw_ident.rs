@@ -0,0 +1,27 @@
+use syn;
+
+/// Name of an identifier without the `r#` prefix of raw identifiers, e.g.
+/// `type` for `r#type`.
+///
+/// Use this to derive other names from an identifier, e.g. `take_type`, and
+/// for user-facing strings like keys and error messages.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate syn;
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::ident_name;
+/// # fn main() {
+/// assert_eq!(ident_name(&syn::Ident::new("r#type")), "type");
+/// assert_eq!(ident_name(&syn::Ident::new("foo")), "foo");
+/// # }
+/// ```
+pub fn ident_name(ident: &syn::Ident) -> &str {
+    let name = ident.as_ref();
+    if name.starts_with("r#") {
+        &name[2..]
+    } else {
+        name
+    }
+}
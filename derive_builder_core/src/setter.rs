@@ -1,8 +1,11 @@
 use quote::{Tokens, ToTokens};
 use syn;
 use BuilderPattern;
+use SetterOnce;
 use DeprecationNotes;
 use Bindings;
+use ident_name;
+use doc_comment::doc_comment_from;
 
 /// Setter for the struct fields in the build method, implementing
 /// `quote::ToTokens`.
@@ -37,14 +40,33 @@ pub struct Setter<'a> {
     pub enabled: bool,
     /// Enables code generation for the `try_` variant of this setter fn.
     pub try_setter: bool,
+    /// Error type of the `try_` variant, converted from `TryInto::Error` via `From`.
+    ///
+    /// Defaults to `TryInto::Error` itself.
+    pub try_setter_error: Option<&'a syn::Path>,
+    /// Make this setter fn itself fallible, converting its argument via `TryInto`.
+    ///
+    /// Unlike `try_setter`, this replaces the infallible setter fn.
+    pub try_into: bool,
+    /// Error type of the fallible setter fn, converted from `TryInto::Error`
+    /// via `From`.
+    ///
+    /// Defaults to `TryInto::Error` itself.
+    pub try_into_error: Option<&'a syn::Path>,
     /// Visibility of the setter, e.g. `syn::Visibility::Public`.
     pub visibility: &'a syn::Visibility,
     /// How the setter method takes and returns `self` (e.g. mutably).
     pub pattern: BuilderPattern,
     /// Attributes which will be attached to this setter fn.
     pub attrs: &'a [syn::Attribute],
+    /// Doc-comment of this setter fn.
+    ///
+    /// Takes precedence over doc-comments in `attrs`, e.g. forwarded field docs.
+    pub doc_comment: Option<syn::Attribute>,
     /// Name of this setter fn.
     pub ident: &'a syn::Ident,
+    /// Alternative names of this setter fn, delegating to `ident`.
+    pub aliases: &'a [SetterAlias],
     /// Name of the target field.
     pub field_ident: &'a syn::Ident,
     /// Type of the target field.
@@ -53,12 +75,107 @@ pub struct Setter<'a> {
     pub field_type: &'a syn::Ty,
     /// Make the setter generic over `Into<T>`, where `T` is the field type.
     pub generic_into: bool,
+    /// Keep this setter fn plain if `generic_into` is enabled, and add a
+    /// `{ident}_into` fn generic over `Into<T>` instead.
+    pub with_plain: bool,
+    /// Type `T` of the `Into<T>` bound instead of the field type, if
+    /// `generic_into` is enabled, e.g. to disambiguate conversions.
+    ///
+    /// The converted value is then converted into the field type via `Into`.
+    pub into_ty: Option<&'a syn::Ty>,
+    /// Use `impl Into<T>` in argument position instead of a type parameter,
+    /// if `generic_into` is enabled.
+    pub impl_trait: bool,
+    /// Additional setter which adds a single item to a collection field.
+    pub each: Option<EachSetter<'a>>,
+    /// Item type of a collection field, making the setter generic over
+    /// `IntoIterator` of items convertible into it, e.g. `T` for `Vec<T>`.
+    ///
+    /// Takes precedence over `generic_into`.
+    pub into_iter: Option<&'a syn::Ty>,
+    /// Make the setter generic over `AsRef<B>` and store an owned copy of the
+    /// borrowed value, where `B` is e.g. `str` for `String`.
+    ///
+    /// Supported for `String`, `PathBuf` and `OsString` fields. Takes
+    /// precedence over `generic_into`.
+    pub as_ref: bool,
+    /// Accept the value without its `Option` layers and wrap it in the setter.
+    pub strip_option: Option<StripOption<'a>>,
+    /// Name of an additional setter fn taking `Option<T>`, where `T` is the
+    /// stripped type, so `None` clears an `Option` field again.
+    ///
+    /// Requires `strip_option`.
+    pub option: Option<&'a syn::Ident>,
+    /// Take no argument and set a `bool` field to `true`, like a flag.
+    ///
+    /// Takes precedence over all other conversions of the argument.
+    pub strip_bool: bool,
+    /// Reject calls to this setter if the field has already been set.
+    pub once: Option<SetterOnce>,
+    /// Declare this setter fn and its aliases as `const fn`.
+    ///
+    /// Requires the owned pattern and a field type without drop glue.
+    pub const_fn: bool,
+    /// Mark this setter fn and its variants, e.g. aliases, as `#[inline]`.
+    pub inline: bool,
+    /// Item type of the collection, adding `set_{ident}`, which replaces the
+    /// collection from any `IntoIterator`, and `clear_{ident}`.
+    pub collection: Option<&'a syn::Ty>,
+    /// Extend the collection via `Extend`, instead of replacing it, so
+    /// repeated calls accumulate.
+    ///
+    /// Requires the field type to implement `Default` and `Extend`.
+    pub append: bool,
     /// Emit deprecation notes to the user.
     pub deprecation_notes: &'a DeprecationNotes,
+    /// `#[deprecated]` attribute of the target field, attached to this setter
+    /// fn and its variants.
+    pub deprecated: Option<&'a syn::Attribute>,
     /// Bindings to libstd or libcore.
     pub bindings: Bindings,
 }
 
+/// Setter which adds a single item to a collection field, e.g. `Vec<T>`.
+///
+/// The item is passed to `Extend::extend`, starting from an empty collection
+/// if the field has not been set yet. `String` fields are appended to via
+/// `push_str` instead.
+#[derive(Debug, Clone)]
+pub struct EachSetter<'a> {
+    /// Name of this setter fn.
+    pub ident: &'a syn::Ident,
+    /// Type of a single item of the collection, e.g. `T` for `Vec<T>`.
+    ///
+    /// For `String` fields this is `str`, which is taken by reference.
+    pub item_type: &'a syn::Ty,
+    /// Make the setter generic over `Into<T>`, where `T` is the item type.
+    ///
+    /// For `String` fields the setter is generic over `AsRef<str>` instead.
+    pub generic_into: bool,
+    /// Take the key and the value of a map entry as separate arguments and
+    /// `insert` them, if `item_type` is a pair `(K, V)`.
+    pub key_value: bool,
+}
+
+/// Alternative name of a setter, delegating to the primary setter fn.
+#[derive(Debug, Clone)]
+pub struct SetterAlias {
+    /// Name of the alias fn.
+    pub ident: syn::Ident,
+    /// Mark the alias as `#[deprecated]`, pointing callers to the primary setter.
+    pub deprecated: bool,
+}
+
+/// `Option` layers stripped from the argument of a setter, e.g. `T` instead of
+/// `Option<Option<T>>`.
+#[derive(Debug, Clone)]
+pub struct StripOption<'a> {
+    /// Number of `Option` layers the argument is wrapped in, at least 1.
+    pub depth: usize,
+    /// Type of the argument, i.e. the field type without `depth` layers of `Option`.
+    pub inner_type: &'a syn::Ty,
+}
+
 impl<'a> ToTokens for Setter<'a> {
     fn to_tokens(&self, tokens: &mut Tokens) {
         if self.enabled {
@@ -68,11 +185,21 @@ impl<'a> ToTokens for Setter<'a> {
             let vis = self.visibility;
             let field_ident = self.field_ident;
             let ident = self.ident;
-            let attrs = self.attrs;
+            let attrs = &self.attrs();
             let deprecation_notes = self.deprecation_notes;
             let clone = self.bindings.clone_trait();
             let option = self.bindings.option_ty();
             let into = self.bindings.into_trait();
+            let constness = if self.const_fn {
+                Some(quote!(const))
+            } else {
+                None
+            };
+            let inline = if self.inline {
+                Some(quote!(#[inline]))
+            } else {
+                None
+            };
 
             let self_param: Tokens;
             let return_ty: Tokens;
@@ -96,42 +223,332 @@ impl<'a> ToTokens for Setter<'a> {
                 },
             };
 
+            let setter_return_ty: Tokens;
+            let once_check: Tokens;
+            let return_new: Tokens;
+            let try_into_error = match self.try_into_error {
+                Some(error) => quote!(#error),
+                None => quote!(__VALUE::Error),
+            };
+            let infallible_return = |return_ty: Tokens| if self.try_into {
+                let result = self.bindings.result_ty();
+                (quote!(#result<#return_ty, #try_into_error>), quote!(#result::Ok(new)))
+            } else {
+                (return_ty, quote!(new))
+            };
+
+            match self.once {
+                None => {
+                    let (ty, new) = infallible_return(return_ty.clone());
+                    setter_return_ty = ty;
+                    once_check = quote!();
+                    return_new = new;
+                },
+                Some(SetterOnce::Panic) => {
+                    let msg = format!("`{}` must not be set more than once",
+                                      ident_name(field_ident));
+                    let (ty, new) = infallible_return(return_ty.clone());
+                    setter_return_ty = ty;
+                    once_check = quote!(
+                        if self.#field_ident.is_some() {
+                            panic!(#msg);
+                        }
+                    );
+                    return_new = new;
+                },
+                Some(SetterOnce::Error) if self.try_into => {
+                    panic!("`setter(try_into)` can not be combined with `setter(once = \"error\")` \
+                            on field `{}`.",
+                           field_ident)
+                },
+                Some(SetterOnce::Error) if self.try_setter => {
+                    panic!("`try_setter` can not be combined with `setter(once = \"error\")` \
+                            on field `{}`.",
+                           field_ident)
+                },
+                Some(SetterOnce::Error) => {
+                    let msg = format!("`{}` must not be set more than once",
+                                      ident_name(field_ident));
+                    let result = self.bindings.result_ty();
+                    let string = self.bindings.string_ty();
+                    setter_return_ty = quote!(#result<#return_ty, #string>);
+                    once_check = quote!(
+                        if self.#field_ident.is_some() {
+                            return #result::Err(#string::from(#msg));
+                        }
+                    );
+                    return_new = quote!(#result::Ok(new));
+                },
+            }
+
             let ty_params: Tokens;
             let param_ty: Tokens;
             let into_value: Tokens;
+            let mut where_clause: Option<Tokens> = None;
+            // generic `{ident}_into` fn next to the plain setter fn
+            let mut into_fn: Option<Tokens> = None;
 
-            if self.generic_into {
-                ty_params = quote!(<VALUE: #into<#ty>>);
-                param_ty = quote!(VALUE);
-                into_value = quote!(value.into());
+            let value_ty = self.strip_option.as_ref().map(|s| s.inner_type).unwrap_or(ty);
+
+            if self.strip_bool {
+                ty_params = quote!();
+                param_ty = quote!(bool);
+                into_value = quote!(true);
+            } else if self.try_into {
+                let try_into = self.bindings.try_into_trait();
+                ty_params = quote!(<__VALUE: #try_into<#value_ty>>);
+                param_ty = quote!(__VALUE);
+                into_value = quote!(#try_into::<#value_ty>::try_into(value)?);
+                where_clause = self.try_into_error.map(|error| {
+                    let from = self.bindings.from_trait();
+                    quote!(where #error: #from<__VALUE::Error>)
+                });
+            } else if let Some(item_ty) = self.into_iter {
+                let into_iterator = self.bindings.into_iterator_trait();
+                ty_params = quote!(<__VALUE: #into_iterator<Item = __ITEM>, __ITEM: #into<#item_ty>>);
+                param_ty = quote!(__VALUE);
+                into_value = if self.append {
+                    quote!(value.into_iter().map(#into::<#item_ty>::into))
+                } else {
+                    quote!(value.into_iter().map(#into::<#item_ty>::into).collect())
+                };
+            } else if self.as_ref {
+                let as_ref = self.bindings.as_ref_trait();
+                let borrowed_ty = self.borrowed_type(value_ty);
+                ty_params = quote!(<__VALUE: #as_ref<#borrowed_ty>>);
+                param_ty = quote!(__VALUE);
+                into_value = quote!(value.as_ref().to_owned());
+            } else if self.generic_into && self.with_plain {
+                let into_ty = self.into_ty.unwrap_or(value_ty);
+                let into_ident = syn::Ident::new(format!("{}_into", ident_name(ident)));
+                let (into_ty_params, into_param_ty) = if self.impl_trait {
+                    (quote!(), quote!(impl #into<#into_ty>))
+                } else {
+                    (quote!(<__VALUE: #into<#into_ty>>), quote!(__VALUE))
+                };
+                let into_arg = match self.into_ty {
+                    Some(into_ty) => quote!(#into::<#value_ty>::into(#into::<#into_ty>::into(value))),
+                    None => quote!(value.into()),
+                };
+                into_fn = Some(quote!(
+                    #(#attrs)*
+                    #inline
+                    #vis fn #into_ident #into_ty_params (#self_param, value: #into_param_ty)
+                        -> #setter_return_ty
+                    {
+                        self.#ident(#into_arg)
+                }));
+                ty_params = quote!();
+                param_ty = quote!(#value_ty);
+                into_value = quote!(value);
+            } else if self.generic_into {
+                let into_ty = self.into_ty.unwrap_or(value_ty);
+                if self.impl_trait {
+                    ty_params = quote!();
+                    param_ty = quote!(impl #into<#into_ty>);
+                } else {
+                    ty_params = quote!(<__VALUE: #into<#into_ty>>);
+                    param_ty = quote!(__VALUE);
+                }
+                into_value = match self.into_ty {
+                    Some(into_ty) => quote!(#into::<#value_ty>::into(#into::<#into_ty>::into(value))),
+                    None => quote!(value.into()),
+                };
             } else {
                 ty_params = quote!();
-                param_ty = quote!(#ty);
+                param_ty = quote!(#value_ty);
                 into_value = quote!(value);
             }
 
+            let into_value = match self.strip_option {
+                Some(ref s) => (0..s.depth).fold(into_value, |v, _| quote!(#option::Some(#v))),
+                None => into_value,
+            };
+
+            let (value_param, value_arg) = if self.strip_bool {
+                (None, None)
+            } else {
+                (Some(quote!(, value: #param_ty)), Some(quote!(value)))
+            };
+
+            let assignment = if self.append {
+                let extend = self.bindings.extend_trait();
+                let default = self.bindings.default_trait();
+                quote!(
+                    #extend::extend(new.#field_ident.get_or_insert_with(#default::default),
+                                    #into_value);
+                )
+            } else {
+                quote!(new.#field_ident = #option::Some(#into_value);)
+            };
+
             tokens.append(quote!(
                 #(#attrs)*
-                #vis fn #ident #ty_params (#self_param, value: #param_ty)
-                    -> #return_ty
+                #inline
+                #vis #constness fn #ident #ty_params (#self_param #value_param)
+                    -> #setter_return_ty
+                    #where_clause
                 {
                     #deprecation_notes
+                    #once_check
                     let mut new = #self_into_return_ty;
-                    new.#field_ident = #option::Some(#into_value);
-                    new
+                    #assignment
+                    #return_new
             }));
 
+            if let Some(into_fn) = into_fn {
+                tokens.append(into_fn);
+            }
+
+            for alias in self.aliases {
+                let alias_ident = &alias.ident;
+                let mut alias_notes = DeprecationNotes::default();
+                // the deprecated field takes precedence, `#[deprecated]` may only appear once
+                if alias.deprecated && self.deprecated.is_none() {
+                    alias_notes.push(format!("use `{}` instead", ident));
+                }
+                let deprecated = alias_notes.as_attribute();
+                tokens.append(quote!(
+                    #(#attrs)*
+                    #deprecated
+                    #inline
+                    #vis #constness fn #alias_ident #ty_params (#self_param #value_param)
+                        -> #setter_return_ty
+                        #where_clause
+                    {
+                        self.#ident(#value_arg)
+                }));
+            }
+
+            if let Some(option_ident) = self.option {
+                let strip_option = self.strip_option.as_ref().unwrap_or_else(|| {
+                    panic!("`setter(option)` requires `setter(strip_option)` on field `{}`.",
+                           field_ident)
+                });
+                let inner_ty = strip_option.inner_type;
+                let value = (1..strip_option.depth)
+                    .fold(quote!(value), |v, _| quote!(#option::Some(#v)));
+
+                tokens.append(quote!(
+                    #(#attrs)*
+                    #inline
+                    #vis #constness fn #option_ident (#self_param, value: #option<#inner_ty>)
+                        -> #setter_return_ty
+                    {
+                        #once_check
+                        let mut new = #self_into_return_ty;
+                        new.#field_ident = #option::Some(#value);
+                        #return_new
+                }));
+            }
+
+            if let Some(ref each) = self.each {
+                let each_ident = each.ident;
+                let item_ty = each.item_type;
+                let default = self.bindings.default_trait();
+
+                let entry_types = match *item_ty {
+                    syn::Ty::Tup(ref types) if each.key_value && types.len() == 2 => {
+                        Some((&types[0], &types[1]))
+                    },
+                    _ => None,
+                };
+
+                let (each_ty_params, each_params, append) = if let Some((key_ty, entry_ty)) =
+                    entry_types {
+                    if each.generic_into {
+                        (quote!(<__KEY: #into<#key_ty>, __VALUE: #into<#entry_ty>>),
+                         quote!(key: __KEY, value: __VALUE),
+                         quote!(insert(key.into(), value.into())))
+                    } else {
+                        (quote!(),
+                         quote!(key: #key_ty, value: #entry_ty),
+                         quote!(insert(key, value)))
+                    }
+                } else if is_string(value_ty) {
+                    if each.generic_into {
+                        let as_ref = self.bindings.as_ref_trait();
+                        (quote!(<__VALUE: #as_ref<#item_ty>>),
+                         quote!(item: __VALUE),
+                         quote!(push_str(item.as_ref())))
+                    } else {
+                        (quote!(), quote!(item: &#item_ty), quote!(push_str(item)))
+                    }
+                } else if each.generic_into {
+                    (quote!(<__VALUE: #into<#item_ty>>),
+                     quote!(item: __VALUE),
+                     quote!(extend(#option::Some(item.into()))))
+                } else {
+                    (quote!(), quote!(item: #item_ty), quote!(extend(#option::Some(item))))
+                };
+                // initialize each stripped `Option` layer on the way to the collection
+                let depth = self.strip_option.as_ref().map(|s| s.depth).unwrap_or(0);
+                let collection = (0..depth).fold(
+                    quote!(#option::get_or_insert_with(&mut new.#field_ident, #default::default)),
+                    |c, _| quote!(#option::get_or_insert_with(#c, #default::default)));
+
+                tokens.append(quote!(
+                    #(#attrs)*
+                    #inline
+                    #vis fn #each_ident #each_ty_params (#self_param, #each_params)
+                        -> #return_ty
+                    {
+                        let mut new = #self_into_return_ty;
+                        #collection.#append;
+                        new
+                }));
+            }
+
+            if let Some(item_ty) = self.collection {
+                let into_iterator = self.bindings.into_iterator_trait();
+                let set_ident = syn::Ident::new(format!("set_{}", ident_name(ident)));
+                let clear_ident = syn::Ident::new(format!("clear_{}", ident_name(ident)));
+
+                tokens.append(quote!(
+                    #(#attrs)*
+                    #inline
+                    #vis fn #set_ident <__VALUE: #into_iterator<Item = __ITEM>,
+                                        __ITEM: #into<#item_ty>>
+                        (#self_param, value: __VALUE) -> #return_ty
+                    {
+                        let mut new = #self_into_return_ty;
+                        new.#field_ident = #option::Some(
+                            value.into_iter().map(#into::<#item_ty>::into).collect());
+                        new
+                    }
+
+                    #(#attrs)*
+                    #inline
+                    #vis fn #clear_ident (#self_param) -> #return_ty {
+                        let mut new = #self_into_return_ty;
+                        if let #option::Some(ref mut collection) = new.#field_ident {
+                            collection.clear();
+                        }
+                        new
+                }));
+            }
+
             if self.try_setter {
                 let try_into = self.bindings.try_into_trait();
-                let try_ty_params = quote!(<VALUE: #try_into<#ty>>);
-                let try_ident = syn::Ident::new(format!("try_{}", ident));
+                let try_ty_params = quote!(<__VALUE: #try_into<#ty>>);
+                let try_ident = syn::Ident::new(format!("try_{}", ident_name(ident)));
                 let result = self.bindings.result_ty();
+                let (error_ty, error_bound) = match self.try_setter_error {
+                    Some(error) => {
+                        let from = self.bindings.from_trait();
+                        (quote!(#error), Some(quote!(where #error: #from<__VALUE::Error>)))
+                    },
+                    None => (quote!(__VALUE::Error), None),
+                };
 
                 tokens.append(quote!(
                     #(#attrs)*
-                    #vis fn #try_ident #try_ty_params (#self_param, value: VALUE)
-                        -> #result<#return_ty, VALUE::Error>
+                    #inline
+                    #vis fn #try_ident #try_ty_params (#self_param, value: __VALUE)
+                        -> #result<#return_ty, #error_ty>
+                        #error_bound
                     {
+                        #once_check
                         let converted : #ty = value.try_into()?;
                         let mut new = #self_into_return_ty;
                         new.#field_ident = #option::Some(converted);
@@ -146,6 +563,66 @@ impl<'a> ToTokens for Setter<'a> {
     }
 }
 
+impl<'a> Setter<'a> {
+    /// Set a doc-comment for this item.
+    pub fn doc_comment(&mut self, s: String) -> &mut Self {
+        self.doc_comment = Some(doc_comment_from(s));
+        self
+    }
+
+    /// Attributes of this setter fn, with `doc_comment` replacing any other
+    /// doc-comments.
+    fn attrs(&self) -> Vec<&syn::Attribute> {
+        let mut attrs: Vec<_> = match self.doc_comment {
+            Some(ref doc_comment) => {
+                self.attrs
+                    .iter()
+                    .filter(|a| a.value.name() != "doc")
+                    .chain(Some(doc_comment))
+                    .collect()
+            },
+            None => self.attrs.iter().collect(),
+        };
+        attrs.extend(self.deprecated);
+        attrs
+    }
+
+    /// Borrowed form of `ty` for `as_ref` setters, e.g. `str` for `String`.
+    ///
+    /// # Panics
+    ///
+    /// If `ty` is not one of `String`, `PathBuf` or `OsString`.
+    fn borrowed_type(&self, ty: &syn::Ty) -> Tokens {
+        let name = match *ty {
+            syn::Ty::Path(None, ref path) => path.segments.last().map(|s| s.ident.as_ref()),
+            _ => None,
+        };
+
+        match name {
+            Some("String") => quote!(str),
+            Some("PathBuf") => quote!(::std::path::Path),
+            Some("OsString") => quote!(::std::ffi::OsStr),
+            _ => {
+                panic!("`setter(as_ref)` is not supported for field `{}` of type `{}`, \
+                        expected `String`, `PathBuf` or `OsString`.",
+                       self.field_ident,
+                       quote!(#ty))
+            },
+        }
+    }
+}
+
+/// Whether `ty` is `String`, which is appended to via `push_str` instead of
+/// `Extend`.
+fn is_string(ty: &syn::Ty) -> bool {
+    match *ty {
+        syn::Ty::Path(None, ref path) => {
+            path.segments.last().map_or(false, |s| s.ident == "String" && s.parameters.is_empty())
+        },
+        _ => false,
+    }
+}
+
 /// Helper macro for unit tests. This is _only_ public in order to be accessible
 /// from doc-tests too.
 #[doc(hidden)]
@@ -155,14 +632,34 @@ macro_rules! default_setter {
         Setter {
             enabled: true,
             try_setter: false,
+            try_setter_error: None,
+            try_into: false,
+            try_into_error: None,
             visibility: &syn::Visibility::Public,
             pattern: BuilderPattern::Mutable,
             attrs: &vec![],
+            doc_comment: None,
             ident: &syn::Ident::new("foo"),
+            aliases: &[],
             field_ident: &syn::Ident::new("foo"),
             field_type: &syn::parse_type("Foo").unwrap(),
             generic_into: false,
+            with_plain: false,
+            into_ty: None,
+            impl_trait: false,
+            each: None,
+            into_iter: None,
+            as_ref: false,
+            strip_option: None,
+            option: None,
+            strip_bool: false,
+            once: None,
+            const_fn: false,
+            inline: false,
+            collection: None,
+            append: false,
             deprecation_notes: &Default::default(),
+            deprecated: None,
             bindings: Default::default(),
         };
     }
@@ -215,6 +712,219 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn const_fn() {
+        let mut setter = default_setter!();
+        setter.pattern = BuilderPattern::Owned;
+        setter.const_fn = true;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub const fn foo(self, value: Foo) -> Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn into_iter() {
+        let ty = syn::parse_type("Vec<Foo>").unwrap();
+        let item_ty = syn::parse_type("Foo").unwrap();
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.into_iter = Some(&item_ty);
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo<__VALUE: ::std::iter::IntoIterator<Item = __ITEM>,
+                       __ITEM: ::std::convert::Into<Foo>>(&mut self, value: __VALUE) -> &mut Self
+            {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(
+                    value.into_iter().map(::std::convert::Into::<Foo>::into).collect());
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn append() {
+        let ty = syn::parse_type("Vec<Foo>").unwrap();
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.append = true;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Vec<Foo>) -> &mut Self {
+                let mut new = self;
+                ::std::iter::Extend::extend(
+                    new.foo.get_or_insert_with(::std::default::Default::default), value);
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn append_into_iter() {
+        let ty = syn::parse_type("Vec<Foo>").unwrap();
+        let item_ty = syn::parse_type("Foo").unwrap();
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.into_iter = Some(&item_ty);
+        setter.append = true;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo<__VALUE: ::std::iter::IntoIterator<Item = __ITEM>,
+                       __ITEM: ::std::convert::Into<Foo>>(&mut self, value: __VALUE) -> &mut Self
+            {
+                let mut new = self;
+                ::std::iter::Extend::extend(
+                    new.foo.get_or_insert_with(::std::default::Default::default),
+                    value.into_iter().map(::std::convert::Into::<Foo>::into));
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn collection() {
+        let ty = syn::parse_type("Vec<Foo>").unwrap();
+        let item_ty = syn::parse_type("Foo").unwrap();
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.collection = Some(&item_ty);
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Vec<Foo>) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+
+            pub fn set_foo<__VALUE: ::std::iter::IntoIterator<Item = __ITEM>,
+                           __ITEM: ::std::convert::Into<Foo>>(&mut self, value: __VALUE)
+                -> &mut Self
+            {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(
+                    value.into_iter().map(::std::convert::Into::<Foo>::into).collect());
+                new
+            }
+
+            pub fn clear_foo(&mut self) -> &mut Self {
+                let mut new = self;
+                if let ::std::option::Option::Some(ref mut collection) = new.foo {
+                    collection.clear();
+                }
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn as_ref() {
+        let ty = syn::parse_type("PathBuf").unwrap();
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.as_ref = true;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo<__VALUE: ::std::convert::AsRef<::std::path::Path>>(&mut self, value: __VALUE)
+                -> &mut Self
+            {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value.as_ref().to_owned());
+                new
+            }
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "`setter(as_ref)` is not supported for field `foo` of type `Foo`")]
+    fn as_ref_unsupported() {
+        let mut setter = default_setter!();
+        setter.as_ref = true;
+
+        quote!(#setter);
+    }
+
+    #[test]
+    fn strip_option() {
+        let ty = syn::parse_type("Option<Option<Foo> >").unwrap();
+        let inner_ty = syn::parse_type("Foo").unwrap();
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.generic_into = true;
+        setter.strip_option = Some(StripOption {
+            depth: 2,
+            inner_type: &inner_ty,
+        });
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo<__VALUE: ::std::convert::Into<Foo>>(&mut self, value: __VALUE) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(
+                    ::std::option::Option::Some(::std::option::Option::Some(value.into())));
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn option() {
+        let ty = syn::parse_type("Option<Foo>").unwrap();
+        let inner_ty = syn::parse_type("Foo").unwrap();
+        let option_ident = syn::Ident::new("maybe_foo");
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.strip_option = Some(StripOption {
+            depth: 1,
+            inner_type: &inner_ty,
+        });
+        setter.option = Some(&option_ident);
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Foo) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(::std::option::Option::Some(value));
+                new
+            }
+
+            pub fn maybe_foo(&mut self, value: ::std::option::Option<Foo>) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn strip_bool() {
+        let ty = syn::parse_type("bool").unwrap();
+        let alias = SetterAlias {
+            ident: syn::Ident::new("bar"),
+            deprecated: false,
+        };
+        let aliases = vec![alias];
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.generic_into = true;
+        setter.strip_bool = true;
+        setter.aliases = &aliases;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(true);
+                new
+            }
+
+            pub fn bar(&mut self) -> &mut Self {
+                self.foo()
+            }
+        ));
+    }
+
     #[test]
     fn private() {
         let vis = syn::Visibility::Inherited;
@@ -237,11 +947,347 @@ mod tests {
         setter.generic_into = true;
 
         assert_eq!(quote!(#setter), quote!(
-            pub fn foo <VALUE: ::std::convert::Into<Foo>>(&mut self, value: VALUE) -> &mut Self {
+            pub fn foo <__VALUE: ::std::convert::Into<Foo>>(&mut self, value: __VALUE) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value.into());
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn generic_associated_type() {
+        let ty = syn::parse_type("<T as Iterator>::Item").unwrap();
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.generic_into = true;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo <__VALUE: ::std::convert::Into< <T as Iterator>::Item>>(&mut self,
+                                                                              value: __VALUE)
+                -> &mut Self
+            {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value.into());
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn with_plain() {
+        let mut setter = default_setter!();
+        setter.generic_into = true;
+        setter.with_plain = true;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Foo) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+
+            pub fn foo_into <__VALUE: ::std::convert::Into<Foo>>(&mut self, value: __VALUE)
+                -> &mut Self
+            {
+                self.foo(value.into())
+            }
+        ));
+    }
+
+    #[test]
+    fn generic_into_ty() {
+        let into_ty = syn::parse_type("Bar").unwrap();
+        let mut setter = default_setter!();
+        setter.generic_into = true;
+        setter.into_ty = Some(&into_ty);
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo <__VALUE: ::std::convert::Into<Bar>>(&mut self, value: __VALUE) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(
+                    ::std::convert::Into::<Foo>::into(::std::convert::Into::<Bar>::into(value)));
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn generic_impl_trait() {
+        let mut setter = default_setter!();
+        setter.generic_into = true;
+        setter.impl_trait = true;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: impl ::std::convert::Into<Foo>) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value.into());
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn impl_trait_without_into() {
+        let mut setter = default_setter!();
+        setter.impl_trait = true;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Foo) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn aliases() {
+        let aliases = vec![
+            SetterAlias {
+                ident: syn::Ident::new("bar"),
+                deprecated: true,
+            },
+            SetterAlias {
+                ident: syn::Ident::new("baz"),
+                deprecated: false,
+            },
+        ];
+        let mut setter = default_setter!();
+        setter.generic_into = true;
+        setter.aliases = &aliases;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo<__VALUE: ::std::convert::Into<Foo>>(&mut self, value: __VALUE) -> &mut Self {
                 let mut new = self;
                 new.foo = ::std::option::Option::Some(value.into());
                 new
             }
+
+            #[deprecated(note = "use `foo` instead")]
+            pub fn bar<__VALUE: ::std::convert::Into<Foo>>(&mut self, value: __VALUE) -> &mut Self {
+                self.foo(value)
+            }
+
+            pub fn baz<__VALUE: ::std::convert::Into<Foo>>(&mut self, value: __VALUE) -> &mut Self {
+                self.foo(value)
+            }
+        ));
+    }
+
+    #[test]
+    fn deprecated() {
+        let deprecated = syn::parse_outer_attr("#[deprecated(note = \"use `bar`\")]").unwrap();
+        let aliases = vec![
+            SetterAlias {
+                ident: syn::Ident::new("baz"),
+                deprecated: true,
+            },
+        ];
+        let mut setter = default_setter!();
+        setter.aliases = &aliases;
+        setter.deprecated = Some(&deprecated);
+
+        assert_eq!(quote!(#setter), quote!(
+            #[deprecated(note = "use `bar`")]
+            pub fn foo(&mut self, value: Foo) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+
+            #[deprecated(note = "use `bar`")]
+            pub fn baz(&mut self, value: Foo) -> &mut Self {
+                self.foo(value)
+            }
+        ));
+    }
+
+    #[test]
+    fn each() {
+        let each_ident = syn::Ident::new("bar");
+        let item_ty = syn::parse_type("Bar").unwrap();
+        let mut setter = default_setter!();
+        setter.each = Some(EachSetter {
+            ident: &each_ident,
+            item_type: &item_ty,
+            generic_into: true,
+            key_value: false,
+        });
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Foo) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+
+            pub fn bar <__VALUE: ::std::convert::Into<Bar>>(&mut self, item: __VALUE) -> &mut Self {
+                let mut new = self;
+                ::std::option::Option::get_or_insert_with(&mut new.foo,
+                                                          ::std::default::Default::default)
+                    .extend(::std::option::Option::Some(item.into()));
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn each_key_value() {
+        let ty = syn::parse_type("HashMap<String, u32>").unwrap();
+        let each_ident = syn::Ident::new("bar");
+        let item_ty = syn::parse_type("(String, u32)").unwrap();
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.each = Some(EachSetter {
+            ident: &each_ident,
+            item_type: &item_ty,
+            generic_into: true,
+            key_value: true,
+        });
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: HashMap<String, u32>) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+
+            pub fn bar <__KEY: ::std::convert::Into<String>, __VALUE: ::std::convert::Into<u32>>
+                (&mut self, key: __KEY, value: __VALUE) -> &mut Self
+            {
+                let mut new = self;
+                ::std::option::Option::get_or_insert_with(&mut new.foo,
+                                                          ::std::default::Default::default)
+                    .insert(key.into(), value.into());
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn each_strip_option() {
+        let ty = syn::parse_type("Option<Vec<Bar>>").unwrap();
+        let inner_ty = syn::parse_type("Vec<Bar>").unwrap();
+        let each_ident = syn::Ident::new("bar");
+        let item_ty = syn::parse_type("Bar").unwrap();
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.strip_option = Some(StripOption {
+            depth: 1,
+            inner_type: &inner_ty,
+        });
+        setter.each = Some(EachSetter {
+            ident: &each_ident,
+            item_type: &item_ty,
+            generic_into: false,
+            key_value: false,
+        });
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Vec<Bar>) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(::std::option::Option::Some(value));
+                new
+            }
+
+            pub fn bar(&mut self, item: Bar) -> &mut Self {
+                let mut new = self;
+                ::std::option::Option::get_or_insert_with(
+                    ::std::option::Option::get_or_insert_with(&mut new.foo,
+                                                              ::std::default::Default::default),
+                    ::std::default::Default::default)
+                    .extend(::std::option::Option::Some(item));
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn each_string() {
+        let ty = syn::parse_type("String").unwrap();
+        let each_ident = syn::Ident::new("bar");
+        let item_ty = syn::parse_type("str").unwrap();
+        let mut setter = default_setter!();
+        setter.field_type = &ty;
+        setter.each = Some(EachSetter {
+            ident: &each_ident,
+            item_type: &item_ty,
+            generic_into: false,
+            key_value: false,
+        });
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: String) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+
+            pub fn bar(&mut self, item: &str) -> &mut Self {
+                let mut new = self;
+                ::std::option::Option::get_or_insert_with(&mut new.foo,
+                                                          ::std::default::Default::default)
+                    .push_str(item);
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn once_panic() {
+        let mut setter = default_setter!();
+        setter.once = Some(SetterOnce::Panic);
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Foo) -> &mut Self {
+                if self.foo.is_some() {
+                    panic!("`foo` must not be set more than once");
+                }
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+        ));
+    }
+
+    #[test]
+    fn once_error() {
+        let mut setter = default_setter!();
+        setter.once = Some(SetterOnce::Error);
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Foo)
+                -> ::std::result::Result<&mut Self, ::std::string::String>
+            {
+                if self.foo.is_some() {
+                    return ::std::result::Result::Err(
+                        ::std::string::String::from("`foo` must not be set more than once"));
+                }
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                ::std::result::Result::Ok(new)
+            }
+        ));
+    }
+
+    #[test]
+    fn doc_comment() {
+        let attrs = vec![syn::parse_outer_attr("#[doc = r\"Field doc\"]").unwrap(),
+                         syn::parse_outer_attr("#[some_attr]").unwrap()];
+
+        let mut setter = default_setter!();
+        setter.attrs = attrs.as_slice();
+        setter.doc_comment("Setter doc".to_string());
+
+        assert_eq!(quote!(#setter), quote!(
+            #[some_attr]
+            #[doc = r##"Setter doc"##]
+            pub fn foo(&mut self, value: Foo) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
         ));
     }
 
@@ -261,7 +1307,7 @@ mod tests {
 
         assert_eq!(quote!(#setter), quote!(
             #[some_attr]
-            pub fn foo <VALUE: ::std::convert::Into<Foo>>(&mut self, value: VALUE) -> &mut Self {
+            pub fn foo <__VALUE: ::std::convert::Into<Foo>>(&mut self, value: __VALUE) -> &mut Self {
                 #deprecated
                 let mut new = self;
                 new.foo = ::std::option::Option::Some(value.into());
@@ -269,8 +1315,34 @@ mod tests {
             }
 
             #[some_attr]
-            pub fn try_foo<VALUE: ::std::convert::TryInto<Foo>>(&mut self, value: VALUE)
-                -> ::std::result::Result<&mut Self, VALUE::Error> {
+            pub fn try_foo<__VALUE: ::std::convert::TryInto<Foo>>(&mut self, value: __VALUE)
+                -> ::std::result::Result<&mut Self, __VALUE::Error> {
+                let converted : Foo = value.try_into()?;
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(converted);
+                Ok(new)
+            }
+        ));
+    }
+
+    #[test]
+    fn try_setter_error() {
+        let error = syn::parse_path("MyError").unwrap();
+        let mut setter: Setter = default_setter!();
+        setter.try_setter = true;
+        setter.try_setter_error = Some(&error);
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Foo) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+
+            pub fn try_foo<__VALUE: ::std::convert::TryInto<Foo>>(&mut self, value: __VALUE)
+                -> ::std::result::Result<&mut Self, MyError>
+                where MyError: ::std::convert::From<__VALUE::Error>
+            {
                 let converted : Foo = value.try_into()?;
                 let mut new = self;
                 new.foo = ::std::option::Option::Some(converted);
@@ -279,6 +1351,89 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn inline() {
+        let mut setter: Setter = default_setter!();
+        setter.inline = true;
+        setter.try_setter = true;
+
+        assert_eq!(quote!(#setter), quote!(
+            #[inline]
+            pub fn foo(&mut self, value: Foo) -> &mut Self {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+
+            #[inline]
+            pub fn try_foo<__VALUE: ::std::convert::TryInto<Foo>>(&mut self, value: __VALUE)
+                -> ::std::result::Result<&mut Self, __VALUE::Error> {
+                let converted : Foo = value.try_into()?;
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(converted);
+                Ok(new)
+            }
+        ));
+    }
+
+    #[test]
+    fn try_into() {
+        let error = syn::parse_path("MyError").unwrap();
+        let mut setter: Setter = default_setter!();
+        setter.try_into = true;
+        setter.try_into_error = Some(&error);
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo<__VALUE: ::std::convert::TryInto<Foo>>(&mut self, value: __VALUE)
+                -> ::std::result::Result<&mut Self, MyError>
+                where MyError: ::std::convert::From<__VALUE::Error>
+            {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(
+                    ::std::convert::TryInto::<Foo>::try_into(value)?);
+                ::std::result::Result::Ok(new)
+            }
+        ));
+    }
+
+    #[test]
+    fn try_into_error_inferred() {
+        let mut setter: Setter = default_setter!();
+        setter.try_into = true;
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo<__VALUE: ::std::convert::TryInto<Foo>>(&mut self, value: __VALUE)
+                -> ::std::result::Result<&mut Self, __VALUE::Error>
+            {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(
+                    ::std::convert::TryInto::<Foo>::try_into(value)?);
+                ::std::result::Result::Ok(new)
+            }
+        ));
+    }
+
+    #[test]
+    fn try_into_owned() {
+        let error = syn::parse_path("MyError").unwrap();
+        let mut setter: Setter = default_setter!();
+        setter.pattern = BuilderPattern::Owned;
+        setter.try_into = true;
+        setter.try_into_error = Some(&error);
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo<__VALUE: ::std::convert::TryInto<Foo>>(self, value: __VALUE)
+                -> ::std::result::Result<Self, MyError>
+                where MyError: ::std::convert::From<__VALUE::Error>
+            {
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(
+                    ::std::convert::TryInto::<Foo>::try_into(value)?);
+                ::std::result::Result::Ok(new)
+            }
+        ));
+    }
+
     #[test]
     fn no_std() {
         let mut setter = default_setter!();
@@ -301,7 +1456,7 @@ mod tests {
         setter.generic_into = true;
 
         assert_eq!(quote!(#setter), quote!(
-            pub fn foo <VALUE: ::core::convert::Into<Foo>>(&mut self, value: VALUE) -> &mut Self {
+            pub fn foo <__VALUE: ::core::convert::Into<Foo>>(&mut self, value: __VALUE) -> &mut Self {
                 let mut new = self;
                 new.foo = ::core::option::Option::Some(value.into());
                 new
@@ -330,8 +1485,8 @@ mod tests {
                 new
             }
 
-            pub fn try_foo<VALUE: ::std::convert::TryInto<Foo>>(&mut self, value: VALUE)
-                -> ::std::result::Result<&mut Self, VALUE::Error> {
+            pub fn try_foo<__VALUE: ::std::convert::TryInto<Foo>>(&mut self, value: __VALUE)
+                -> ::std::result::Result<&mut Self, __VALUE::Error> {
                 let converted : Foo = value.try_into()?;
                 let mut new = self;
                 new.foo = ::std::option::Option::Some(converted);
@@ -339,4 +1494,43 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn try_setter_once() {
+        let mut setter: Setter = default_setter!();
+        setter.try_setter = true;
+        setter.once = Some(SetterOnce::Panic);
+
+        assert_eq!(quote!(#setter), quote!(
+            pub fn foo(&mut self, value: Foo) -> &mut Self {
+                if self.foo.is_some() {
+                    panic!("`foo` must not be set more than once");
+                }
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(value);
+                new
+            }
+
+            pub fn try_foo<__VALUE: ::std::convert::TryInto<Foo>>(&mut self, value: __VALUE)
+                -> ::std::result::Result<&mut Self, __VALUE::Error> {
+                if self.foo.is_some() {
+                    panic!("`foo` must not be set more than once");
+                }
+                let converted : Foo = value.try_into()?;
+                let mut new = self;
+                new.foo = ::std::option::Option::Some(converted);
+                Ok(new)
+            }
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "`try_setter` can not be combined with `setter(once = \"error\")`")]
+    fn try_setter_once_error() {
+        let mut setter: Setter = default_setter!();
+        setter.try_setter = true;
+        setter.once = Some(SetterOnce::Error);
+
+        quote!(#setter);
+    }
 }
@@ -0,0 +1,267 @@
+use quote::{Tokens, ToTokens};
+use syn;
+use Bindings;
+use Initializer;
+use build_method::evaluation_order;
+
+/// `Default` impl of the target struct, using the default values of its
+/// fields, implementing `quote::ToTokens`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::TargetDefault;
+/// # fn main() {
+/// #    let target_default = default_target_default!();
+/// #
+/// #    assert_eq!(quote!(#target_default), quote!(
+/// impl ::std::default::Default for Foo {
+///     fn default() -> Self {
+///         Foo {
+///         }
+///     }
+/// }
+/// #    ));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TargetDefault<'a> {
+    /// Enables code generation for the `Default` impl.
+    pub enabled: bool,
+    /// Name of the target struct or enum, e.g. `Foo`.
+    pub target_ty: &'a syn::Ident,
+    /// Name of the target variant, if the target is an enum, e.g. `Bar` in `Foo::Bar`.
+    pub target_variant: Option<&'a syn::Ident>,
+    /// Type parameters and lifetimes attached to the target struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Field initializers, e.g. `foo: 42u32,`
+    pub initializers: Vec<Tokens>,
+    /// Field names and their dependencies, one entry per pushed initializer.
+    ///
+    /// Initializers are evaluated after the initializers they depend on, just
+    /// like in `BuildMethod`.
+    pub initializer_deps: Vec<(syn::Ident, Vec<syn::Ident>)>,
+    /// Bindings to libstd or libcore.
+    pub bindings: Bindings,
+}
+
+impl<'a> ToTokens for TargetDefault<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        if self.enabled {
+            trace!("Deriving `Default` for `{}`.", self.target_ty);
+            let target_ty = self.target_ty;
+            let target_path = match self.target_variant {
+                Some(variant) => quote!(#target_ty::#variant),
+                None => quote!(#target_ty),
+            };
+            let (impl_generics, ty_generics, where_clause) = self.generics
+                .map(syn::Generics::split_for_impl)
+                .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+                .unwrap_or((None, None, None));
+            let initializers = evaluation_order(&self.initializer_deps, self.initializers.len())
+                .into_iter()
+                .map(|i| &self.initializers[i])
+                .collect::<Vec<_>>();
+            let default = self.bindings.default_trait();
+
+            tokens.append(quote!(
+                impl #impl_generics #default for #target_ty #ty_generics #where_clause {
+                    fn default() -> Self {
+                        #target_path {
+                            #(#initializers)*
+                        }
+                    }
+                }
+            ));
+        } else {
+            trace!("Skipping `Default` for `{}`.", self.target_ty);
+        }
+    }
+}
+
+impl<'a> TargetDefault<'a> {
+    /// Initialize a field of the target with its default value.
+    ///
+    /// # Panics
+    ///
+    /// If the `Default` impl is enabled, but the field has no default value of
+    /// its own, see `Initializer::target_default_value`.
+    pub fn push_initializer(&mut self, init: Initializer) -> &mut Self {
+        if self.enabled {
+            let ident = init.field_ident;
+            let value = init.target_default_value().unwrap_or_else(|| {
+                panic!("`derive_target(Default)` requires an infallible default value for field \
+                        `{}`, e.g. `#[builder(default)]`.", ident)
+            });
+            let cfg_attrs = init.attrs.iter().filter(|a| a.value.name() == "cfg");
+
+            self.initializers.push(quote!(
+                #(#cfg_attrs)* #ident: #value,
+            ));
+            self.initializer_deps.push((ident.clone(), init.depends_on.to_vec()));
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_target_default {
+    () => {
+        TargetDefault {
+            enabled: true,
+            target_ty: &syn::Ident::new("Foo"),
+            target_variant: None,
+            generics: None,
+            initializers: vec![],
+            initializer_deps: vec![],
+            bindings: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    use BuilderPattern;
+    use Initializer;
+
+    fn initializer<'a>(field_ident: &'a syn::Ident, field_type: &'a syn::Ty) -> Initializer<'a> {
+        Initializer {
+            field_ident: field_ident,
+            field_type: field_type,
+            setter_enabled: true,
+            builder_pattern: BuilderPattern::Mutable,
+            default_value: None,
+            use_default_struct: false,
+            option_as_optional: false,
+            depends_on: &[],
+            take_from_builder: false,
+            initial_value: None,
+            checked_by_build_fn: false,
+            charset: None,
+            validate_fn: None,
+            forbid_default: false,
+            error_ty: None,
+            attrs: &[],
+            bindings: Default::default(),
+        }
+    }
+
+    #[test]
+    fn disabled() {
+        let mut target_default = default_target_default!();
+        target_default.enabled = false;
+
+        assert_eq!(quote!(#target_default), quote!());
+    }
+
+    #[test]
+    fn fields() {
+        let foo = syn::Ident::new("foo");
+        let bar = syn::Ident::new("bar");
+        let baz = syn::Ident::new("baz");
+        let ty = syn::parse_type("u32").unwrap();
+        let option_ty = syn::parse_type("Option<u32>").unwrap();
+        let mut target_default = default_target_default!();
+
+        let mut init = initializer(&foo, &ty);
+        init.default_value = Some("42".parse().unwrap());
+        target_default.push_initializer(init);
+
+        let mut init = initializer(&bar, &option_ty);
+        init.option_as_optional = true;
+        target_default.push_initializer(init);
+
+        let mut init = initializer(&baz, &ty);
+        init.setter_enabled = false;
+        target_default.push_initializer(init);
+
+        assert_eq!(quote!(#target_default), quote!(
+            impl ::std::default::Default for Foo {
+                fn default() -> Self {
+                    Foo {
+                        foo: { 42 },
+                        bar: ::std::option::Option::None,
+                        baz: ::std::default::Default::default(),
+                    }
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn depends_on() {
+        let foo = syn::Ident::new("foo");
+        let bar = syn::Ident::new("bar");
+        let ty = syn::parse_type("u32").unwrap();
+        let deps = [bar.clone()];
+        let mut target_default = default_target_default!();
+
+        let mut init = initializer(&foo, &ty);
+        init.default_value = Some("bar()".parse().unwrap());
+        init.depends_on = &deps;
+        target_default.push_initializer(init);
+
+        let mut init = initializer(&bar, &ty);
+        init.default_value = Some("foo()".parse().unwrap());
+        target_default.push_initializer(init);
+
+        assert_eq!(quote!(#target_default), quote!(
+            impl ::std::default::Default for Foo {
+                fn default() -> Self {
+                    Foo {
+                        bar: { foo() },
+                        foo: { bar() },
+                    }
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn variant() {
+        let variant = syn::Ident::new("Bar");
+        let mut target_default = default_target_default!();
+        target_default.target_variant = Some(&variant);
+
+        assert_eq!(quote!(#target_default), quote!(
+            impl ::std::default::Default for Foo {
+                fn default() -> Self {
+                    Foo::Bar {
+                    }
+                }
+            }
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "requires an infallible default value for field `foo`")]
+    fn required_field() {
+        let foo = syn::Ident::new("foo");
+        let ty = syn::parse_type("u32").unwrap();
+        let mut target_default = default_target_default!();
+        target_default.push_initializer(initializer(&foo, &ty));
+    }
+
+    #[test]
+    #[should_panic(expected = "requires an infallible default value for field `foo`")]
+    fn fallible_default() {
+        let foo = syn::Ident::new("foo");
+        let ty = syn::parse_type("u32").unwrap();
+        let mut target_default = default_target_default!();
+        let mut init = initializer(&foo, &ty);
+        init.default_value = Some("parse_foo()?".parse().unwrap());
+        target_default.push_initializer(init);
+    }
+}
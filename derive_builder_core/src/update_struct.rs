@@ -0,0 +1,269 @@
+use quote::{Tokens, ToTokens};
+use syn;
+use Bindings;
+use BuilderField;
+use BuilderPattern;
+use doc_comment::doc_comment_from;
+
+/// Update struct with all-optional fields and a method to apply it to the
+/// builder, implementing `quote::ToTokens`.
+///
+/// # Examples
+///
+/// Will expand to something like the following (depending on settings):
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate quote;
+/// # extern crate syn;
+/// # #[macro_use]
+/// # extern crate derive_builder_core;
+/// # use derive_builder_core::{UpdateStruct, BuilderPattern};
+/// # fn main() {
+/// #    let update_struct = default_update_struct!();
+/// #
+/// #    assert_eq!(quote!(#update_struct), quote!(
+/// #[derive(Default, Clone)]
+/// pub struct FooUpdate {
+///     pub foo: ::std::option::Option<u32>,
+/// }
+///
+/// #[allow(dead_code)]
+/// impl FooBuilder {
+///     pub fn apply_update(&mut self, update: FooUpdate) -> &mut Self {
+///         let mut new = self;
+///         if let ::std::option::Option::Some(value) = update.foo {
+///             new.foo = ::std::option::Option::Some(value);
+///         }
+///         new
+///     }
+/// }
+/// #    ));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct UpdateStruct<'a> {
+    /// Name of the update struct, e.g. `FooUpdate`.
+    ///
+    /// No code is generated if this is `None`.
+    pub ident: Option<&'a syn::Ident>,
+    /// Name of the builder struct, e.g. `FooBuilder`.
+    pub builder_ident: &'a syn::Ident,
+    /// How `apply_update` takes and returns `self` (e.g. mutably).
+    pub pattern: BuilderPattern,
+    /// Type parameters and lifetimes attached to the target struct.
+    pub generics: Option<&'a syn::Generics>,
+    /// Visibility of the update struct, its fields and `apply_update`.
+    pub visibility: &'a syn::Visibility,
+    /// Fields of the update struct, e.g. `pub foo: Option<u32>,`
+    pub fields: Vec<Tokens>,
+    /// Statements assigning present fields of the update to the builder.
+    pub assignments: Vec<Tokens>,
+    /// Doc-comment of the update struct.
+    pub doc_comment: Option<syn::Attribute>,
+    /// Bindings to libstd or libcore.
+    pub bindings: Bindings,
+}
+
+impl<'a> ToTokens for UpdateStruct<'a> {
+    fn to_tokens(&self, tokens: &mut Tokens) {
+        if let Some(ident) = self.ident {
+            trace!("Deriving update struct `{}`.", ident);
+            let vis = self.visibility;
+            let builder_ident = self.builder_ident;
+            let (impl_generics, ty_generics, where_clause) = self.generics
+                .map(syn::Generics::split_for_impl)
+                .map(|(i, t, w)| (Some(i), Some(t), Some(w)))
+                .unwrap_or((None, None, None));
+            let struct_generics = &impl_generics;
+            let fields = &self.fields;
+            let assignments = &self.assignments;
+            let doc_comment = &self.doc_comment;
+            let clone = self.bindings.clone_trait();
+
+            let derive_clone = if self.pattern.requires_clone() {
+                Some(quote!(, Clone))
+            } else {
+                None
+            };
+
+            let (self_param, return_ty, self_into_return_ty, clone_bound) = match self.pattern {
+                BuilderPattern::Owned => (quote!(self), quote!(Self), quote!(self), None),
+                BuilderPattern::Mutable => (quote!(&mut self), quote!(&mut Self), quote!(self), None),
+                BuilderPattern::Immutable => (quote!(&self),
+                                              quote!(Self),
+                                              quote!(#clone::clone(self)),
+                                              Some(quote!(where Self: #clone))),
+            };
+
+            tokens.append(quote!(
+                #[derive(Default #derive_clone)]
+                #doc_comment
+                #vis struct #ident #struct_generics #where_clause {
+                    #(#fields)*
+                }
+
+                #[allow(dead_code)]
+                impl #impl_generics #builder_ident #ty_generics #where_clause {
+                    #vis fn apply_update(#self_param, update: #ident #ty_generics) -> #return_ty
+                        #clone_bound
+                    {
+                        let mut new = #self_into_return_ty;
+                        #(#assignments)*
+                        new
+                    }
+                }
+            ));
+        } else {
+            trace!("Skipping update struct.");
+        }
+    }
+}
+
+impl<'a> UpdateStruct<'a> {
+    /// Set a doc-comment for this item.
+    pub fn doc_comment(&mut self, s: String) -> &mut Self {
+        self.doc_comment = Some(doc_comment_from(s));
+        self
+    }
+
+    /// Add the optional counterpart of a builder field to the update struct.
+    ///
+    /// Fields without a setter fall back to `PhantomData`, just like on the
+    /// builder, and are never applied.
+    pub fn push_field(&mut self, f: BuilderField) -> &mut Self {
+        let vis = self.visibility;
+        let ident = f.field_ident;
+        let ty = f.field_type;
+        let attrs = f.attrs;
+        let option = self.bindings.option_ty();
+
+        if f.setter_enabled {
+            self.fields.push(quote!(
+                #(#attrs)* #vis #ident: #option<#ty>,
+            ));
+            self.assignments.push(quote!(
+                if let #option::Some(value) = update.#ident {
+                    new.#ident = #option::Some(value);
+                }
+            ));
+        } else {
+            let phantom_data = self.bindings.phantom_data_ty();
+            self.fields.push(quote!(
+                #(#attrs)* #ident: #phantom_data<#ty>,
+            ));
+        }
+        self
+    }
+}
+
+/// Helper macro for unit tests. This is _only_ public in order to be accessible
+/// from doc-tests too.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! default_update_struct {
+    () => {
+        UpdateStruct {
+            ident: Some(&syn::Ident::new("FooUpdate")),
+            builder_ident: &syn::Ident::new("FooBuilder"),
+            pattern: BuilderPattern::Mutable,
+            generics: None,
+            visibility: &syn::Visibility::Public,
+            fields: vec![quote!(pub foo: ::std::option::Option<u32>,)],
+            assignments: vec![quote!(
+                if let ::std::option::Option::Some(value) = update.foo {
+                    new.foo = ::std::option::Option::Some(value);
+                }
+            )],
+            doc_comment: None,
+            bindings: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let mut update_struct = default_update_struct!();
+        update_struct.ident = None;
+
+        assert_eq!(quote!(#update_struct), quote!());
+    }
+
+    #[test]
+    fn immutable() {
+        let mut update_struct = default_update_struct!();
+        update_struct.pattern = BuilderPattern::Immutable;
+        update_struct.fields.clear();
+        update_struct.assignments.clear();
+
+        assert_eq!(quote!(#update_struct), quote!(
+            #[derive(Default, Clone)]
+            pub struct FooUpdate {}
+
+            #[allow(dead_code)]
+            impl FooBuilder {
+                pub fn apply_update(&self, update: FooUpdate) -> Self
+                    where Self: ::std::clone::Clone
+                {
+                    let mut new = ::std::clone::Clone::clone(self);
+                    new
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn push_field() {
+        let foo = syn::Ident::new("foo");
+        let bar = syn::Ident::new("bar");
+        let ty = syn::parse_type("u32").unwrap();
+        let vis = syn::Visibility::Public;
+        let mut update_struct = default_update_struct!();
+        update_struct.fields.clear();
+        update_struct.assignments.clear();
+        update_struct.push_field(BuilderField {
+            field_ident: &foo,
+            field_type: &ty,
+            setter_enabled: true,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+        update_struct.push_field(BuilderField {
+            field_ident: &bar,
+            field_type: &ty,
+            setter_enabled: false,
+            field_visibility: &vis,
+            attrs: &[],
+            serde: false,
+            initial_value: None,
+            bindings: Default::default(),
+        });
+
+        assert_eq!(quote!(#update_struct), quote!(
+            #[derive(Default, Clone)]
+            pub struct FooUpdate {
+                pub foo: ::std::option::Option<u32>,
+                bar: ::std::marker::PhantomData<u32>,
+            }
+
+            #[allow(dead_code)]
+            impl FooBuilder {
+                pub fn apply_update(&mut self, update: FooUpdate) -> &mut Self {
+                    let mut new = self;
+                    if let ::std::option::Option::Some(value) = update.foo {
+                        new.foo = ::std::option::Option::Some(value);
+                    }
+                    new
+                }
+            }
+        ));
+    }
+}